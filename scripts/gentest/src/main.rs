@@ -161,11 +161,22 @@ fn generate_node(node: &json::JsonValue) -> String {
         _ => (),
     };
 
-    match style["overflow"] {
+    match style["overflowX"] {
         json::JsonValue::Short(ref value) => {
             match value.as_ref() {
-                "hidden" => src.push_str("overflow: stretch::style::Overflow::Hidden,\n"),
-                "scroll" => src.push_str("overflow: stretch::style::Overflow::Scroll,\n"),
+                "hidden" => src.push_str("overflow_x: stretch::style::Overflow::Hidden,\n"),
+                "scroll" => src.push_str("overflow_x: stretch::style::Overflow::Scroll,\n"),
+                _ => (),
+            };
+        },
+        _ => (),
+    };
+
+    match style["overflowY"] {
+        json::JsonValue::Short(ref value) => {
+            match value.as_ref() {
+                "hidden" => src.push_str("overflow_y: stretch::style::Overflow::Hidden,\n"),
+                "scroll" => src.push_str("overflow_y: stretch::style::Overflow::Scroll,\n"),
                 _ => (),
             };
         },
@@ -179,12 +190,21 @@ fn generate_node(node: &json::JsonValue) -> String {
                 "flex-end" => src.push_str("align_items: stretch::style::AlignItems::FlexEnd,\n"),
                 "center" => src.push_str("align_items: stretch::style::AlignItems::Center,\n"),
                 "baseline" => src.push_str("align_items: stretch::style::AlignItems::Baseline,\n"),
+                "start" => src.push_str("align_items: stretch::style::AlignItems::Start,\n"),
+                "end" => src.push_str("align_items: stretch::style::AlignItems::End,\n"),
+                "normal" => src.push_str("align_items: stretch::style::AlignItems::Normal,\n"),
+                "last baseline" => src.push_str("align_items: stretch::style::AlignItems::LastBaseline,\n"),
                 _ => (),
             };
         },
         _ => (),
     };
 
+    match style["alignItemsSafety"] {
+        json::JsonValue::Short(ref value) => src.push_str(&generate_alignment_safety("align_items_safety", value)),
+        _ => (),
+    };
+
     match style["alignSelf"] {
         json::JsonValue::Short(ref value) => {
             match value.as_ref() {
@@ -193,12 +213,21 @@ fn generate_node(node: &json::JsonValue) -> String {
                 "center" => src.push_str("align_self: stretch::style::AlignSelf::Center,\n"),
                 "baseline" => src.push_str("align_self: stretch::style::AlignSelf::Baseline,\n"),
                 "stretch" => src.push_str("align_self: stretch::style::AlignSelf::Stretch,\n"),
+                "start" => src.push_str("align_self: stretch::style::AlignSelf::Start,\n"),
+                "end" => src.push_str("align_self: stretch::style::AlignSelf::End,\n"),
+                "normal" => src.push_str("align_self: stretch::style::AlignSelf::Normal,\n"),
+                "last baseline" => src.push_str("align_self: stretch::style::AlignSelf::LastBaseline,\n"),
                 _ => (),
             };
         },
         _ => (),
     };
 
+    match style["alignSelfSafety"] {
+        json::JsonValue::Short(ref value) => src.push_str(&generate_alignment_safety("align_self_safety", value)),
+        _ => (),
+    };
+
     match style["alignContent"] {
         json::JsonValue::Short(ref value) => {
             match value.as_ref() {
@@ -207,12 +236,21 @@ fn generate_node(node: &json::JsonValue) -> String {
                 "center" => src.push_str("align_content: stretch::style::AlignContent::Center,\n"),
                 "space-between" => src.push_str("align_content: stretch::style::AlignContent::SpaceBetween,\n"),
                 "space-around" => src.push_str("align_content: stretch::style::AlignContent::SpaceAround,\n"),
+                "space-evenly" => src.push_str("align_content: stretch::style::AlignContent::SpaceEvenly,\n"),
+                "start" => src.push_str("align_content: stretch::style::AlignContent::Start,\n"),
+                "end" => src.push_str("align_content: stretch::style::AlignContent::End,\n"),
+                "normal" => src.push_str("align_content: stretch::style::AlignContent::Normal,\n"),
                 _ => (),
             };
         },
         _ => (),
     };
 
+    match style["alignContentSafety"] {
+        json::JsonValue::Short(ref value) => src.push_str(&generate_alignment_safety("align_content_safety", value)),
+        _ => (),
+    };
+
     match style["justifyContent"] {
         json::JsonValue::Short(ref value) => {
             match value.as_ref() {
@@ -221,12 +259,31 @@ fn generate_node(node: &json::JsonValue) -> String {
                 "space-between" => src.push_str("justify_content: stretch::style::JustifyContent::SpaceBetween,\n"),
                 "space-around" => src.push_str("justify_content: stretch::style::JustifyContent::SpaceAround,\n"),
                 "space-evenly" => src.push_str("justify_content: stretch::style::JustifyContent::SpaceEvenly,\n"),
+                "start" => src.push_str("justify_content: stretch::style::JustifyContent::Start,\n"),
+                "end" => src.push_str("justify_content: stretch::style::JustifyContent::End,\n"),
+                "left" => src.push_str("justify_content: stretch::style::JustifyContent::Left,\n"),
+                "right" => src.push_str("justify_content: stretch::style::JustifyContent::Right,\n"),
+                "stretch" => src.push_str("justify_content: stretch::style::JustifyContent::Stretch,\n"),
+                "normal" => src.push_str("justify_content: stretch::style::JustifyContent::Normal,\n"),
                 _ => (),
             };
         },
         _ => (),
     };
 
+    match style["justifyContentSafety"] {
+        json::JsonValue::Short(ref value) => src.push_str(&generate_alignment_safety("justify_content_safety", value)),
+        _ => (),
+    };
+
+    match style["order"] {
+        json::JsonValue::Number(value) => {
+            let value: f32 = value.into();
+            src.push_str(&format!("order: {},\n", value as i32))
+        },
+        _ => (),
+    };
+
     match style["flexGrow"] {
         json::JsonValue::Number(value) => {
             let value: f32 = value.into();
@@ -263,6 +320,11 @@ fn generate_node(node: &json::JsonValue) -> String {
         _ => (),
     };
 
+    match style["gap"] {
+        json::JsonValue::Object(ref value) => src.push_str(&format!("gap: {},\n", generate_size(value))),
+        _ => (),
+    };
+
     match style["margin"] {
         json::JsonValue::Object(ref value) => src.push_str(&format!("margin: {},\n", generate_edges(value))),
         _ => (),
@@ -343,6 +405,14 @@ fn generate_dimension(dimen: &json::object::Object) -> String {
     }
 }
 
+fn generate_alignment_safety(field: &str, safety: &str) -> String {
+    match safety {
+        "safe" => format!("{}: stretch::style::AlignmentSafety::Safe,\n", field),
+        "unsafe" => format!("{}: stretch::style::AlignmentSafety::Unsafe,\n", field),
+        _ => panic!(),
+    }
+}
+
 fn generate_edges(dimen: &json::object::Object) -> String {
     let mut src = String::new();
     src.push_str("stretch::geometry::Rect {\n");
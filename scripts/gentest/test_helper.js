@@ -44,6 +44,15 @@ function parseEnum(input) {
   }
 }
 
+function parseAlignment(input) {
+  return parseEnum(input.replace(/^(un)?safe /, ''));
+}
+
+function parseAlignmentSafety(input) {
+  var match = input.match(/^(unsafe|safe) /);
+  return match ? match[1] : undefined;
+}
+
 function parseEdges(edges) {
   var start = parseDimension(edges.start);
   var end = parseDimension(edges.end);
@@ -86,14 +95,20 @@ function describeElement(e) {
       flexDirection: parseEnum(e.style.flexDirection),
 
       flexWrap: parseEnum(e.style.flexWrap),
-      overflow: parseEnum(e.style.overflow),
-
-      alignItems: parseEnum(e.style.alignItems),
-      alignSelf: parseEnum(e.style.alignSelf),
-      alignContent: parseEnum(e.style.alignContent),
+      overflowX: parseEnum(e.style.overflowX),
+      overflowY: parseEnum(e.style.overflowY),
+
+      alignItems: parseAlignment(e.style.alignItems),
+      alignItemsSafety: parseAlignmentSafety(e.style.alignItems),
+      alignSelf: parseAlignment(e.style.alignSelf),
+      alignSelfSafety: parseAlignmentSafety(e.style.alignSelf),
+      alignContent: parseAlignment(e.style.alignContent),
+      alignContentSafety: parseAlignmentSafety(e.style.alignContent),
       
-      justifyContent: parseEnum(e.style.justifyContent),
+      justifyContent: parseAlignment(e.style.justifyContent),
+      justifyContentSafety: parseAlignmentSafety(e.style.justifyContent),
 
+      order: parseNumber(e.style.order),
       flexGrow: parseNumber(e.style.flexGrow),
       flexShrink: parseNumber(e.style.flexShrink),
       flexBasis: parseDimension(e.style.flexBasis),
@@ -102,30 +117,32 @@ function describeElement(e) {
       min_size: parseSize({width: e.style.minWidth, height: e.style.minHeight}),
       max_size: parseSize({width: e.style.maxWidth, height: e.style.maxHeight}),
 
+      gap: parseSize({width: e.style.columnGap, height: e.style.rowGap}),
+
       margin: parseEdges({
-        start: e.style.marginLeft,
-        end: e.style.marginRight,
+        start: e.style.marginInlineStart || e.style.marginLeft,
+        end: e.style.marginInlineEnd || e.style.marginRight,
         top: e.style.marginTop,
         bottom: e.style.marginBottom,
       }),
 
       padding: parseEdges({
-        start: e.style.paddingLeft,
-        end: e.style.paddingRight,
+        start: e.style.paddingInlineStart || e.style.paddingLeft,
+        end: e.style.paddingInlineEnd || e.style.paddingRight,
         top: e.style.paddingTop,
         bottom: e.style.paddingBottom,
       }),
 
       border: parseEdges({
-        start: e.style.borderLeftWidth,
-        end: e.style.borderRightWidth,
+        start: e.style.borderInlineStartWidth || e.style.borderLeftWidth,
+        end: e.style.borderInlineEndWidth || e.style.borderRightWidth,
         top: e.style.borderTopWidth,
         bottom: e.style.borderBottomWidth,
       }),
 
       position: parseEdges({
-        start: e.style.left,
-        end: e.style.right,
+        start: e.style.insetInlineStart || e.style.left,
+        end: e.style.insetInlineEnd || e.style.right,
         top: e.style.top,
         bottom: e.style.bottom,
       }),
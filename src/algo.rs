@@ -4,16 +4,31 @@ use std::f32;
 use crate::layout;
 
 use crate::style;
-use crate::style::{AlignContent, AlignSelf, Dimension, Display, FlexWrap, JustifyContent, PositionType};
+use crate::style::{
+    AlignContent, AlignSelf, AlignmentSafety, Dimension, Direction, Display, FlexWrap, JustifyContent, PositionType,
+    Visibility,
+};
 
 use crate::number::Number::*;
 use crate::number::*;
 
 use crate::geometry::{Point, Rect, Size};
 
+mod block;
+mod constraints;
+mod custom;
+mod grid;
+mod inline;
+mod multicol;
+mod stack;
+mod table;
+
 #[derive(Debug)]
 struct ComputeResult {
     size: Size<f32>,
+    content_size: Size<f32>,
+    baseline: f32,
+    last_baseline: f32,
     children: Vec<layout::Node>,
 }
 
@@ -40,6 +55,7 @@ struct FlexItem<'a> {
     outer_target_size: Size<f32>,
 
     baseline: f32,
+    last_baseline: f32,
 
     // temporary values for holding offset in the main / cross direction.
     // offset is the relative position from the item's natural flow position based on
@@ -50,6 +66,7 @@ struct FlexItem<'a> {
 
 struct FlexLine<'a> {
     items: Vec<FlexItem<'a>>,
+    collapsed: Vec<FlexItem<'a>>,
     cross_size: f32,
     offset_cross: f32,
 }
@@ -92,13 +109,41 @@ pub unsafe extern "C" fn stretch_cleanup_style(node: *mut style::Node) {
 }
 
 pub fn compute(root: &style::Node) -> layout::Node {
+    let mut layout = root_layout(root);
+    round_layout(&mut layout, 0.0, 0.0);
+    layout
+}
+
+// A root with display: contents doesn't generate a box either. It is laid out as an empty box at the
+// origin, with its children laid out in its place as roots of their own.
+fn root_layout(root: &style::Node) -> layout::Node {
+    if root.display == Display::Contents {
+        return layout::Node {
+            order: 0,
+            size: Size { width: 0.0, height: 0.0 },
+            content_size: Size { width: 0.0, height: 0.0 },
+            location: Point { x: 0.0, y: 0.0 },
+            baseline: 0.0,
+            last_baseline: 0.0,
+            visible: root.visibility == Visibility::Visible,
+            children: Box::new(
+                root.children
+                    .iter()
+                    .enumerate()
+                    .map(|(order, child)| layout::Node { order: order as u32, ..root_layout(child) })
+                    .collect(),
+            ),
+        };
+    }
+
     // TODO - Don't do two passes here just to handle min/max.
     // Probably want to pass min/max down as top level paramerer instead.
     let first_pass = compute_internal(
         root,
         Size { width: root.size.width.resolve(Undefined), height: root.size.height.resolve(Undefined) },
-        Size { width: Undefined, height: Undefined },
+        Size { width: AvailableSpace::MaxContent, height: AvailableSpace::MaxContent },
         Undefined,
+        Direction::LTR,
     );
 
     let result = compute_internal(
@@ -117,19 +162,21 @@ pub fn compute(root: &style::Node) -> layout::Node {
                 .maybe_min(root.max_size.height.resolve(Undefined))
                 .to_number(),
         },
-        Size { width: Undefined, height: Undefined },
+        Size { width: AvailableSpace::MaxContent, height: AvailableSpace::MaxContent },
         Undefined,
+        Direction::LTR,
     );
 
-    let mut layout = layout::Node {
+    layout::Node {
         order: 0,
         size: Size { width: result.size.width, height: result.size.height },
+        content_size: result.content_size,
         location: Point { x: 0.0, y: 0.0 },
+        baseline: result.baseline,
+        last_baseline: result.last_baseline,
+        visible: root.visibility == Visibility::Visible,
         children: Box::new(result.children),
-    };
-
-    round_layout(&mut layout, 0.0, 0.0);
-    layout
+    }
 }
 
 fn round_layout(layout: &mut layout::Node, abs_x: f32, abs_y: f32) {
@@ -140,19 +187,287 @@ fn round_layout(layout: &mut layout::Node, abs_x: f32, abs_y: f32) {
     layout.location.y = layout.location.y.round();
     layout.size.width = (abs_x + layout.size.width).round() - abs_x.round();
     layout.size.height = (abs_y + layout.size.height).round() - abs_y.round();
+    layout.content_size.width = (abs_x + layout.content_size.width).round() - abs_x.round();
+    layout.content_size.height = (abs_y + layout.content_size.height).round() - abs_y.round();
+    layout.baseline = (abs_y + layout.baseline).round() - abs_y.round();
+    layout.last_baseline = (abs_y + layout.last_baseline).round() - abs_y.round();
 
     layout.children.iter_mut().for_each(|child| round_layout(child, abs_x, abs_y));
 }
 
+fn hidden_layout(order: usize, node: &style::Node) -> layout::Node {
+    layout::Node {
+        order: order as u32,
+        size: Size { width: 0.0, height: 0.0 },
+        content_size: Size { width: 0.0, height: 0.0 },
+        location: Point { x: 0.0, y: 0.0 },
+        baseline: 0.0,
+        last_baseline: 0.0,
+        visible: false,
+        children: Box::new(
+            node.children.iter().enumerate().map(|(order, child)| hidden_layout(order, child)).collect(),
+        ),
+    }
+}
+
+// The padding and border of a container together, the offsets of its content box from its border box.
+fn padding_border(padding: Rect<f32>, border: Rect<f32>) -> Rect<f32> {
+    Rect {
+        start: padding.start + border.start,
+        end: padding.end + border.end,
+        top: padding.top + border.top,
+        bottom: padding.bottom + border.bottom,
+    }
+}
+
+// Children which are laid out by the algorithm of their container, rather than being hidden or
+// positioned absolutely.
+pub(super) fn is_in_flow(node: &style::Node) -> bool {
+    node.display != Display::None && node.position_type != PositionType::Absolute
+}
+
+// Lays out the children which are not displayed, given by index of the child.
+fn hidden_children<'a>(children: impl Iterator<Item = &'a style::Node>) -> Vec<layout::Node> {
+    children
+        .enumerate()
+        .filter(|(_, child)| child.display == Display::None)
+        .map(|(order, child)| hidden_layout(order, child))
+        .collect()
+}
+
+// Containers are laid out with the inline-start edge to the left. For right-to-left containers the
+// result is mirrored within the given width so that start edges, the main axis of rows, and the
+// cross axis of columns all begin at the right edge of the container instead.
+fn mirror_children(children: &mut [layout::Node], width: f32) {
+    children.iter_mut().for_each(|child| {
+        child.location.x = width - child.location.x - child.size.width;
+    });
+}
+
+// The size of a child together with the part of its content which overflows it, measured from the
+// location of the child before the layout of its container is mirrored. Scroll containers clip their
+// content. Content overflows toward the end edge of the child, so when the child has the opposite
+// direction of the layout it overflows toward the start edge of the container, where it can't be
+// scrolled to, and only the child itself counts across the inline axis.
+fn child_overflow(
+    child: &style::Node,
+    size: Size<f32>,
+    content_size: Size<f32>,
+    direction: Direction,
+    layout_direction: Direction,
+) -> Size<f32> {
+    if child.is_scroll_container() {
+        size
+    } else if child.direction(direction) != layout_direction {
+        Size { width: size.width, height: content_size.height }
+    } else {
+        content_size
+    }
+}
+
+// Completes the layout of a container from the layouts of its in-flow children, given by index in
+// the contents_children of the node, once its size is known. Absolutely positioned children are laid
+// out and the content size is made to cover the container. Layouts are mirrored for right-to-left
+// containers, except that constraint and user-defined layouts are physical so only their absolutely
+// positioned children are mirrored. Children which are not displayed are added last, and all of
+// them are nested back under the children with display: contents they were taken from.
+fn finish_layout(
+    node: &style::Node,
+    children: Vec<layout::Node>,
+    container_size: Size<f32>,
+    border: Rect<f32>,
+    static_position: &dyn Fn(usize, Size<f32>) -> Point<f32>,
+    direction: Direction,
+    content_size: Size<f32>,
+) -> (Vec<layout::Node>, Size<f32>) {
+    let mut children = children;
+    let mut content_size = content_size;
+    let child_nodes = contents_children(node);
+
+    let mut absolute_children = absolute_layout(
+        child_nodes.iter().copied(),
+        container_size,
+        border,
+        static_position,
+        direction,
+        &mut content_size,
+    );
+
+    let is_physical = node.algorithm.is_some() || node.display == Display::Constraints;
+    if direction == Direction::RTL && is_physical {
+        mirror_children(&mut absolute_children, container_size.width);
+    }
+
+    children.append(&mut absolute_children);
+
+    if direction == Direction::RTL && !is_physical {
+        mirror_children(&mut children, container_size.width);
+    }
+
+    children.append(&mut hidden_children(child_nodes.iter().copied()));
+    children.sort_by_key(|child| child.order);
+
+    let content_size = Size {
+        width: content_size.width.max(container_size.width),
+        height: content_size.height.max(container_size.height),
+    };
+
+    (contents_layout(node, &mut children.into_iter()), content_size)
+}
+
+// Lays out the absolutely positioned children of a container. They are positioned relative to the
+// border box of the container, and children without insets are placed at their static position,
+// given by index and size of the child. The overflow of the children is added to the content size
+// of the container.
+fn absolute_layout<'a>(
+    children: impl Iterator<Item = &'a style::Node>,
+    container_size: Size<f32>,
+    border: Rect<f32>,
+    static_position: &dyn Fn(usize, Size<f32>) -> Point<f32>,
+    direction: Direction,
+    content_size: &mut Size<f32>,
+) -> Vec<layout::Node> {
+    children
+        .enumerate()
+        .filter(|(_, child)| child.position_type == PositionType::Absolute && child.display != Display::None)
+        .map(|(order, child)| {
+            let container_width = container_size.width.to_number();
+            let container_height = container_size.height.to_number();
+
+            let start = child.position.start.resolve(container_width) + child.margin.start.resolve(container_width);
+            let end = child.position.end.resolve(container_width) + child.margin.end.resolve(container_width);
+            let top = child.position.top.resolve(container_height) + child.margin.top.resolve(container_height);
+            let bottom =
+                child.position.bottom.resolve(container_height) + child.margin.bottom.resolve(container_height);
+
+            let width = child
+                .size
+                .width
+                .resolve(container_width)
+                .maybe_max(child.min_size.width.resolve(container_width))
+                .maybe_min(child.max_size.width.resolve(container_width))
+                .or_else(container_width - start - end);
+
+            let height = child
+                .size
+                .height
+                .resolve(container_height)
+                .maybe_max(child.min_size.height.resolve(container_height))
+                .maybe_min(child.max_size.height.resolve(container_height))
+                .or_else(container_height - top - bottom);
+
+            let result = compute_internal(
+                child,
+                Size { width, height },
+                container_size.map(AvailableSpace::Definite),
+                container_width,
+                direction,
+            );
+
+            let static_position = static_position(order, result.size);
+
+            let location = Point {
+                x: if start.is_defined() {
+                    start.or_else(0.0) + border.start
+                } else if end.is_defined() {
+                    container_size.width - result.size.width - end.or_else(0.0) - border.end
+                } else {
+                    static_position.x + child.margin.start.resolve(container_width).or_else(0.0)
+                },
+                y: if top.is_defined() {
+                    top.or_else(0.0) + border.top
+                } else if bottom.is_defined() {
+                    container_size.height - result.size.height - bottom.or_else(0.0) - border.bottom
+                } else {
+                    static_position.y + child.margin.top.resolve(container_width).or_else(0.0)
+                },
+            };
+
+            let overflow_size = child_overflow(child, result.size, result.content_size, direction, direction);
+
+            content_size.width = content_size.width.max(location.x + overflow_size.width);
+            content_size.height = content_size.height.max(location.y + overflow_size.height);
+
+            layout::Node {
+                order: order as u32,
+                size: result.size,
+                content_size: result.content_size,
+                location,
+                baseline: result.baseline,
+                last_baseline: result.last_baseline,
+                visible: child.visibility == Visibility::Visible,
+                children: Box::new(result.children),
+            }
+        })
+        .collect()
+}
+
+// The children of a node with those which have display: contents replaced by their own children,
+// recursively, as they don't generate a box of their own.
+fn contents_children(node: &style::Node) -> Vec<&style::Node> {
+    node.children
+        .iter()
+        .flat_map(|child| if child.display == Display::Contents { contents_children(child) } else { vec![child] })
+        .collect()
+}
+
+// Nests the layouts of the children returned by contents_children under layouts for the children
+// with display: contents they were taken from. These are empty boxes at the origin of the container,
+// so the locations of the layouts nested under them stay relative to the container.
+fn contents_layout(node: &style::Node, layouts: &mut dyn Iterator<Item = layout::Node>) -> Vec<layout::Node> {
+    node.children
+        .iter()
+        .enumerate()
+        .map(|(order, child)| {
+            if child.display == Display::Contents {
+                layout::Node {
+                    order: order as u32,
+                    size: Size { width: 0.0, height: 0.0 },
+                    content_size: Size { width: 0.0, height: 0.0 },
+                    location: Point { x: 0.0, y: 0.0 },
+                    baseline: 0.0,
+                    last_baseline: 0.0,
+                    visible: child.visibility == Visibility::Visible,
+                    children: Box::new(contents_layout(child, layouts)),
+                }
+            } else {
+                layout::Node { order: order as u32, ..layouts.next().unwrap() }
+            }
+        })
+        .collect()
+}
+
+// Takes the layouts nested by contents_layout back out from under the children with display:
+// contents, in the order of the children returned by contents_children.
+fn flatten_contents_layout(node: &style::Node, layouts: Vec<layout::Node>) -> Vec<layout::Node> {
+    let mut flattened: Vec<layout::Node> = node
+        .children
+        .iter()
+        .zip(layouts)
+        .flat_map(|(child, layout)| {
+            if child.display == Display::Contents {
+                flatten_contents_layout(child, *layout.children)
+            } else {
+                vec![layout]
+            }
+        })
+        .collect();
+
+    flattened.iter_mut().enumerate().for_each(|(order, layout)| layout.order = order as u32);
+    flattened
+}
+
 fn compute_internal(
     node: &style::Node,
     node_size: Size<Number>,
-    parent_size: Size<Number>,
+    parent_size: Size<AvailableSpace>,
     percent_calc_base: Number,
+    parent_direction: Direction,
 ) -> ComputeResult {
     // Define some general constants we will need for the remainder
     // of the algorithm.
 
+    let direction = node.direction(parent_direction);
     let dir = node.flex_direction;
     let is_row = dir.is_row();
     let is_column = dir.is_column();
@@ -162,11 +477,46 @@ fn compute_internal(
     let padding = node.padding.map(|n| n.resolve(percent_calc_base).or_else(0.0));
     let border = node.border.map(|n| n.resolve(percent_calc_base).or_else(0.0));
 
-    let padding_border = Rect {
-        start: padding.start + border.start,
-        end: padding.end + border.end,
-        top: padding.top + border.top,
-        bottom: padding.bottom + border.bottom,
+    // Scrollbars sit between the border and the padding of a scroll container, so the space reserved
+    // for them is treated as part of its border. Start and end are flow-relative which places the
+    // scrollbar on the left edge of right-to-left containers.
+    let scrollbar_gutter = node.scrollbar_gutter();
+    let border = Rect {
+        start: border.start + scrollbar_gutter.start,
+        end: border.end + scrollbar_gutter.end,
+        top: border.top + scrollbar_gutter.top,
+        bottom: border.bottom + scrollbar_gutter.bottom,
+    };
+
+    let padding_border = padding_border(padding, border);
+
+    // Inline flex containers without a width shrink to fit their content within the available space,
+    // rather than filling it. Their minimum and maximum content widths are found by laying them out
+    // under the respective constraint, which is also how they are sized when there is no definite
+    // space available.
+    let node_size = match (node.display, node_size.width, parent_size.width) {
+        (Display::InlineFlex, Undefined, AvailableSpace::Definite(available_width)) => {
+            let content_width = |width| {
+                compute_internal(
+                    node,
+                    node_size,
+                    Size { width, height: parent_size.height },
+                    percent_calc_base,
+                    parent_direction,
+                )
+                .size
+                .width
+            };
+
+            let min_content = content_width(AvailableSpace::MinContent);
+            let max_content = content_width(AvailableSpace::MaxContent);
+
+            Size {
+                width: Defined(max_content.min(min_content.max(available_width - margin.horizontal()))),
+                height: node_size.height,
+            }
+        }
+        _ => node_size,
     };
 
     let node_inner_size = Size {
@@ -174,7 +524,114 @@ fn compute_internal(
         height: node_size.height - padding_border.vertical(),
     };
 
+    // Leaf nodes with a measure function size themselves to their content. Any dimension of the
+    // content box which is already known is passed along so that e.g. text can do height-for-width
+    // sizing, and padding and border are added around the measured content.
+    if let Some(ref measure) = node.measure {
+        if node.children.is_empty() {
+            let size = match node_size {
+                Size { width: Defined(width), height: Defined(height) } => Size { width, height },
+                _ => {
+                    let measured = measure(
+                        node_inner_size,
+                        Size {
+                            width: parent_size.width - margin.horizontal() - padding_border.horizontal(),
+                            height: parent_size.height - margin.vertical() - padding_border.vertical(),
+                        },
+                    );
+                    Size {
+                        width: node_size.width.or_else(
+                            (measured.width + padding_border.horizontal())
+                                .maybe_max(node.min_size.width.resolve(percent_calc_base))
+                                .maybe_min(node.max_size.width.resolve(percent_calc_base)),
+                        ),
+                        height: node_size.height.or_else(
+                            (measured.height + padding_border.vertical())
+                                .maybe_max(node.min_size.height.resolve(percent_calc_base))
+                                .maybe_min(node.max_size.height.resolve(percent_calc_base)),
+                        ),
+                    }
+                }
+            };
+
+            // Measured content has no baselines of its own so they are synthesized from the bottom
+            // edge of its border box.
+            return ComputeResult {
+                size,
+                content_size: size,
+                baseline: size.height,
+                last_baseline: size.height,
+                children: vec![],
+            };
+        }
+    }
+
+    // Nodes with a user-defined layout algorithm lay out their children with it regardless of
+    // their display.
+    if node.algorithm.is_some() {
+        return custom::compute(node, node_size, parent_size, direction, margin, padding, border);
+    }
+
+    // Runs of text outside of an inline formatting context are laid out in lines of their own.
+    if inline::is_text(node) {
+        let available_size =
+            Size { width: parent_size.width - margin.horizontal(), height: parent_size.height - margin.vertical() };
+        return inline::compute_text(node, node_size, available_size, direction);
+    }
+
+    match node.display {
+        Display::Grid => return grid::compute(node, node_size, parent_size, direction, margin, padding, border),
+        Display::Constraints => {
+            return constraints::compute(node, node_size, parent_size, direction, margin, padding, border)
+        }
+        Display::Stack => return stack::compute(node, node_size, parent_size, direction, margin, padding, border),
+        Display::Masonry => {
+            return grid::masonry::compute(node, node_size, parent_size, direction, margin, padding, border)
+        }
+        Display::Table => return table::compute(node, node_size, parent_size, direction, margin, padding, border),
+        Display::Block | Display::InlineBlock if node.is_multicol() => {
+            return multicol::compute(node, node_size, parent_size, direction, margin, padding, border)
+        }
+        Display::Block
+        | Display::Inline
+        | Display::InlineBlock
+        | Display::TableRowGroup
+        | Display::TableRow
+        | Display::TableCell
+        | Display::TableColumn => {
+            return block::compute(node, node_size, parent_size, direction, margin, padding, border)
+        }
+        _ => (),
+    }
+
     let percent_calc_base_child = node_inner_size.width;
+
+    // The children of display: contents children take their place, so they are laid out as items of
+    // this container. Layouts are ordered by their position in this list until they are nested back
+    // under the layouts of the children they were taken from.
+    let child_nodes = contents_children(node);
+
+    // Gaps are resolved against the inner size of the container in the respective dimension.
+    // The width is the gap between columns and the height the gap between rows.
+    let gap = Size {
+        width: node.gap.width.resolve(node_inner_size.width).or_else(0.0),
+        height: node.gap.height.resolve(node_inner_size.height).or_else(0.0),
+    };
+
+    let main_gap = gap.main(dir);
+    let cross_gap = gap.cross(dir);
+
+    // 8.3. An item participates in first or last baseline alignment when its align-self is baseline
+    // or last baseline respectively, its cross-axis margins are both non-auto, and its inline axis
+    // is parallel to the main axis. Items in a column have no baseline in the cross axis so they
+    // fall back to flex-start, or flex-end for last baseline alignment.
+    let is_baseline_aligned = |child: &FlexItem, baseline: AlignSelf| {
+        is_row
+            && child.node.align_self(node) == baseline
+            && child.node.cross_margin_start(dir) != Dimension::Auto
+            && child.node.cross_margin_end(dir) != Dimension::Auto
+    };
+
     let mut container_size = Size { width: 0.0, height: 0.0 };
     let mut inner_container_size = Size { width: 0.0, height: 0.0 };
 
@@ -195,567 +652,685 @@ fn compute_internal(
         height: node_size.height.or_else(parent_size.height - margin.vertical()) - padding_border.vertical(),
     };
 
-    let mut flex_items: Vec<FlexItem> = node
-        .children
-        .iter()
-        .filter(|child| child.position_type != PositionType::Absolute)
-        .filter(|child| child.display != Display::None)
-        .map(|child| FlexItem {
-            node: child,
-
-            size: child.size.map(|s| s.resolve(percent_calc_base_child)),
-            min_size: child.min_size.map(|s| s.resolve(percent_calc_base_child)),
-            max_size: child.max_size.map(|s| s.resolve(percent_calc_base_child)),
-
-            position: child.position.map(|p| p.resolve(percent_calc_base_child)),
-            margin: child.margin.map(|m| m.resolve(percent_calc_base_child).or_else(0.0)),
-            padding: child.padding.map(|p| p.resolve(percent_calc_base_child).or_else(0.0)),
-            border: child.border.map(|b| b.resolve(percent_calc_base_child).or_else(0.0)),
-
-            flex_basis: 0.0,
-            inner_flex_basis: 0.0,
-            violation: 0.0,
-            frozen: false,
-
-            hypothetical_inner_size: Size { width: 0.0, height: 0.0 },
-            hypothetical_outer_size: Size { width: 0.0, height: 0.0 },
-            target_size: Size { width: 0.0, height: 0.0 },
-            outer_target_size: Size { width: 0.0, height: 0.0 },
-
-            baseline: 0.0,
-
-            offset_main: 0.0,
-            offset_cross: 0.0,
-        })
-        .collect();
+    // Laying out visibility:collapse items takes two rounds, see step 10 below. During the first round
+    // we note the strut size of every collapsed item, indexed by its position in the node's children.
+    let mut struts: Vec<Option<f32>> = vec![];
 
-    // TODO - this does not follow spec. See commented out code below
-    // 3. Determine the flex base size and hypothetical main size of each item:
-    flex_items.iter_mut().for_each(|child| {
-        // A. If the item has a definite used flex basis, that’s the flex base size.
+    let mut flex_lines = loop {
+        let is_second_round = !struts.is_empty();
 
-        let flex_basis = child.node.flex_basis.resolve(percent_calc_base_child);
-        if flex_basis.is_defined() {
-            child.flex_basis = flex_basis.or_else(0.0);
-            return;
-        };
+        let mut flex_items: Vec<FlexItem> = child_nodes
+            .iter()
+            .copied()
+            .filter(|child| child.position_type != PositionType::Absolute)
+            .filter(|child| child.display != Display::None)
+            .map(|child| FlexItem {
+                node: child,
+
+                size: child.size.map(|s| s.resolve(percent_calc_base_child)),
+                min_size: child.min_size.map(|s| s.resolve(percent_calc_base_child)),
+                max_size: child.max_size.map(|s| s.resolve(percent_calc_base_child)),
+
+                position: child.position.map(|p| p.resolve(percent_calc_base_child)),
+                margin: child.margin.map(|m| m.resolve(percent_calc_base_child).or_else(0.0)),
+                padding: child.padding.map(|p| p.resolve(percent_calc_base_child).or_else(0.0)),
+                border: child.border.map(|b| b.resolve(percent_calc_base_child).or_else(0.0)),
+
+                flex_basis: 0.0,
+                inner_flex_basis: 0.0,
+                violation: 0.0,
+                frozen: false,
+
+                hypothetical_inner_size: Size { width: 0.0, height: 0.0 },
+                hypothetical_outer_size: Size { width: 0.0, height: 0.0 },
+                target_size: Size { width: 0.0, height: 0.0 },
+                outer_target_size: Size { width: 0.0, height: 0.0 },
+
+                baseline: 0.0,
+                last_baseline: 0.0,
+
+                offset_main: 0.0,
+                offset_cross: 0.0,
+            })
+            .collect();
+
+        // Flex items are laid out in order-modified document order. The sort is stable so items
+        // sharing the same order keep their source order. Layouts are sorted back into source order
+        // before returning.
+        flex_items.sort_by_key(|child| child.node.order);
+
+        // TODO - this does not follow spec. See commented out code below
+        // 3. Determine the flex base size and hypothetical main size of each item:
+        flex_items.iter_mut().for_each(|child| {
+            // A. If the item has a definite used flex basis, that’s the flex base size.
+
+            let flex_basis = child.node.flex_basis.resolve(percent_calc_base_child);
+            if flex_basis.is_defined() {
+                child.flex_basis = flex_basis.or_else(0.0);
+                return;
+            };
 
-        // B. If the flex item has an intrinsic aspect ratio,
-        //    a used flex basis of content, and a definite cross size,
-        //    then the flex base size is calculated from its inner
-        //    cross size and the flex item’s intrinsic aspect ratio.
+            // B. If the flex item has an intrinsic aspect ratio,
+            //    a used flex basis of content, and a definite cross size,
+            //    then the flex base size is calculated from its inner
+            //    cross size and the flex item’s intrinsic aspect ratio.
 
-        if let Defined(ratio) = child.node.aspect_ratio {
-            if let Defined(cross) = node_size.cross(dir) {
-                if child.node.flex_basis == Dimension::Auto {
-                    child.flex_basis = cross * ratio;
-                    return;
+            if let Defined(ratio) = child.node.aspect_ratio {
+                if let Defined(cross) = node_size.cross(dir) {
+                    if child.node.flex_basis == Dimension::Auto {
+                        child.flex_basis = cross * ratio;
+                        return;
+                    }
                 }
             }
-        }
 
-        // C. If the used flex basis is content or depends on its available space,
-        //    and the flex container is being sized under a min-content or max-content
-        //    constraint (e.g. when performing automatic table layout [CSS21]),
-        //    size the item under that constraint. The flex base size is the item’s
-        //    resulting main size.
+            // C. If the used flex basis is content or depends on its available space,
+            //    and the flex container is being sized under a min-content or max-content
+            //    constraint (e.g. when performing automatic table layout [CSS21]),
+            //    size the item under that constraint. The flex base size is the item’s
+            //    resulting main size.
 
-        // TODO - Probably need to cover this case in future
+            //    The constraint is carried by `available_space`, so sizing the item into it
+            //    as in E below sizes the item under that same constraint.
 
-        // D. Otherwise, if the used flex basis is content or depends on its
-        //    available space, the available main size is infinite, and the flex item’s
-        //    inline axis is parallel to the main axis, lay the item out using the rules
-        //    for a box in an orthogonal flow [CSS3-WRITING-MODES]. The flex base size
-        //    is the item’s max-content main size.
+            // D. Otherwise, if the used flex basis is content or depends on its
+            //    available space, the available main size is infinite, and the flex item’s
+            //    inline axis is parallel to the main axis, lay the item out using the rules
+            //    for a box in an orthogonal flow [CSS3-WRITING-MODES]. The flex base size
+            //    is the item’s max-content main size.
 
-        // TODO - Probably need to cover this case in future
+            //    An infinite available main size is `AvailableSpace::MaxContent`, so this is
+            //    also handled by E below.
 
-        // E. Otherwise, size the item into the available space using its used flex basis
-        //    in place of its main size, treating a value of content as max-content.
-        //    If a cross size is needed to determine the main size (e.g. when the
-        //    flex item’s main size is in its block axis) and the flex item’s cross size
-        //    is auto and not definite, in this calculation use fit-content as the
-        //    flex item’s cross size. The flex base size is the item’s resulting main size.
+            // E. Otherwise, size the item into the available space using its used flex basis
+            //    in place of its main size, treating a value of content as max-content.
+            //    If a cross size is needed to determine the main size (e.g. when the
+            //    flex item’s main size is in its block axis) and the flex item’s cross size
+            //    is auto and not definite, in this calculation use fit-content as the
+            //    flex item’s cross size. The flex base size is the item’s resulting main size.
 
-        child.flex_basis = compute_internal(
-            child.node,
-            Size {
+            let mut size = Size {
                 width: child.size.width.maybe_max(child.min_size.width).maybe_min(child.max_size.width),
                 height: child.size.height.maybe_max(child.min_size.height).maybe_min(child.max_size.height),
-            },
-            available_space,
-            percent_calc_base_child,
-        )
-        .size
-        .main(dir)
-        .maybe_max(child.min_size.main(dir))
-        .maybe_min(child.max_size.main(dir));
-    });
+            };
 
-    // The hypothetical main size is the item’s flex base size clamped according to its
-    // used min and max main sizes (and flooring the content box size at zero).
+            // A stretched item will end up with the container's inner cross size so lay it out
+            // with that size, this lets measured content such as text do height-for-width sizing.
+            if size.cross(dir).is_undefined()
+                && child.node.align_self(node) == AlignSelf::Stretch
+                && child.node.cross_margin_start(dir) != Dimension::Auto
+                && child.node.cross_margin_end(dir) != Dimension::Auto
+            {
+                if let Defined(cross) = node_inner_size.cross(dir) {
+                    size.set_cross(
+                        dir,
+                        (cross - child.margin.cross(dir))
+                            .maybe_max(child.min_size.cross(dir))
+                            .maybe_min(child.max_size.cross(dir))
+                            .to_number(),
+                    );
+                }
+            }
 
-    flex_items.iter_mut().for_each(|child| {
-        child.inner_flex_basis = child.flex_basis - child.padding.main(dir) - child.border.main(dir);
+            child.flex_basis = compute_internal(child.node, size, available_space, percent_calc_base_child, direction)
+                .size
+                .main(dir)
+                .maybe_max(child.min_size.main(dir))
+                .maybe_min(child.max_size.main(dir));
+        });
 
-        // TODO - not really spec abiding but needs to be done somewhere. probably somewhere else though.
-        // The following logic was developed not from the spec but by trail and error looking into how
-        // webkit handled various scenarios. Can probably be solved better by passing in
-        // min-content max-content constraints fromt the top
-        let min_main = if is_row {
-            compute_internal(
-                child.node,
-                Size { width: Undefined, height: Undefined },
-                available_space,
-                percent_calc_base_child,
-            )
-            .size
-            .width
-            .maybe_max(child.min_size.width)
-            .maybe_min(child.size.width)
-            .to_number()
-        } else {
-            child.min_size.main(dir)
-        };
+        // The hypothetical main size is the item’s flex base size clamped according to its
+        // used min and max main sizes (and flooring the content box size at zero).
+
+        flex_items.iter_mut().for_each(|child| {
+            child.inner_flex_basis = child.flex_basis - child.padding.main(dir) - child.border.main(dir);
+
+            // 4.5. Automatic Minimum Size of Flex Items. An auto min main size resolves to the
+            // content-based minimum size of the item, unless the item is a scroll container.
+            if child.node.min_main_size(dir) == Dimension::Auto && !child.node.is_scroll_container() {
+                // The content size suggestion is the min-content size in the main axis. If the item
+                // has an aspect ratio and a definite cross size it is instead transferred through
+                // the aspect ratio from the cross size.
+                let content_size_suggestion = match (child.node.aspect_ratio, child.size.cross(dir)) {
+                    (Defined(ratio), Defined(cross)) => {
+                        cross.maybe_max(child.min_size.cross(dir)).maybe_min(child.max_size.cross(dir)) * ratio
+                    }
+                    _ => {
+                        let mut size = Size { width: Undefined, height: Undefined };
+                        size.set_cross(dir, child.size.cross(dir));
 
-        child
-            .hypothetical_inner_size
-            .set_main(dir, child.flex_basis.maybe_max(min_main).maybe_min(child.max_size.main(dir)));
+                        let mut available = available_space;
+                        available.set_main(dir, AvailableSpace::MinContent);
 
-        child.hypothetical_outer_size.set_main(dir, child.hypothetical_inner_size.main(dir) + child.margin.main(dir));
-    });
+                        compute_internal(child.node, size, available, percent_calc_base_child, direction).size.main(dir)
+                    }
+                };
 
-    // 9.3. Main Size Determination
+                // The specified size suggestion is the item's definite main size, the content-based
+                // minimum size is the smaller of the two suggestions.
+                let min_main =
+                    content_size_suggestion.maybe_min(child.max_size.main(dir)).maybe_min(child.size.main(dir));
 
-    // 5. Collect flex items into flex lines:
-    //    - If the flex container is single-line, collect all the flex items into
-    //      a single flex line.
-    //    - Otherwise, starting from the first uncollected item, collect consecutive
-    //      items one by one until the first time that the next collected item would
-    //      not fit into the flex container’s inner main size (or until a forced break
-    //      is encountered, see §10 Fragmenting Flex Layout). If the very first
-    //      uncollected item wouldn’t fit, collect just it into the line.
-    //
-    //      For this step, the size of a flex item is its outer hypothetical main size. (Note: This can be negative.)
-    //      Repeat until all flex items have been collected into flex lines
-    //
-    //      Note that the "collect as many" line will collect zero-sized flex items onto
-    //      the end of the previous line even if the last non-zero item exactly "filled up" the line.
+                child.min_size.set_main(dir, Defined(min_main));
+            }
 
-    let mut flex_lines = {
-        let mut lines: Vec<FlexLine> = vec![];
-        let mut line_length = 0.0;
+            child.hypothetical_inner_size.set_main(
+                dir,
+                child.flex_basis.maybe_max(child.min_size.main(dir)).maybe_min(child.max_size.main(dir)),
+            );
 
-        if node.flex_wrap == FlexWrap::NoWrap {
-            lines.push(FlexLine { items: flex_items, cross_size: 0.0, offset_cross: 0.0 });
-        } else {
-            let mut line = FlexLine { items: vec![], cross_size: 0.0, offset_cross: 0.0 };
+            child
+                .hypothetical_outer_size
+                .set_main(dir, child.hypothetical_inner_size.main(dir) + child.margin.main(dir));
+
+            // Collapsed items take up no main space in the second layout round, see step 10.
+            if is_second_round && child.node.visibility == Visibility::Collapse {
+                child.hypothetical_inner_size.set_main(dir, 0.0);
+                child.hypothetical_outer_size.set_main(dir, 0.0);
+            }
+        });
+
+        // 9.3. Main Size Determination
+
+        // 5. Collect flex items into flex lines:
+        //    - If the flex container is single-line, collect all the flex items into
+        //      a single flex line.
+        //    - Otherwise, starting from the first uncollected item, collect consecutive
+        //      items one by one until the first time that the next collected item would
+        //      not fit into the flex container’s inner main size (or until a forced break
+        //      is encountered, see §10 Fragmenting Flex Layout). If the very first
+        //      uncollected item wouldn’t fit, collect just it into the line.
+        //
+        //      For this step, the size of a flex item is its outer hypothetical main size. (Note: This can be negative.)
+        //      Repeat until all flex items have been collected into flex lines
+        //
+        //      Note that the "collect as many" line will collect zero-sized flex items onto
+        //      the end of the previous line even if the last non-zero item exactly "filled up" the line.
+
+        let mut flex_lines = {
+            let mut lines: Vec<FlexLine> = vec![];
+            let mut line_length = 0.0;
+
+            if node.flex_wrap == FlexWrap::NoWrap {
+                lines.push(FlexLine { items: flex_items, collapsed: vec![], cross_size: 0.0, offset_cross: 0.0 });
+            } else {
+                let mut line = FlexLine { items: vec![], collapsed: vec![], cross_size: 0.0, offset_cross: 0.0 };
 
-            for child in flex_items {
-                line_length += child.hypothetical_outer_size.main(dir);
+                for child in flex_items {
+                    let is_collapsed = is_second_round && child.node.visibility == Visibility::Collapse;
 
-                if let Defined(main) = available_space.main(dir) {
-                    if line_length > main && !line.items.is_empty() {
+                    if !line.items.is_empty() && !is_collapsed {
+                        line_length += main_gap;
+                    }
+
+                    line_length += child.hypothetical_outer_size.main(dir);
+
+                    // Under a min-content constraint every item goes onto its own line and under a
+                    // max-content constraint the line is never broken.
+                    let exceeds_line = match available_space.main(dir) {
+                        AvailableSpace::Definite(main) => line_length > main,
+                        AvailableSpace::MinContent => true,
+                        AvailableSpace::MaxContent => false,
+                    };
+
+                    if exceeds_line && !line.items.is_empty() {
                         line_length = child.hypothetical_outer_size.main(dir);
                         lines.push(line);
-                        line = FlexLine { items: vec![], cross_size: 0.0, offset_cross: 0.0 };
+                        line = FlexLine { items: vec![], collapsed: vec![], cross_size: 0.0, offset_cross: 0.0 };
                     }
+
+                    line.items.push(child);
                 }
 
-                line.items.push(child);
+                lines.push(line);
             }
 
-            lines.push(line);
-        }
-
-        lines
-    };
-
-    // 6. Resolve the flexible lengths of all the flex items to find their used main size.
-    //    See §9.7 Resolving Flexible Lengths.
-    //
-    // 9.7. Resolving Flexible Lengths
-
-    flex_lines.iter_mut().for_each(|line| {
-        // 1. Determine the used flex factor. Sum the outer hypothetical main sizes of all
-        //    items on the line. If the sum is less than the flex container’s inner main size,
-        //    use the flex grow factor for the rest of this algorithm; otherwise, use the
-        //    flex shrink factor.
-
-        let used_flex_factor: f32 = line.items.iter().map(|child| child.hypothetical_outer_size.main(dir)).sum();
-        let growing = used_flex_factor < node_inner_size.main(dir).or_else(0.0);
-        let shrinking = !growing;
-
-        // 2. Size inflexible items. Freeze, setting its target main size to its hypothetical main size
-        //    - Any item that has a flex factor of zero
-        //    - If using the flex grow factor: any item that has a flex base size
-        //      greater than its hypothetical main size
-        //    - If using the flex shrink factor: any item that has a flex base size
-        //      smaller than its hypothetical main size
+            lines
+        };
 
-        line.items.iter_mut().for_each(|child| {
-            // TODO - This is not found by reading the spec. Maybe this can be done in some other place
-            // instead. This was found by trail and error fixing tests to align with webkit output.
-            if node_inner_size.main(dir).is_undefined() && is_row {
-                child.target_size.set_main(
-                    dir,
-                    compute_internal(
-                        child.node,
-                        Size {
-                            width: child.size.width.maybe_max(child.min_size.width).maybe_min(child.max_size.width),
-                            height: child.size.height.maybe_max(child.min_size.height).maybe_min(child.max_size.height),
-                        },
-                        available_space,
-                        percent_calc_base_child,
-                    )
-                    .size
-                    .main(dir)
-                    .maybe_max(child.min_size.main(dir))
-                    .maybe_min(child.max_size.main(dir)),
-                );
-            } else {
-                child.target_size.set_main(dir, child.hypothetical_inner_size.main(dir));
-            }
+        // Past collecting items into lines the second layout round ignores collapsed items entirely,
+        // apart from their strut sizes, so move them out of the way.
+        if is_second_round {
+            flex_lines.iter_mut().for_each(|line| {
+                let items = std::mem::take(&mut line.items);
+                let (collapsed, items) =
+                    items.into_iter().partition(|child| child.node.visibility == Visibility::Collapse);
+                line.items = items;
+                line.collapsed = collapsed;
+            });
+        }
 
-            // TODO this should really only be set inside the if-statement below but
-            // that casues the target_main_size to never be set for some items
+        // 9.9.1. Flex Container Intrinsic Main Sizes. A row container sized under a min-content or
+        // max-content constraint is as wide as the content contributions of the items on its longest
+        // line under that constraint, and its items are flexed to fit that size.
+        let content_contribution = |child: &FlexItem| {
+            let content_size = compute_internal(
+                child.node,
+                Size {
+                    width: child.size.width.maybe_max(child.min_size.width).maybe_min(child.max_size.width),
+                    height: child.size.height.maybe_max(child.min_size.height).maybe_min(child.max_size.height),
+                },
+                available_space,
+                percent_calc_base_child,
+                direction,
+            )
+            .size;
 
-            child.outer_target_size.set_main(dir, child.target_size.main(dir) + child.margin.main(dir));
+            content_size.main(dir).maybe_max(child.min_size.main(dir)).maybe_min(child.max_size.main(dir))
+                + child.margin.main(dir)
+        };
 
-            if (child.node.flex_grow == 0.0 && child.node.flex_shrink == 0.0)
-                || (growing && child.flex_basis > child.hypothetical_inner_size.main(dir))
-                || (shrinking && child.flex_basis < child.hypothetical_inner_size.main(dir))
-            {
-                child.frozen = true;
+        let inner_main_size = match node_inner_size.main(dir) {
+            Undefined if is_row && !available_space.main(dir).is_definite() => {
+                Defined(flex_lines.iter().fold(0.0, |acc: f32, line| {
+                    let contributions: f32 = line.items.iter().map(content_contribution).sum();
+                    acc.max(contributions + main_gap * (line.items.len().max(1) - 1) as f32)
+                }))
             }
-        });
-
-        // 3. Calculate initial free space. Sum the outer sizes of all items on the line,
-        //    and subtract this from the flex container’s inner main size. For frozen items,
-        //    use their outer target main size; for other items, use their outer flex base size.
+            inner_main_size => inner_main_size,
+        };
 
-        let used_space: f32 = line
-            .items
-            .iter()
-            .map(|child| {
-                child.margin.main(dir) + if child.frozen { child.target_size.main(dir) } else { child.flex_basis }
-            })
-            .sum();
+        // 6. Resolve the flexible lengths of all the flex items to find their used main size.
+        //    See §9.7 Resolving Flexible Lengths.
+        //
+        // 9.7. Resolving Flexible Lengths
 
-        let initial_free_space = (node_inner_size.main(dir) - used_space).or_else(0.0);
+        flex_lines.iter_mut().for_each(|line| {
+            // Gaps between items take up space on the line just like the items themselves do.
+            let total_main_gap = main_gap * (line.items.len().max(1) - 1) as f32;
+
+            // 1. Determine the used flex factor. Sum the outer hypothetical main sizes of all
+            //    items on the line. If the sum is less than the flex container’s inner main size,
+            //    use the flex grow factor for the rest of this algorithm; otherwise, use the
+            //    flex shrink factor.
+
+            let used_flex_factor: f32 =
+                total_main_gap + line.items.iter().map(|child| child.hypothetical_outer_size.main(dir)).sum::<f32>();
+            let growing = used_flex_factor < inner_main_size.or_else(0.0);
+            let shrinking = !growing;
+
+            // 2. Size inflexible items. Freeze, setting its target main size to its hypothetical main size
+            //    - Any item that has a flex factor of zero
+            //    - If using the flex grow factor: any item that has a flex base size
+            //      greater than its hypothetical main size
+            //    - If using the flex shrink factor: any item that has a flex base size
+            //      smaller than its hypothetical main size
 
-        // 4. Loop
+            line.items.iter_mut().for_each(|child| {
+                child.target_size.set_main(dir, child.hypothetical_inner_size.main(dir));
 
-        loop {
-            // a. Check for flexible items. If all the flex items on the line are frozen,
-            //    free space has been distributed; exit this loop.
+                // TODO this should really only be set inside the if-statement below but
+                // that casues the target_main_size to never be set for some items
 
-            let mut frozen: Vec<&mut FlexItem> = vec![];
-            let mut unfrozen: Vec<&mut FlexItem> = vec![];
+                child.outer_target_size.set_main(dir, child.target_size.main(dir) + child.margin.main(dir));
 
-            line.items.iter_mut().for_each(|child| {
-                if child.frozen {
-                    frozen.push(child);
-                } else {
-                    unfrozen.push(child);
+                if (child.node.flex_grow == 0.0 && child.node.flex_shrink == 0.0)
+                    || (growing && child.flex_basis > child.hypothetical_inner_size.main(dir))
+                    || (shrinking && child.flex_basis < child.hypothetical_inner_size.main(dir))
+                {
+                    child.frozen = true;
                 }
             });
 
-            if unfrozen.is_empty() {
-                break;
-            }
+            // 3. Calculate initial free space. Sum the outer sizes of all items on the line,
+            //    and subtract this from the flex container’s inner main size. For frozen items,
+            //    use their outer target main size; for other items, use their outer flex base size.
 
-            // b. Calculate the remaining free space as for initial free space, above.
-            //    If the sum of the unfrozen flex items’ flex factors is less than one,
-            //    multiply the initial free space by this sum. If the magnitude of this
-            //    value is less than the magnitude of the remaining free space, use this
-            //    as the remaining free space.
+            let used_space: f32 = total_main_gap
+                + line
+                    .items
+                    .iter()
+                    .map(|child| {
+                        child.margin.main(dir)
+                            + if child.frozen { child.target_size.main(dir) } else { child.flex_basis }
+                    })
+                    .sum::<f32>();
 
-            let used_space: f32 = Iterator::chain(frozen.iter(), unfrozen.iter())
-                .map(|child| {
-                    child.margin.main(dir) + if child.frozen { child.target_size.main(dir) } else { child.flex_basis }
-                })
-                .sum();
+            let initial_free_space = (inner_main_size - used_space).or_else(0.0);
 
-            let sum_flex_grow: f32 = unfrozen.iter().map(|item| item.node.flex_grow).sum();
-            let sum_flex_shrink: f32 = unfrozen.iter().map(|item| item.node.flex_shrink).sum();
+            // 4. Loop
 
-            let free_space = if growing && sum_flex_grow < 1.0 {
-                (initial_free_space * sum_flex_grow).maybe_min(node_inner_size.main(dir) - used_space)
-            } else if shrinking && sum_flex_shrink < 1.0 {
-                (initial_free_space * sum_flex_shrink).maybe_max(node_inner_size.main(dir) - used_space)
-            } else {
-                (node_inner_size.main(dir) - used_space).or_else(0.0)
-            };
+            loop {
+                // a. Check for flexible items. If all the flex items on the line are frozen,
+                //    free space has been distributed; exit this loop.
 
-            // c. Distribute free space proportional to the flex factors.
-            //    - If the remaining free space is zero
-            //        Do Nothing
-            //    - If using the flex grow factor
-            //        Find the ratio of the item’s flex grow factor to the sum of the
-            //        flex grow factors of all unfrozen items on the line. Set the item’s
-            //        target main size to its flex base size plus a fraction of the remaining
-            //        free space proportional to the ratio.
-            //    - If using the flex shrink factor
-            //        For every unfrozen item on the line, multiply its flex shrink factor by
-            //        its inner flex base size, and note this as its scaled flex shrink factor.
-            //        Find the ratio of the item’s scaled flex shrink factor to the sum of the
-            //        scaled flex shrink factors of all unfrozen items on the line. Set the item’s
-            //        target main size to its flex base size minus a fraction of the absolute value
-            //        of the remaining free space proportional to the ratio. Note this may result
-            //        in a negative inner main size; it will be corrected in the next step.
-            //    - Otherwise
-            //        Do Nothing
-
-            if free_space.is_normal() {
-                if growing && sum_flex_grow > 0.0 {
-                    unfrozen.iter_mut().for_each(|child| {
-                        child
-                            .target_size
-                            .set_main(dir, child.flex_basis + free_space * (child.node.flex_grow / sum_flex_grow));
-                    });
-                } else if shrinking && sum_flex_shrink > 0.0 {
-                    let sum_scaled_shrink_factor: f32 =
-                        unfrozen.iter().map(|child| child.inner_flex_basis * child.node.flex_shrink).sum();
-
-                    if sum_scaled_shrink_factor > 0.0 {
-                        unfrozen.iter_mut().for_each(|child| {
-                            let scaled_shrink_factor = child.inner_flex_basis * child.node.flex_shrink;
-                            child.target_size.set_main(
-                                dir,
-                                child.flex_basis + free_space * (scaled_shrink_factor / sum_scaled_shrink_factor),
-                            )
-                        });
+                let mut frozen: Vec<&mut FlexItem> = vec![];
+                let mut unfrozen: Vec<&mut FlexItem> = vec![];
+
+                line.items.iter_mut().for_each(|child| {
+                    if child.frozen {
+                        frozen.push(child);
+                    } else {
+                        unfrozen.push(child);
                     }
+                });
+
+                if unfrozen.is_empty() {
+                    break;
                 }
-            }
 
-            // d. Fix min/max violations. Clamp each non-frozen item’s target main size by its
-            //    used min and max main sizes and floor its content-box size at zero. If the
-            //    item’s target main size was made smaller by this, it’s a max violation.
-            //    If the item’s target main size was made larger by this, it’s a min violation.
-
-            let total_violation = unfrozen.iter_mut().fold(0.0, |acc, child| {
-                // TODO - not really spec abiding but needs to be done somewhere. probably somewhere else though.
-                // The following logic was developed not from the spec but by trail and error looking into how
-                // webkit handled various scenarios. Can probably be solved better by passing in
-                // min-content max-content constraints fromt the top
-                let min_main = if is_row {
-                    compute_internal(
-                        child.node,
-                        Size { width: Undefined, height: Undefined },
-                        available_space,
-                        percent_calc_base_child,
-                    )
-                    .size
-                    .width
-                    .maybe_min(child.size.width)
-                    .maybe_max(child.min_size.width)
-                    .to_number()
+                // b. Calculate the remaining free space as for initial free space, above.
+                //    If the sum of the unfrozen flex items’ flex factors is less than one,
+                //    multiply the initial free space by this sum. If the magnitude of this
+                //    value is less than the magnitude of the remaining free space, use this
+                //    as the remaining free space.
+
+                let used_space: f32 = total_main_gap
+                    + Iterator::chain(frozen.iter(), unfrozen.iter())
+                        .map(|child| {
+                            child.margin.main(dir)
+                                + if child.frozen { child.target_size.main(dir) } else { child.flex_basis }
+                        })
+                        .sum::<f32>();
+
+                let sum_flex_grow: f32 = unfrozen.iter().map(|item| item.node.flex_grow).sum();
+                let sum_flex_shrink: f32 = unfrozen.iter().map(|item| item.node.flex_shrink).sum();
+
+                let free_space = if growing && sum_flex_grow < 1.0 {
+                    (initial_free_space * sum_flex_grow).maybe_min(inner_main_size - used_space)
+                } else if shrinking && sum_flex_shrink < 1.0 {
+                    (initial_free_space * sum_flex_shrink).maybe_max(inner_main_size - used_space)
                 } else {
-                    child.min_size.main(dir)
+                    (inner_main_size - used_space).or_else(0.0)
                 };
 
-                let max_main = child.max_size.main(dir);
-                let clamped = child.target_size.main(dir).maybe_min(max_main).maybe_max(min_main).max(0.0);
-                child.violation = clamped - child.target_size.main(dir);
-                child.target_size.set_main(dir, clamped);
-                child.outer_target_size.set_main(dir, child.target_size.main(dir) + child.margin.main(dir));
+                // c. Distribute free space proportional to the flex factors.
+                //    - If the remaining free space is zero
+                //        Do Nothing
+                //    - If using the flex grow factor
+                //        Find the ratio of the item’s flex grow factor to the sum of the
+                //        flex grow factors of all unfrozen items on the line. Set the item’s
+                //        target main size to its flex base size plus a fraction of the remaining
+                //        free space proportional to the ratio.
+                //    - If using the flex shrink factor
+                //        For every unfrozen item on the line, multiply its flex shrink factor by
+                //        its inner flex base size, and note this as its scaled flex shrink factor.
+                //        Find the ratio of the item’s scaled flex shrink factor to the sum of the
+                //        scaled flex shrink factors of all unfrozen items on the line. Set the item’s
+                //        target main size to its flex base size minus a fraction of the absolute value
+                //        of the remaining free space proportional to the ratio. Note this may result
+                //        in a negative inner main size; it will be corrected in the next step.
+                //    - Otherwise
+                //        Do Nothing
+
+                if free_space.is_normal() {
+                    if growing && sum_flex_grow > 0.0 {
+                        unfrozen.iter_mut().for_each(|child| {
+                            child
+                                .target_size
+                                .set_main(dir, child.flex_basis + free_space * (child.node.flex_grow / sum_flex_grow));
+                        });
+                    } else if shrinking && sum_flex_shrink > 0.0 {
+                        let sum_scaled_shrink_factor: f32 =
+                            unfrozen.iter().map(|child| child.inner_flex_basis * child.node.flex_shrink).sum();
+
+                        if sum_scaled_shrink_factor > 0.0 {
+                            unfrozen.iter_mut().for_each(|child| {
+                                let scaled_shrink_factor = child.inner_flex_basis * child.node.flex_shrink;
+                                child.target_size.set_main(
+                                    dir,
+                                    child.flex_basis + free_space * (scaled_shrink_factor / sum_scaled_shrink_factor),
+                                )
+                            });
+                        }
+                    }
+                }
 
-                acc + child.violation
-            });
+                // d. Fix min/max violations. Clamp each non-frozen item’s target main size by its
+                //    used min and max main sizes and floor its content-box size at zero. If the
+                //    item’s target main size was made smaller by this, it’s a max violation.
+                //    If the item’s target main size was made larger by this, it’s a min violation.
 
-            // e. Freeze over-flexed items. The total violation is the sum of the adjustments
-            //    from the previous step ∑(clamped size - unclamped size). If the total violation is:
-            //    - Zero
-            //        Freeze all items.
-            //    - Positive
-            //        Freeze all the items with min violations.
-            //    - Negative
-            //        Freeze all the items with max violations.
-
-            unfrozen.iter_mut().for_each(|child| match total_violation {
-                v if v > 0.0 => child.frozen = child.violation > 0.0,
-                v if v < 0.0 => child.frozen = child.violation < 0.0,
-                _ => child.frozen = true,
-            })
+                let total_violation = unfrozen.iter_mut().fold(0.0, |acc, child| {
+                    let min_main = child.min_size.main(dir);
+                    let max_main = child.max_size.main(dir);
+                    let clamped = child.target_size.main(dir).maybe_min(max_main).maybe_max(min_main).max(0.0);
+                    child.violation = clamped - child.target_size.main(dir);
+                    child.target_size.set_main(dir, clamped);
+                    child.outer_target_size.set_main(dir, child.target_size.main(dir) + child.margin.main(dir));
 
-            // f. Return to the start of this loop.
-        }
-    });
+                    acc + child.violation
+                });
 
-    // Not part of the spec from what i can see but seems correct
-    container_size.set_main(
-        dir,
-        node_size.main(dir).or_else({
-            let longest_line = flex_lines.iter().fold(f32::MIN, |acc, line| {
-                let length: f32 = line.items.iter().map(|item| item.outer_target_size.main(dir)).sum();
-                acc.max(length)
-            });
+                // e. Freeze over-flexed items. The total violation is the sum of the adjustments
+                //    from the previous step ∑(clamped size - unclamped size). If the total violation is:
+                //    - Zero
+                //        Freeze all items.
+                //    - Positive
+                //        Freeze all the items with min violations.
+                //    - Negative
+                //        Freeze all the items with max violations.
+
+                unfrozen.iter_mut().for_each(|child| match total_violation {
+                    v if v > 0.0 => child.frozen = child.violation > 0.0,
+                    v if v < 0.0 => child.frozen = child.violation < 0.0,
+                    _ => child.frozen = true,
+                })
 
-            let size = longest_line + padding_border.main(dir);
-            match available_space.main(dir) {
-                Defined(val) if flex_lines.len() > 1 && size < val => val,
-                _ => size,
+                // f. Return to the start of this loop.
             }
-        }),
-    );
+        });
+
+        // Not part of the spec from what i can see but seems correct
+        container_size.set_main(
+            dir,
+            node_size.main(dir).or_else({
+                let longest_line = flex_lines.iter().fold(f32::MIN, |acc, line| {
+                    let length: f32 = line.items.iter().map(|item| item.outer_target_size.main(dir)).sum();
+                    acc.max(length + main_gap * (line.items.len().max(1) - 1) as f32)
+                });
 
-    inner_container_size.set_main(dir, container_size.main(dir) - padding_border.main(dir));
+                let size = longest_line + padding_border.main(dir);
+                match available_space.main(dir) {
+                    AvailableSpace::Definite(val) if flex_lines.len() > 1 && size < val => val,
+                    _ => size,
+                }
+            }),
+        );
 
-    // 9.4. Cross Size Determination
+        inner_container_size.set_main(dir, container_size.main(dir) - padding_border.main(dir));
 
-    // 7. Determine the hypothetical cross size of each item by performing layout with the
-    //    used main size and the available space, treating auto as fit-content.
+        // 9.4. Cross Size Determination
 
-    flex_lines.iter_mut().for_each(|line| {
-        line.items.iter_mut().for_each(|child| {
-            let child_cross =
-                child.size.cross(dir).maybe_max(child.min_size.cross(dir)).maybe_min(child.max_size.cross(dir));
+        // 7. Determine the hypothetical cross size of each item by performing layout with the
+        //    used main size and the available space, treating auto as fit-content.
 
-            child.hypothetical_inner_size.set_cross(
-                dir,
-                compute_internal(
+        flex_lines.iter_mut().for_each(|line| {
+            line.items.iter_mut().for_each(|child| {
+                let child_cross =
+                    child.size.cross(dir).maybe_max(child.min_size.cross(dir)).maybe_min(child.max_size.cross(dir));
+
+                child.hypothetical_inner_size.set_cross(
+                    dir,
+                    compute_internal(
+                        child.node,
+                        Size {
+                            width: if is_row { child.target_size.width.to_number() } else { child_cross },
+                            height: if is_row { child_cross } else { child.target_size.height.to_number() },
+                        },
+                        Size {
+                            width: if is_row {
+                                AvailableSpace::Definite(container_size.main(dir))
+                            } else {
+                                available_space.width
+                            },
+                            height: if is_row {
+                                available_space.height
+                            } else {
+                                AvailableSpace::Definite(container_size.main(dir))
+                            },
+                        },
+                        percent_calc_base_child,
+                        direction,
+                    )
+                    .size
+                    .cross(dir)
+                    .maybe_max(child.min_size.cross(dir))
+                    .maybe_min(child.max_size.cross(dir)),
+                );
+
+                child
+                    .hypothetical_outer_size
+                    .set_cross(dir, child.hypothetical_inner_size.cross(dir) + child.margin.cross(dir));
+            });
+        });
+
+        // TODO - This is expensive and should only be done if we really require a baseline. aka, make it lazy
+        flex_lines.iter_mut().for_each(|line| {
+            line.items.iter_mut().for_each(|child| {
+                let result = compute_internal(
                     child.node,
                     Size {
-                        width: if is_row { child.target_size.width.to_number() } else { child_cross },
-                        height: if is_row { child_cross } else { child.target_size.height.to_number() },
+                        width: if is_row {
+                            child.target_size.width.to_number()
+                        } else {
+                            child.hypothetical_inner_size.width.to_number()
+                        },
+                        height: if is_row {
+                            child.hypothetical_inner_size.height.to_number()
+                        } else {
+                            child.target_size.height.to_number()
+                        },
                     },
                     Size {
-                        width: if is_row { container_size.main(dir).to_number() } else { available_space.width },
-                        height: if is_row { available_space.height } else { container_size.main(dir).to_number() },
+                        width: if is_row {
+                            AvailableSpace::Definite(container_size.width)
+                        } else {
+                            node_size.width.into()
+                        },
+                        height: if is_row {
+                            node_size.height.into()
+                        } else {
+                            AvailableSpace::Definite(container_size.height)
+                        },
                     },
                     percent_calc_base_child,
-                )
-                .size
-                .cross(dir)
-                .maybe_max(child.min_size.cross(dir))
-                .maybe_min(child.max_size.cross(dir)),
-            );
+                    direction,
+                );
 
-            child
-                .hypothetical_outer_size
-                .set_cross(dir, child.hypothetical_inner_size.cross(dir) + child.margin.cross(dir));
+                child.baseline = result.baseline;
+                child.last_baseline = result.last_baseline;
+            });
         });
-    });
 
-    // TODO - probably should move this somewhere else as it doesn't make a ton of sense here but we need it below
-    // TODO - This is expensive and should only be done if we really require a baseline. aka, make it lazy
+        // 8. Calculate the cross size of each flex line.
+        //    If the flex container is single-line and has a definite cross size, the cross size
+        //    of the flex line is the flex container’s inner cross size. Otherwise, for each flex line:
+        //
+        //    If the flex container is single-line, then clamp the line’s cross-size to be within
+        //    the container’s computed min and max cross sizes. Note that if CSS 2.1’s definition
+        //    of min/max-width/height applied more generally, this behavior would fall out automatically.
 
-    fn calc_baseline(layout: &layout::Node) -> f32 {
-        if layout.children.is_empty() {
-            layout.size.height
+        if flex_lines.len() == 1 && node_size.cross(dir).is_defined() {
+            flex_lines[0].cross_size = (node_size.cross(dir) - padding_border.cross(dir)).or_else(0.0);
         } else {
-            calc_baseline(&layout.children[0])
-        }
-    };
-
-    flex_lines.iter_mut().for_each(|line| {
-        line.items.iter_mut().for_each(|child| {
-            let result = compute_internal(
-                child.node,
-                Size {
-                    width: if is_row {
-                        child.target_size.width.to_number()
-                    } else {
-                        child.hypothetical_inner_size.width.to_number()
-                    },
-                    height: if is_row {
-                        child.hypothetical_inner_size.height.to_number()
-                    } else {
-                        child.target_size.height.to_number()
-                    },
-                },
-                Size {
-                    width: if is_row { container_size.width.to_number() } else { node_size.width },
-                    height: if is_row { node_size.height } else { container_size.height.to_number() },
-                },
-                percent_calc_base_child,
-            );
+            flex_lines.iter_mut().for_each(|line| {
+                //    1. Collect all the flex items whose inline-axis is parallel to the main-axis, whose
+                //       align-self is baseline, and whose cross-axis margins are both non-auto. Find the
+                //       largest of the distances between each item’s baseline and its hypothetical outer
+                //       cross-start edge, and the largest of the distances between each item’s baseline
+                //       and its hypothetical outer cross-end edge, and sum these two values.
+
+                //       Items with align-self last baseline are collected separately in the same way
+                //       using their last baselines, as they share a different alignment baseline.
+
+                //    2. Among all the items not collected by the previous step, find the largest
+                //       outer hypothetical cross size.
+
+                //    3. The used cross-size of the flex line is the largest of the numbers found in the
+                //       previous two steps and zero.
+
+                let shared_baseline_size = |baseline: AlignSelf| {
+                    let ascent = |child: &FlexItem| {
+                        child.margin.cross_start(dir)
+                            + if baseline == AlignSelf::Baseline { child.baseline } else { child.last_baseline }
+                    };
+
+                    let baseline_items = line.items.iter().filter(|child| is_baseline_aligned(child, baseline));
+                    let max_ascent = baseline_items.clone().map(ascent).fold(0.0, f32::max);
+                    let max_descent = baseline_items
+                        .map(|child| child.hypothetical_outer_size.cross(dir) - ascent(child))
+                        .fold(0.0, f32::max);
+
+                    max_ascent + max_descent
+                };
 
-            child.baseline = calc_baseline(&layout::Node {
-                order: node.children.iter().position(|n| ref_eq(n, child.node)).unwrap() as u32,
-                size: result.size,
-                location: Point { x: 0.0, y: 0.0 },
-                children: Box::new(result.children),
+                let baseline_size =
+                    shared_baseline_size(AlignSelf::Baseline).max(shared_baseline_size(AlignSelf::LastBaseline));
+
+                line.cross_size = line
+                    .items
+                    .iter()
+                    .filter(|child| {
+                        !is_baseline_aligned(child, AlignSelf::Baseline)
+                            && !is_baseline_aligned(child, AlignSelf::LastBaseline)
+                    })
+                    .map(|child| child.hypothetical_outer_size.cross(dir))
+                    .fold(baseline_size, f32::max);
             });
-        });
-    });
-
-    // 8. Calculate the cross size of each flex line.
-    //    If the flex container is single-line and has a definite cross size, the cross size
-    //    of the flex line is the flex container’s inner cross size. Otherwise, for each flex line:
-    //
-    //    If the flex container is single-line, then clamp the line’s cross-size to be within
-    //    the container’s computed min and max cross sizes. Note that if CSS 2.1’s definition
-    //    of min/max-width/height applied more generally, this behavior would fall out automatically.
+        }
 
-    if flex_lines.len() == 1 && node_size.cross(dir).is_defined() {
-        flex_lines[0].cross_size = (node_size.cross(dir) - padding_border.cross(dir)).or_else(0.0);
-    } else {
+        // In the second layout round no line may be smaller than the struts of its collapsed items.
         flex_lines.iter_mut().for_each(|line| {
-            //    1. Collect all the flex items whose inline-axis is parallel to the main-axis, whose
-            //       align-self is baseline, and whose cross-axis margins are both non-auto. Find the
-            //       largest of the distances between each item’s baseline and its hypothetical outer
-            //       cross-start edge, and the largest of the distances between each item’s baseline
-            //       and its hypothetical outer cross-end edge, and sum these two values.
-
-            //    2. Among all the items not collected by the previous step, find the largest
-            //       outer hypothetical cross size.
-
-            //    3. The used cross-size of the flex line is the largest of the numbers found in the
-            //       previous two steps and zero.
-
-            let max_baseline: f32 = line.items.iter().map(|child| child.baseline).fold(0.0, |acc, x| acc.max(x));
             line.cross_size = line
-                .items
+                .collapsed
                 .iter()
-                .map(|child| {
-                    if child.node.align_self(node) == AlignSelf::Baseline
-                        && child.node.cross_margin_start(dir) != Dimension::Auto
-                        && child.node.cross_margin_end(dir) != Dimension::Auto
-                        && child.node.cross_size(dir) == Dimension::Auto
-                    {
-                        max_baseline - child.baseline + child.hypothetical_outer_size.cross(dir)
-                    } else {
-                        child.hypothetical_outer_size.cross(dir)
-                    }
-                })
-                .fold(0.0, |acc, x| acc.max(x));
+                .filter_map(|child| struts[child_nodes.iter().position(|n| ref_eq(*n, child.node)).unwrap()])
+                .fold(line.cross_size, |acc, x| acc.max(x));
         });
-    }
 
-    // 9. Handle 'align-content: stretch'. If the flex container has a definite cross size,
-    //    align-content is stretch, and the sum of the flex lines' cross sizes is less than
-    //    the flex container’s inner cross size, increase the cross size of each flex line
-    //    by equal amounts such that the sum of their cross sizes exactly equals the
-    //    flex container’s inner cross size.
+        // 9. Handle 'align-content: stretch'. If the flex container has a definite cross size,
+        //    align-content is stretch, and the sum of the flex lines' cross sizes is less than
+        //    the flex container’s inner cross size, increase the cross size of each flex line
+        //    by equal amounts such that the sum of their cross sizes exactly equals the
+        //    flex container’s inner cross size.
+
+        if node.align_content() == AlignContent::Stretch && node_size.cross(dir).is_defined() {
+            let total_cross_gap = cross_gap * (flex_lines.len() - 1) as f32;
+            let total_cross: f32 = total_cross_gap + flex_lines.iter().map(|line| line.cross_size).sum::<f32>();
+            let inner_cross = (node_size.cross(dir) - padding_border.cross(dir)).or_else(0.0);
+
+            if total_cross < inner_cross {
+                let remaining = inner_cross - total_cross;
+                let addition = remaining / flex_lines.len() as f32;
+                flex_lines.iter_mut().for_each(|line| line.cross_size += addition);
+            }
+        }
 
-    if node.align_content == AlignContent::Stretch && node_size.cross(dir).is_defined() {
-        let total_cross: f32 = flex_lines.iter().map(|line| line.cross_size).sum();
-        let inner_cross = (node_size.cross(dir) - padding_border.cross(dir)).or_else(0.0);
+        // 10. Collapse visibility:collapse items. If any flex items have visibility: collapse,
+        //     note the cross size of the line they’re in as the item’s strut size, and restart
+        //     layout from the beginning.
+        //
+        //     In this second layout round, when collecting items into lines, treat the collapsed
+        //     items as having zero main size. For the rest of the algorithm following that step,
+        //     ignore the collapsed items entirely (as if they were display:none) except that after
+        //     calculating the cross size of the lines, if any line’s cross size is less than the
+        //     largest strut size among all the collapsed items in the line, set its cross size to
+        //     that strut size.
+        //
+        //     Skip this step in the second layout round.
+
+        if !is_second_round
+            && flex_lines
+                .iter()
+                .any(|line| line.items.iter().any(|child| child.node.visibility == Visibility::Collapse))
+        {
+            struts = vec![None; child_nodes.len()];
+
+            flex_lines.iter().for_each(|line| {
+                line.items.iter().filter(|child| child.node.visibility == Visibility::Collapse).for_each(|child| {
+                    let index = child_nodes.iter().position(|n| ref_eq(*n, child.node)).unwrap();
+                    struts[index] = Some(line.cross_size);
+                });
+            });
 
-        if total_cross < inner_cross {
-            let remaining = inner_cross - total_cross;
-            let addition = remaining / flex_lines.len() as f32;
-            flex_lines.iter_mut().for_each(|line| line.cross_size += addition);
+            continue;
         }
-    }
 
-    // 10. Collapse visibility:collapse items. If any flex items have visibility: collapse,
-    //     note the cross size of the line they’re in as the item’s strut size, and restart
-    //     layout from the beginning.
-    //
-    //     In this second layout round, when collecting items into lines, treat the collapsed
-    //     items as having zero main size. For the rest of the algorithm following that step,
-    //     ignore the collapsed items entirely (as if they were display:none) except that after
-    //     calculating the cross size of the lines, if any line’s cross size is less than the
-    //     largest strut size among all the collapsed items in the line, set its cross size to
-    //     that strut size.
-    //
-    //     Skip this step in the second layout round.
+        break flex_lines;
+    };
 
-    // TODO implement once (if ever) we support visibility:collapse
+    let total_cross_gap = cross_gap * (flex_lines.len() - 1) as f32;
 
     // 11. Determine the used cross size of each flex item. If a flex item has align-self: stretch,
     //     its computed cross size property is auto, and neither of its cross-axis margins are auto,
@@ -801,7 +1376,8 @@ fn compute_internal(
     //     2. Align the items along the main-axis per justify-content.
 
     flex_lines.iter_mut().for_each(|line| {
-        let used_space: f32 = line.items.iter().map(|child| child.outer_target_size.main(dir)).sum();
+        let used_space: f32 = main_gap * (line.items.len().max(1) - 1) as f32
+            + line.items.iter().map(|child| child.outer_target_size.main(dir)).sum::<f32>();
         let free_space = inner_container_size.main(dir) - used_space;
         let mut num_auto_margins = 0;
 
@@ -837,10 +1413,22 @@ fn compute_internal(
             let num_items = line.items.len();
             let layout_reverse = dir.is_reverse();
 
+            // Safe alignment of an overflowing line falls back to start alignment. Distributed
+            // alignment of an overflowing line falls back to flex-start for space-between and to
+            // center otherwise, as there is no space to distribute.
+            let justify_content = match node.justify_content(direction) {
+                _ if node.justify_content_safety == AlignmentSafety::Safe && free_space < 0.0 => JustifyContent::Start,
+                JustifyContent::SpaceBetween if free_space < 0.0 => JustifyContent::FlexStart,
+                JustifyContent::SpaceAround | JustifyContent::SpaceEvenly if free_space < 0.0 => JustifyContent::Center,
+                justify_content => justify_content,
+            };
+
             let justify_item = |(i, child): (usize, &mut FlexItem)| {
                 let is_first = i == 0;
 
-                child.offset_main = match node.justify_content {
+                // Items are laid out from the start edge of the main axis, in reverse order for
+                // reversed directions, so start and end alignment need not consider the direction.
+                child.offset_main = match justify_content {
                     JustifyContent::FlexStart => {
                         if layout_reverse && is_first {
                             free_space
@@ -877,6 +1465,17 @@ fn compute_internal(
                         }
                     }
                     JustifyContent::SpaceEvenly => free_space / (num_items + 1) as f32,
+                    JustifyContent::Start => 0.0,
+                    JustifyContent::End => {
+                        if is_first {
+                            free_space
+                        } else {
+                            0.0
+                        }
+                    }
+                    JustifyContent::Left | JustifyContent::Right | JustifyContent::Stretch | JustifyContent::Normal => {
+                        0.0
+                    } // Should never happen
                 };
             };
 
@@ -900,7 +1499,21 @@ fn compute_internal(
 
     flex_lines.iter_mut().for_each(|line| {
         let line_cross_size = line.cross_size;
-        let max_baseline: f32 = line.items.iter_mut().map(|child| child.baseline).fold(0.0, |acc, x| acc.max(x));
+        let max_baseline: f32 = line
+            .items
+            .iter()
+            .filter(|child| is_baseline_aligned(child, AlignSelf::Baseline))
+            .map(|child| child.margin.cross_start(dir) + child.baseline)
+            .fold(0.0, f32::max);
+
+        // Last baseline aligned items are aligned towards the cross-end of the line, measured by
+        // the distance between their last baseline and their outer cross-end edge.
+        let max_last_baseline_descent: f32 = line
+            .items
+            .iter()
+            .filter(|child| is_baseline_aligned(child, AlignSelf::LastBaseline))
+            .map(|child| child.outer_target_size.cross(dir) - child.margin.cross_start(dir) - child.last_baseline)
+            .fold(0.0, f32::max);
 
         line.items.iter_mut().for_each(|child| {
             let free_space = line_cross_size - child.outer_target_size.cross(dir);
@@ -931,8 +1544,17 @@ fn compute_internal(
                 // 14. Align all flex items along the cross-axis per align-self, if neither of the item’s
                 //     cross-axis margins are auto.
 
-                child.offset_cross = match child.node.align_self(node) {
-                    AlignSelf::Auto => 0.0, // Should never happen
+                // Safe alignment of an overflowing item falls back to start alignment.
+                let align_self = match child.node.align_self(node) {
+                    AlignSelf::Baseline | AlignSelf::LastBaseline | AlignSelf::Stretch => child.node.align_self(node),
+                    _ if child.node.align_self_safety(node) == AlignmentSafety::Safe && free_space < 0.0 => {
+                        AlignSelf::Start
+                    }
+                    align_self => align_self,
+                };
+
+                child.offset_cross = match align_self {
+                    AlignSelf::Auto | AlignSelf::Normal => 0.0, // Should never happen
                     AlignSelf::FlexStart => {
                         if is_wrap_reverse {
                             free_space
@@ -948,17 +1570,27 @@ fn compute_internal(
                         }
                     }
                     AlignSelf::Center => free_space / 2.0,
+                    AlignSelf::Start => 0.0,
+                    AlignSelf::End => free_space,
                     AlignSelf::Baseline => {
-                        if is_row {
-                            max_baseline - child.baseline
+                        if is_baseline_aligned(child, AlignSelf::Baseline) {
+                            max_baseline - child.margin.cross_start(dir) - child.baseline
+                        } else if is_wrap_reverse {
+                            free_space
                         } else {
-                            // basline alignment only makes sense if the direction is row
-                            // we treat it as flex-start alignment in columns.
-                            if is_wrap_reverse {
-                                free_space
-                            } else {
-                                0.0
-                            }
+                            0.0
+                        }
+                    }
+                    AlignSelf::LastBaseline => {
+                        if is_baseline_aligned(child, AlignSelf::LastBaseline) {
+                            line_cross_size
+                                - max_last_baseline_descent
+                                - child.margin.cross_start(dir)
+                                - child.last_baseline
+                        } else if is_wrap_reverse {
+                            0.0
+                        } else {
+                            free_space
                         }
                     }
                     AlignSelf::Stretch => {
@@ -979,7 +1611,7 @@ fn compute_internal(
     //     - Otherwise, use the sum of the flex lines' cross sizes, clamped by the used
     //       min and max cross sizes of the flex container.
 
-    let total_cross_size: f32 = flex_lines.iter().map(|line| line.cross_size).sum();
+    let total_cross_size: f32 = total_cross_gap + flex_lines.iter().map(|line| line.cross_size).sum::<f32>();
     container_size.set_cross(dir, node_size.cross(dir).or_else(total_cross_size + padding_border.cross(dir)));
     inner_container_size.set_cross(dir, container_size.cross(dir) - padding_border.cross(dir));
 
@@ -988,10 +1620,22 @@ fn compute_internal(
     let free_space = inner_container_size.cross(dir) - total_cross_size;
     let num_lines = flex_lines.len();
 
+    // Safe alignment of overflowing lines falls back to start alignment. Distributed alignment of
+    // overflowing lines falls back to flex-start for space-between and to center otherwise, as
+    // there is no space to distribute.
+    let align_content = match node.align_content() {
+        _ if node.align_content_safety == AlignmentSafety::Safe && free_space < 0.0 => AlignContent::Start,
+        AlignContent::SpaceBetween if free_space < 0.0 => AlignContent::FlexStart,
+        AlignContent::SpaceAround | AlignContent::SpaceEvenly if free_space < 0.0 => AlignContent::Center,
+        align_content => align_content,
+    };
+
     let align_line = |(i, line): (usize, &mut FlexLine)| {
         let is_first = i == 0;
 
-        line.offset_cross = match node.align_content {
+        // Lines are laid out from the start edge of the cross axis, in reverse order when
+        // wrapping in reverse, so start and end alignment need not consider the wrap direction.
+        line.offset_cross = match align_content {
             AlignContent::FlexStart => {
                 if is_first && is_wrap_reverse {
                     free_space
@@ -1028,6 +1672,16 @@ fn compute_internal(
                     free_space / num_lines as f32
                 }
             }
+            AlignContent::SpaceEvenly => free_space / (num_lines + 1) as f32,
+            AlignContent::Start => 0.0,
+            AlignContent::End => {
+                if is_first {
+                    free_space
+                } else {
+                    0.0
+                }
+            }
+            AlignContent::Normal => 0.0, // Should never happen
         };
     };
 
@@ -1037,8 +1691,13 @@ fn compute_internal(
         flex_lines.iter_mut().enumerate().for_each(align_line);
     }
 
+    // The scrollable overflow of the container, measured from the top left of its border box. It
+    // covers the margin boxes of the flex items with the end padding and border beyond them, as
+    // well as the border boxes of absolutely positioned children.
+    let mut content_size: Size<f32> = Size { width: 0.0, height: 0.0 };
+
     // Do a final layout pass and gather the resulting layouts
-    let mut children: Vec<layout::Node> = {
+    let children: Vec<layout::Node> = {
         let mut lines: Vec<Vec<layout::Node>> = vec![];
         let mut total_offset_cross = padding_border.cross_start(dir);
 
@@ -1051,8 +1710,9 @@ fn compute_internal(
                 let result = compute_internal(
                     child.node,
                     child.target_size.map(|s| s.to_number()),
-                    container_size.map(|s| s.to_number()),
+                    container_size.map(AvailableSpace::Definite),
                     percent_calc_base_child,
+                    direction,
                 );
 
                 let offset_main = total_offset_main
@@ -1066,17 +1726,36 @@ fn compute_internal(
                     + child.margin.cross_start(dir)
                     + (child.position.cross_start(dir).or_else(0.0) - child.position.cross_end(dir).or_else(0.0));
 
+                let location = Point {
+                    x: if is_row { offset_main } else { offset_cross },
+                    y: if is_column { offset_main } else { offset_cross },
+                };
+
+                // Items which are not scroll containers themselves also contribute the part of their
+                // content which overflows them.
+                let overflow_size = child_overflow(child.node, result.size, result.content_size, direction, direction);
+
+                content_size.width = content_size.width.max(
+                    location.x + (result.size.width + child.margin.end).max(overflow_size.width) + padding_border.end,
+                );
+                content_size.height = content_size.height.max(
+                    location.y
+                        + (result.size.height + child.margin.bottom).max(overflow_size.height)
+                        + padding_border.bottom,
+                );
+
                 children.push(layout::Node {
-                    order: node.children.iter().position(|n| ref_eq(n, child.node)).unwrap() as u32,
+                    order: child_nodes.iter().position(|n| ref_eq(*n, child.node)).unwrap() as u32,
                     size: result.size,
-                    location: Point {
-                        x: if is_row { offset_main } else { offset_cross },
-                        y: if is_column { offset_main } else { offset_cross },
-                    },
+                    content_size: result.content_size,
+                    location,
+                    baseline: result.baseline,
+                    last_baseline: result.last_baseline,
+                    visible: child.node.visibility == Visibility::Visible,
                     children: Box::new(result.children),
                 });
 
-                total_offset_main += child.offset_main + child.margin.main(dir) + result.size.main(dir);
+                total_offset_main += child.offset_main + child.margin.main(dir) + result.size.main(dir) + main_gap;
             };
 
             if dir.is_reverse() {
@@ -1085,7 +1764,20 @@ fn compute_internal(
                 line.items.iter_mut().for_each(layout_item);
             }
 
-            total_offset_cross += line_offset_cross + line.cross_size;
+            // Collapsed items are not rendered and take up no space, place them at the start of their line.
+            line.collapsed.iter().for_each(|child| {
+                let order = child_nodes.iter().position(|n| ref_eq(*n, child.node)).unwrap();
+                let mut layout = hidden_layout(order, child.node);
+                let offset_main = padding_border.main_start(dir);
+                let offset_cross = total_offset_cross + line_offset_cross;
+                layout.location = Point {
+                    x: if is_row { offset_main } else { offset_cross },
+                    y: if is_column { offset_main } else { offset_cross },
+                };
+                children.push(layout);
+            });
+
+            total_offset_cross += line_offset_cross + line.cross_size + cross_gap;
 
             if dir.is_reverse() {
                 children.reverse();
@@ -1107,140 +1799,112 @@ fn compute_internal(
         }
     };
 
-    // Before returning we perform absolute layout on all absolutely positioned children
-    let mut absolute_children: Vec<layout::Node> = node
-        .children
-        .iter()
-        .filter(|child| child.position_type == PositionType::Absolute)
-        .map(|child| {
-            let container_width = container_size.width.to_number();
-            let container_height = container_size.height.to_number();
-
-            let start = child.position.start.resolve(container_width) + child.margin.start.resolve(container_width);
-            let end = child.position.end.resolve(container_width) + child.margin.end.resolve(container_width);
-            let top = child.position.top.resolve(container_height) + child.margin.top.resolve(container_height);
-            let bottom =
-                child.position.bottom.resolve(container_height) + child.margin.bottom.resolve(container_height);
-
-            let (start_main, end_main) = if is_row { (start, end) } else { (top, bottom) };
-            let (start_cross, end_cross) = if is_row { (top, bottom) } else { (start, end) };
-
-            let width = child
-                .size
-                .width
-                .resolve(container_width)
-                .maybe_max(child.min_size.width.resolve(container_width))
-                .maybe_min(child.max_size.width.resolve(container_width))
-                .or_else(container_width - start - end);
+    // 8.5. Flex Container Baselines. The baseline of the container is the shared baseline of the
+    // items participating in baseline alignment in its first line. Otherwise it is the baseline of
+    // the first item, and without any items it is synthesized from the bottom of the border box.
+    // The last baseline is found in the same way from the last item of the last line.
+    let find_layout = |child: &FlexItem| {
+        let order = child_nodes.iter().position(|n| ref_eq(*n, child.node)).unwrap() as u32;
+        children.iter().find(|layout| layout.order == order)
+    };
 
-            let height = child
-                .size
-                .height
-                .resolve(container_height)
-                .maybe_max(child.min_size.height.resolve(container_height))
-                .maybe_min(child.max_size.height.resolve(container_height))
-                .or_else(container_height - top - bottom);
+    let baseline = flex_lines
+        .first()
+        .and_then(|line| {
+            line.items
+                .iter()
+                .find(|child| is_baseline_aligned(child, AlignSelf::Baseline))
+                .or_else(|| line.items.first())
+        })
+        .and_then(find_layout)
+        .map(|layout| layout.location.y + layout.baseline)
+        .unwrap_or(container_size.height);
+
+    let last_baseline = flex_lines
+        .last()
+        .and_then(|line| {
+            line.items
+                .iter()
+                .rev()
+                .find(|child| is_baseline_aligned(child, AlignSelf::LastBaseline))
+                .or_else(|| line.items.last())
+        })
+        .and_then(find_layout)
+        .map(|layout| layout.location.y + layout.last_baseline)
+        .unwrap_or(container_size.height);
+
+    // Absolutely positioned children without insets are aligned within the container as if they
+    // were the sole item of a line.
+    let static_position = |order: usize, size: Size<f32>| {
+        let child = child_nodes[order];
+
+        let free_main_space = container_size.main(dir)
+            - size
+                .main(dir)
+                .maybe_max(child.min_main_size(dir).resolve(percent_calc_base_child))
+                .maybe_min(child.max_main_size(dir).resolve(percent_calc_base_child));
+
+        let free_cross_space = container_size.cross(dir)
+            - size
+                .cross(dir)
+                .maybe_max(child.min_cross_size(dir).resolve(percent_calc_base_child))
+                .maybe_min(child.max_cross_size(dir).resolve(percent_calc_base_child));
 
-            let result = compute_internal(
-                child,
-                Size { width, height },
-                Size { width: container_width, height: container_height },
-                container_width,
-            );
+        let justify_content = if node.justify_content_safety == AlignmentSafety::Safe && free_main_space < 0.0 {
+            JustifyContent::Start
+        } else {
+            node.justify_content(direction)
+        };
 
-            let free_main_space = container_size.main(dir)
-                - result
-                    .size
-                    .main(dir)
-                    .maybe_max(child.min_main_size(dir).resolve(percent_calc_base_child))
-                    .maybe_min(child.max_main_size(dir).resolve(percent_calc_base_child));
+        let offset_main = match justify_content {
+            JustifyContent::SpaceBetween
+            | JustifyContent::FlexStart
+            | JustifyContent::Start
+            | JustifyContent::Left
+            | JustifyContent::Right
+            | JustifyContent::Stretch
+            | JustifyContent::Normal => padding_border.main_start(dir),
+            JustifyContent::FlexEnd | JustifyContent::End => free_main_space - padding_border.main_end(dir),
+            JustifyContent::SpaceEvenly | JustifyContent::SpaceAround | JustifyContent::Center => free_main_space / 2.0,
+        };
 
-            let free_cross_space = container_size.cross(dir)
-                - result
-                    .size
-                    .cross(dir)
-                    .maybe_max(child.min_cross_size(dir).resolve(percent_calc_base_child))
-                    .maybe_min(child.max_cross_size(dir).resolve(percent_calc_base_child));
+        let align_self = match child.align_self(node) {
+            AlignSelf::Baseline | AlignSelf::LastBaseline | AlignSelf::Stretch => child.align_self(node),
+            _ if child.align_self_safety(node) == AlignmentSafety::Safe && free_cross_space < 0.0 => AlignSelf::Start,
+            align_self => align_self,
+        };
 
-            let offset_main = if start_main.is_defined() {
-                start_main.or_else(0.0) + border.main_start(dir)
-            } else if end_main.is_defined() {
-                free_main_space - end_main.or_else(0.0) - border.main_end(dir)
-            } else {
-                match node.justify_content {
-                    JustifyContent::SpaceBetween | JustifyContent::FlexStart => padding_border.main_start(dir),
-                    JustifyContent::FlexEnd => free_main_space - padding_border.main_end(dir),
-                    JustifyContent::SpaceEvenly | JustifyContent::SpaceAround | JustifyContent::Center => {
-                        free_main_space / 2.0
-                    }
+        // An absolutely positioned child is aligned as the sole item of its line, for which baseline
+        // alignment is the same as flex-start and last baseline alignment the same as flex-end.
+        let offset_cross = match align_self {
+            AlignSelf::Auto | AlignSelf::Normal => 0.0, // Should never happen
+            AlignSelf::FlexStart | AlignSelf::Baseline | AlignSelf::Stretch => {
+                if is_wrap_reverse {
+                    free_cross_space - padding_border.cross_end(dir)
+                } else {
+                    padding_border.cross_start(dir)
                 }
-            };
-
-            let offset_cross = if start_cross.is_defined() {
-                start_cross.or_else(0.0) + border.cross_start(dir)
-            } else if end_cross.is_defined() {
-                free_cross_space - end_cross.or_else(0.0) - border.cross_end(dir)
-            } else {
-                match child.align_self(node) {
-                    AlignSelf::Auto => 0.0, // Should never happen
-                    AlignSelf::FlexStart => {
-                        if is_wrap_reverse {
-                            free_cross_space - padding_border.cross_end(dir)
-                        } else {
-                            padding_border.cross_start(dir)
-                        }
-                    }
-                    AlignSelf::FlexEnd => {
-                        if is_wrap_reverse {
-                            padding_border.cross_start(dir)
-                        } else {
-                            free_cross_space - padding_border.cross_end(dir)
-                        }
-                    }
-                    AlignSelf::Center => free_cross_space / 2.0,
-                    AlignSelf::Baseline => free_cross_space / 2.0, // Treat as center for now until we have baseline support
-                    AlignSelf::Stretch => {
-                        if is_wrap_reverse {
-                            free_cross_space - padding_border.cross_end(dir)
-                        } else {
-                            padding_border.cross_start(dir)
-                        }
-                    }
+            }
+            AlignSelf::FlexEnd | AlignSelf::LastBaseline => {
+                if is_wrap_reverse {
+                    padding_border.cross_start(dir)
+                } else {
+                    free_cross_space - padding_border.cross_end(dir)
                 }
-            };
-
-            layout::Node {
-                order: node.children.iter().position(|n| ref_eq(n, child)).unwrap() as u32,
-                size: result.size,
-                location: Point {
-                    x: if is_row { offset_main } else { offset_cross },
-                    y: if is_column { offset_main } else { offset_cross },
-                },
-                children: Box::new(result.children),
             }
-        })
-        .collect();
-
-    children.append(&mut absolute_children);
+            AlignSelf::Center => free_cross_space / 2.0,
+            AlignSelf::Start => padding_border.cross_start(dir),
+            AlignSelf::End => free_cross_space - padding_border.cross_end(dir),
+        };
 
-    fn hidden_layout(parent: &style::Node, node: &style::Node) -> layout::Node {
-        layout::Node {
-            order: parent.children.iter().position(|n| ref_eq(n, node)).unwrap() as u32,
-            size: Size { width: 0.0, height: 0.0 },
-            location: Point { x: 0.0, y: 0.0 },
-            children: Box::new(node.children.iter().map(|child| hidden_layout(node, child)).collect()),
+        Point {
+            x: if is_row { offset_main } else { offset_cross },
+            y: if is_column { offset_main } else { offset_cross },
         }
-    }
-
-    let mut hidden_children: Vec<layout::Node> = node
-        .children
-        .iter()
-        .filter(|child| child.display == Display::None)
-        .map(|child| hidden_layout(node, child))
-        .collect();
+    };
 
-    children.append(&mut hidden_children);
+    let (children, content_size) =
+        finish_layout(node, children, container_size, border, &static_position, direction, content_size);
 
-    children.sort_by(|c1, c2| c1.order.cmp(&c2.order));
-    ComputeResult { size: container_size, children }
+    ComputeResult { size: container_size, content_size, baseline, last_baseline, children }
 }
@@ -0,0 +1,647 @@
+use std::ops::Range;
+
+use crate::layout;
+
+use crate::style;
+use crate::style::{Clear, Dimension, Direction, Display, Float, PositionType, Visibility};
+
+use crate::number::Number::*;
+use crate::number::*;
+
+use crate::geometry::{Point, Rect, Size};
+
+use super::inline;
+use super::{child_overflow, compute_internal, contents_children, finish_layout, padding_border, ComputeResult};
+
+// Adjoining margins collapse into a single margin, which is the largest of the positive margins
+// plus the most negative of the negative margins. See CSS 2.1 §8.3.1.
+#[derive(Copy, Clone)]
+struct CollapsibleMargin {
+    positive: f32,
+    negative: f32,
+}
+
+impl CollapsibleMargin {
+    fn new(margin: f32) -> CollapsibleMargin {
+        CollapsibleMargin { positive: margin.max(0.0), negative: margin.min(0.0) }
+    }
+
+    fn collapse(self, other: CollapsibleMargin) -> CollapsibleMargin {
+        CollapsibleMargin { positive: self.positive.max(other.positive), negative: self.negative.min(other.negative) }
+    }
+
+    fn resolve(self) -> f32 {
+        self.positive + self.negative
+    }
+}
+
+struct BlockResult {
+    result: ComputeResult,
+
+    // The margins of the box, collapsed with the margins of its first and last children when they adjoin.
+    margin_top: CollapsibleMargin,
+    margin_bottom: CollapsibleMargin,
+
+    // Whether the top and bottom margins of the box adjoin, which happens when it is empty. Its
+    // margins then collapse with those of the boxes before and after it.
+    collapses_through: bool,
+
+    // The floats placed within the box, which affect the content after it in the same block
+    // formatting context.
+    floats: Vec<FloatBox>,
+}
+
+// 9.5. Floats. The margin box of a float placed in a block formatting context, relative to the
+// border box of a container. Like the rest of the layout, floats on the start side are on the left
+// until right-to-left containers are mirrored.
+#[derive(Copy, Clone)]
+struct FloatBox {
+    is_start: bool,
+    left: f32,
+    right: f32,
+    top: f32,
+    bottom: f32,
+}
+
+impl FloatBox {
+    fn translate(self, x: f32, y: f32) -> FloatBox {
+        FloatBox { left: self.left + x, right: self.right + x, top: self.top + y, bottom: self.bottom + y, ..self }
+    }
+
+    // Mirrors the float within a box of the given width, for children with the opposite direction of
+    // their parent.
+    fn mirror(self, width: f32) -> FloatBox {
+        FloatBox { is_start: !self.is_start, left: width - self.right, right: width - self.left, ..self }
+    }
+
+    fn overlaps(self, top: f32, bottom: f32) -> bool {
+        self.bottom > top && (self.top < bottom || self.top <= top)
+    }
+}
+
+// Floats and clearance are specified by physical side, which is the start side for left-to-right content.
+fn is_start_side(left: bool, direction: Direction) -> bool {
+    left == (direction != Direction::RTL)
+}
+
+// The left and right edges of the space between the floats next to a band of the container, which
+// is the space between the edges of its content box without any floats.
+fn float_free_space(floats: &[FloatBox], top: f32, bottom: f32, left: f32, right: f32) -> (f32, f32) {
+    floats.iter().filter(|float| float.overlaps(top, bottom)).fold((left, right), |(left, right), float| {
+        if float.is_start {
+            (left.max(float.right), right)
+        } else {
+            (left, right.min(float.left))
+        }
+    })
+}
+
+// Finds the highest position at or below the given one where a box of the given size fits between
+// the floats, moving down past floats until it fits or there are no floats beside it. Returns the
+// position along with the edges of the space between the floats there.
+fn fit_between_floats(floats: &[FloatBox], top: f32, size: Size<f32>, left: f32, right: f32) -> (f32, f32, f32) {
+    let mut top = top;
+    loop {
+        let (free_left, free_right) = float_free_space(floats, top, top + size.height, left, right);
+        let next = floats
+            .iter()
+            .filter(|float| float.overlaps(top, top + size.height))
+            .map(|float| float.bottom)
+            .fold(f32::INFINITY, f32::min);
+
+        if free_right - free_left >= size.width || next.is_infinite() {
+            return (top, free_left, free_right);
+        }
+
+        top = next;
+    }
+}
+
+// The position below the earlier floats which a child with the given clearance is placed at.
+fn clearance(floats: &[FloatBox], clear: Clear, direction: Direction) -> f32 {
+    floats
+        .iter()
+        .filter(|float| match clear {
+            Clear::None => false,
+            Clear::Left => float.is_start == is_start_side(true, direction),
+            Clear::Right => float.is_start == is_start_side(false, direction),
+            Clear::Both => true,
+        })
+        .map(|float| float.bottom)
+        .fold(f32::NEG_INFINITY, f32::max)
+}
+
+// Boxes laid out as part of the block formatting context of their parent, rather than establishing
+// one of their own. Margins only collapse between these and with their children.
+fn is_in_flow_block(node: &style::Node) -> bool {
+    node.display == Display::Block
+        && !(node.is_scroll_container() || node.is_multicol())
+        && !(node.measure.is_some() && node.children.is_empty())
+}
+
+// The children of a block container are laid out one after another, except for consecutive inline
+// level children which are laid out together in lines as if wrapped in an anonymous block box.
+// Absolutely positioned and hidden children between them don't interrupt the lines.
+enum BlockEntry {
+    Child(usize),
+    Inline(Range<usize>),
+}
+
+fn block_entries(children: &[&style::Node]) -> Vec<BlockEntry> {
+    let mut entries: Vec<BlockEntry> = vec![];
+    let mut index = 0;
+
+    while index < children.len() {
+        let start = index;
+        index += 1;
+
+        if !inline::is_inline_level(children[start]) || children[start].display == Display::None {
+            entries.push(BlockEntry::Child(start));
+            continue;
+        }
+
+        while index < children.len() {
+            let child = children[index];
+            if !inline::is_inline_level(child)
+                && child.display != Display::None
+                && child.position_type != PositionType::Absolute
+            {
+                break;
+            }
+            index += 1;
+        }
+
+        entries.push(BlockEntry::Inline(start..index));
+    }
+
+    entries
+}
+
+// Lays out a block container which establishes a new block formatting context. This is the case
+// for the root, for flex and grid items, and for scroll containers, so the margins of the container
+// don't collapse with those of its children.
+pub(super) fn compute(
+    node: &style::Node,
+    node_size: Size<Number>,
+    parent_size: Size<AvailableSpace>,
+    direction: Direction,
+    margin: Rect<f32>,
+    padding: Rect<f32>,
+    border: Rect<f32>,
+) -> ComputeResult {
+    let padding_border_width = padding.horizontal() + border.horizontal();
+
+    // Without a definite width the container shrinks to fit its content within the available space,
+    // which is the widest of its children when they shrink to fit as well.
+    let width = match node_size.width {
+        Defined(width) => width,
+        Undefined => {
+            let available_width = parent_size.width - margin.horizontal() - padding_border_width;
+            let child_nodes = contents_children(node);
+
+            block_entries(&child_nodes)
+                .into_iter()
+                .map(|entry| {
+                    let child = match entry {
+                        BlockEntry::Inline(range) => {
+                            return inline::intrinsic_width(&child_nodes, range, available_width, direction);
+                        }
+                        BlockEntry::Child(order) => child_nodes[order],
+                    };
+
+                    if child.position_type == PositionType::Absolute || child.display == Display::None {
+                        return 0.0;
+                    }
+
+                    let child_margin = child.margin.map(|n| n.resolve(Undefined).or_else(0.0));
+                    let width = match child.size.width.resolve(Undefined) {
+                        Defined(width) => width,
+                        Undefined => {
+                            compute_internal(
+                                child,
+                                Size { width: Undefined, height: child.size.height.resolve(Undefined) },
+                                Size {
+                                    width: available_width - child_margin.horizontal(),
+                                    height: AvailableSpace::MaxContent,
+                                },
+                                Undefined,
+                                direction,
+                            )
+                            .size
+                            .width
+                        }
+                    };
+
+                    width
+                        .maybe_max(child.min_size.width.resolve(Undefined))
+                        .maybe_min(child.max_size.width.resolve(Undefined))
+                        + child_margin.horizontal()
+                })
+                .fold(0.0, f32::max)
+                + padding_border_width
+        }
+    };
+
+    layout_block(node, width, node_size.height, direction, margin, padding, border, &[], true).result
+}
+
+// 9.4.1. Block formatting contexts. Lays out the in-flow children of a block container one after
+// another vertically, each filling the width of the container unless it has a width of its own.
+// Children which are part of the same block formatting context are laid out recursively so that
+// the margins of the container can collapse with those of its first and last children, and so
+// that floats affect the content of the following boxes.
+#[allow(clippy::too_many_arguments)]
+fn layout_block(
+    node: &style::Node,
+    width: f32,
+    height: Number,
+    direction: Direction,
+    margin: Rect<f32>,
+    padding: Rect<f32>,
+    border: Rect<f32>,
+    outer_floats: &[FloatBox],
+    is_root: bool,
+) -> BlockResult {
+    let padding_border = padding_border(padding, border);
+
+    let inner_width = width - padding_border.horizontal();
+    let inner_height = height - padding_border.vertical();
+
+    let available_height = match inner_height {
+        Defined(height) => AvailableSpace::Definite(height),
+        Undefined => AvailableSpace::MaxContent,
+    };
+
+    // The margins of a box collapse with those of its first and last children unless they are
+    // separated by border or padding, or the box establishes a new block formatting context.
+    // The bottom margin only collapses when the height of the box depends on its content.
+    let collapses_top = !is_root && padding_border.top == 0.0;
+    let collapses_bottom = !is_root
+        && padding_border.bottom == 0.0
+        && height.is_undefined()
+        && node.min_size.height.resolve(Undefined).or_else(0.0) <= 0.0;
+
+    let mut margin_top = CollapsibleMargin::new(margin.top);
+    let mut margin_bottom = CollapsibleMargin::new(margin.bottom);
+
+    // The bottom edge of the content laid out so far, along with the margins adjoining it which
+    // have not been placed yet as they may collapse with the margins of the next child.
+    let mut offset = padding_border.top;
+    let mut pending_margin = CollapsibleMargin::new(0.0);
+    let mut is_empty = true;
+
+    // The floats of the block formatting context beside the container, followed by those placed
+    // within it which are passed on to the parent.
+    let mut floats: Vec<FloatBox> = outer_floats.to_vec();
+    let (content_left, content_right) = (padding_border.start, width - padding_border.end);
+
+    let mut content_size: Size<f32> = Size { width: 0.0, height: 0.0 };
+    let child_nodes = contents_children(node);
+    let mut static_positions: Vec<Point<f32>> = vec![Point { x: padding_border.start, y: 0.0 }; child_nodes.len()];
+    let mut baselines: Option<(f32, f32)> = None;
+
+    let mut children: Vec<layout::Node> = vec![];
+
+    for entry in block_entries(&child_nodes) {
+        // The position of the next line, where absolutely positioned children, floats and inline
+        // content are placed.
+        let line_top = if is_empty && collapses_top { offset } else { offset + pending_margin.resolve() };
+
+        let order = match entry {
+            BlockEntry::Child(order) => order,
+            BlockEntry::Inline(range) => {
+                // Lines are placed like an in-flow block without margins, separating the margins before
+                // and after them. Each line is shortened by the floats beside it.
+                let fit = |top: f32, width: f32| {
+                    fit_between_floats(&floats, top, Size { width, height: 0.0 }, content_left, content_right)
+                };
+                let result =
+                    inline::layout_inline(node, &child_nodes, range.clone(), inner_width, line_top, &fit, direction);
+
+                for order in range.filter(|order| child_nodes[*order].position_type == PositionType::Absolute) {
+                    static_positions[order].y = line_top;
+                }
+
+                if let Some((first, last)) = result.baselines {
+                    if is_empty && collapses_top {
+                        margin_top = margin_top.collapse(pending_margin);
+                    }
+
+                    offset = line_top + result.height;
+                    pending_margin = CollapsibleMargin::new(0.0);
+                    is_empty = false;
+
+                    baselines = Some(match baselines {
+                        Some((baseline, _)) => (baseline, last),
+                        None => (first, last),
+                    });
+                }
+
+                for child in &result.children {
+                    let overflow_size = child_overflow(
+                        child_nodes[child.order as usize],
+                        child.size,
+                        child.content_size,
+                        direction,
+                        direction,
+                    );
+                    content_size.width = content_size
+                        .width
+                        .max(child.location.x + child.size.width.max(overflow_size.width) + padding_border.end);
+                    content_size.height = content_size
+                        .height
+                        .max(child.location.y + child.size.height.max(overflow_size.height) + padding_border.bottom);
+                }
+
+                children.extend(result.children);
+                continue;
+            }
+        };
+
+        let child = child_nodes[order];
+        if child.display == Display::None {
+            continue;
+        }
+
+        if child.position_type == PositionType::Absolute {
+            static_positions[order].y = line_top;
+            continue;
+        }
+
+        let child_margin = child.margin.map(|n| n.resolve(inner_width.to_number()).or_else(0.0));
+        let position = child.position.map(|n| n.resolve(inner_width.to_number()));
+        let relative_offset = Point {
+            x: position.start.or_else(0.0) - position.end.or_else(0.0),
+            y: position.top.or_else(0.0) - position.bottom.or_else(0.0),
+        };
+
+        let child_width = child
+            .size
+            .width
+            .resolve(inner_width.to_number())
+            .maybe_max(child.min_size.width.resolve(inner_width.to_number()))
+            .maybe_min(child.max_size.width.resolve(inner_width.to_number()));
+
+        let child_height = child
+            .size
+            .height
+            .resolve(inner_height)
+            .maybe_max(child.min_size.height.resolve(inner_height))
+            .maybe_min(child.max_size.height.resolve(inner_height));
+
+        // Floats shrink to fit their content and are placed as high as possible against the start or
+        // end edge of the container, beside earlier floats and no higher than any of them.
+        if child.float != Float::None {
+            let result = compute_internal(
+                child,
+                Size { width: child_width, height: child_height },
+                Size {
+                    width: AvailableSpace::Definite(inner_width - child_margin.horizontal()),
+                    height: available_height,
+                },
+                inner_width.to_number(),
+                direction,
+            );
+
+            let is_start = is_start_side(child.float == Float::Left, direction);
+            let outer_size = Size {
+                width: result.size.width + child_margin.horizontal(),
+                height: result.size.height + child_margin.vertical(),
+            };
+
+            let top = floats.iter().map(|float| float.top).fold(line_top, f32::max).max(clearance(
+                &floats,
+                child.clear,
+                direction,
+            ));
+            let (top, free_left, free_right) =
+                fit_between_floats(&floats, top, outer_size, content_left, content_right);
+            let left = if is_start { free_left } else { free_right - outer_size.width };
+
+            floats.push(FloatBox {
+                is_start,
+                left,
+                right: left + outer_size.width,
+                top,
+                bottom: top + outer_size.height,
+            });
+
+            let location = Point {
+                x: left + child_margin.start + relative_offset.x,
+                y: top + child_margin.top + relative_offset.y,
+            };
+
+            let overflow_size = child_overflow(child, result.size, result.content_size, direction, direction);
+            content_size.width = content_size.width.max(location.x + overflow_size.width + padding_border.end);
+            content_size.height = content_size.height.max(location.y + overflow_size.height + padding_border.bottom);
+
+            children.push(layout::Node {
+                order: order as u32,
+                size: result.size,
+                content_size: result.content_size,
+                location,
+                baseline: result.baseline,
+                last_baseline: result.last_baseline,
+                visible: child.visibility == Visibility::Visible,
+                children: Box::new(result.children),
+            });
+
+            continue;
+        }
+
+        let fill_width = Defined(inner_width - child_margin.horizontal())
+            .maybe_max(child.min_size.width.resolve(inner_width.to_number()))
+            .maybe_min(child.max_size.width.resolve(inner_width.to_number()))
+            .or_else(0.0);
+
+        // Children in the same block formatting context are laid out before they are positioned, as
+        // their margins may collapse with those of their own children. They are laid out again in
+        // case floats beside them affect their content.
+        let block_layout = |floats: &[FloatBox]| {
+            layout_block(
+                child,
+                child_width.or_else(fill_width),
+                child_height,
+                child.direction(direction),
+                child_margin,
+                child.padding.map(|n| n.resolve(inner_width.to_number()).or_else(0.0)),
+                child.border.map(|n| n.resolve(inner_width.to_number()).or_else(0.0)),
+                floats,
+                false,
+            )
+        };
+
+        let first_layout = if is_in_flow_block(child) { Some(block_layout(&[])) } else { None };
+
+        let (child_margin_top, child_margin_bottom, collapses_through) = match first_layout {
+            Some(ref result) => (result.margin_top, result.margin_bottom, result.collapses_through),
+            None => (CollapsibleMargin::new(child_margin.top), CollapsibleMargin::new(child_margin.bottom), false),
+        };
+
+        // The margins between this child and the content before it collapse. When there is no content
+        // before it they collapse with the top margin of the container instead, if it adjoins. Clearance
+        // moves the child below earlier floats, separating its margins from those before it.
+        let collapsed_margin = pending_margin.collapse(child_margin_top);
+        let hypothetical_top = if is_empty && collapses_top { offset } else { offset + collapsed_margin.resolve() };
+        let clearance = clearance(&floats, child.clear, direction);
+        let has_clearance = clearance > hypothetical_top;
+
+        if is_empty && collapses_top && !collapses_through && !has_clearance {
+            margin_top = margin_top.collapse(collapsed_margin);
+        }
+
+        let mut top = hypothetical_top.max(clearance);
+
+        let (left, result) = match first_layout {
+            Some(result) => {
+                let outer_width = result.result.size.width + child_margin.horizontal();
+                let left = content_left + auto_margin_offset(child, content_right - content_left - outer_width);
+                let location = Point { x: left + child_margin.start, y: top };
+                let mirror = child.direction(direction) != direction;
+
+                let result = if floats.iter().any(|float| float.bottom > top) {
+                    let child_floats: Vec<FloatBox> = floats
+                        .iter()
+                        .map(|float| float.translate(-location.x, -location.y))
+                        .map(|float| if mirror { float.mirror(result.result.size.width) } else { float })
+                        .collect();
+                    block_layout(&child_floats)
+                } else {
+                    result
+                };
+
+                floats.extend(
+                    result
+                        .floats
+                        .iter()
+                        .map(|float| if mirror { float.mirror(result.result.size.width) } else { *float })
+                        .map(|float| float.translate(location.x, location.y)),
+                );
+
+                (left, result.result)
+            }
+            None => {
+                // Boxes establishing a new block formatting context don't overlap floats. They are
+                // placed beside them, shrinking to fit the space between them, or below them when
+                // they don't fit.
+                let (fit_top, free_left, free_right) = fit_between_floats(
+                    &floats,
+                    top,
+                    Size { width: child_width.or_else(0.0) + child_margin.horizontal(), height: 0.0 },
+                    content_left,
+                    content_right,
+                );
+                top = fit_top;
+
+                let fill_width = Defined(free_right - free_left - child_margin.horizontal())
+                    .maybe_max(child.min_size.width.resolve(inner_width.to_number()))
+                    .maybe_min(child.max_size.width.resolve(inner_width.to_number()))
+                    .or_else(0.0);
+
+                let result = compute_internal(
+                    child,
+                    Size { width: Defined(child_width.or_else(fill_width)), height: child_height },
+                    Size { width: AvailableSpace::Definite(free_right - free_left), height: available_height },
+                    inner_width.to_number(),
+                    direction,
+                );
+
+                let outer_width = result.size.width + child_margin.horizontal();
+                (free_left + auto_margin_offset(child, free_right - free_left - outer_width), result)
+            }
+        };
+
+        if collapses_through && !has_clearance {
+            pending_margin = collapsed_margin.collapse(child_margin_bottom);
+        } else {
+            offset = top + result.size.height;
+            pending_margin = child_margin_bottom;
+            is_empty = false;
+        }
+
+        let location = Point { x: left + child_margin.start + relative_offset.x, y: top + relative_offset.y };
+
+        let overflow_size = child_overflow(child, result.size, result.content_size, direction, direction);
+
+        content_size.width = content_size
+            .width
+            .max(location.x + (result.size.width + child_margin.end).max(overflow_size.width) + padding_border.end);
+        content_size.height = content_size.height.max(
+            location.y + (result.size.height + child_margin.bottom).max(overflow_size.height) + padding_border.bottom,
+        );
+
+        baselines = Some(match baselines {
+            Some((baseline, _)) => (baseline, location.y + result.last_baseline),
+            None => (location.y + result.baseline, location.y + result.last_baseline),
+        });
+
+        children.push(layout::Node {
+            order: order as u32,
+            size: result.size,
+            content_size: result.content_size,
+            location,
+            baseline: result.baseline,
+            last_baseline: result.last_baseline,
+            visible: child.visibility == Visibility::Visible,
+            children: Box::new(result.children),
+        });
+    }
+
+    // The margins left after the last child collapse with the bottom margin of the container if it
+    // adjoins. An empty container collapses through, with all of its margins adjoining each other.
+    let collapses_through = is_empty && collapses_top && collapses_bottom;
+    if collapses_through || (is_empty && collapses_top) {
+        margin_top = margin_top.collapse(pending_margin);
+    } else if collapses_bottom {
+        margin_bottom = margin_bottom.collapse(pending_margin);
+    } else {
+        offset += pending_margin.resolve();
+    }
+
+    // A block formatting context contains its floats, so the height of its root includes them.
+    // Otherwise floats may extend below the container.
+    let floats = floats.split_off(outer_floats.len());
+    if is_root {
+        offset = floats.iter().map(|float| float.bottom).fold(offset, f32::max);
+    }
+
+    let container_size = Size {
+        width,
+        height: height.or_else(
+            (offset + padding_border.bottom)
+                .maybe_max(node.min_size.height.resolve(Undefined))
+                .maybe_min(node.max_size.height.resolve(Undefined)),
+        ),
+    };
+
+    // The baselines of a block container are those of its first and last in-flow children. Without
+    // children they are synthesized from the bottom of the border box.
+    let (baseline, last_baseline) = baselines.unwrap_or((container_size.height, container_size.height));
+
+    let (children, content_size) = finish_layout(
+        node,
+        children,
+        container_size,
+        border,
+        &|order, _| static_positions[order],
+        direction,
+        content_size,
+    );
+
+    BlockResult {
+        result: ComputeResult { size: container_size, content_size, baseline, last_baseline, children },
+        margin_top,
+        margin_bottom,
+        collapses_through,
+        floats,
+    }
+}
+
+// Auto margins in the inline axis center a child within the free space, or push it to the end.
+fn auto_margin_offset(child: &style::Node, free_space: f32) -> f32 {
+    match (child.margin.start, child.margin.end) {
+        (Dimension::Auto, Dimension::Auto) => free_space / 2.0,
+        (Dimension::Auto, _) => free_space,
+        _ => 0.0,
+    }
+}
@@ -0,0 +1,299 @@
+use crate::layout;
+
+use crate::style;
+use crate::style::{
+    Constraint, ConstraintAttribute, ConstraintExpression, ConstraintItem, ConstraintRelation, ConstraintStrength,
+    Direction, Visibility,
+};
+
+use crate::number::Number::*;
+use crate::number::*;
+
+use crate::geometry::{Point, Rect, Size};
+
+use super::{
+    child_overflow, compute_internal, contents_children, finish_layout, is_in_flow, padding_border, ComputeResult,
+};
+
+mod solver;
+
+use self::solver::{Relation, Solver};
+
+// Constraint layout. The container and each of its in-flow children are boxes with a left and top
+// edge, relative to the border box of the container, and a width and height, which are the variables
+// of a linear system of the constraints of the container. Children are sized to their content and
+// placed at the start of the content box unless their constraints say otherwise, and a container
+// without a definite size is as small as its constraints allow. Children are laid out within the
+// frames the constraints are solved for, without margins.
+
+// The variables of a box in the order of its left and top edge, width and height.
+type BoxVariables = [usize; 4];
+
+const LEFT: usize = 0;
+const TOP: usize = 1;
+const WIDTH: usize = 2;
+const HEIGHT: usize = 3;
+
+// The strengths of the default position of the children and of keeping the size of the container
+// small, which are weaker than any constraint of the container so that they only apply where the
+// constraints leave a choice.
+const DEFAULT_POSITION: f64 = solver::WEAK / 1_000.0;
+const DEFAULT_CONTAINER_SIZE: f64 = DEFAULT_POSITION / 1_000.0;
+
+fn strength(strength: ConstraintStrength) -> f64 {
+    match strength {
+        ConstraintStrength::Required => solver::REQUIRED,
+        ConstraintStrength::Strong => solver::STRONG,
+        ConstraintStrength::Medium => solver::MEDIUM,
+        ConstraintStrength::Weak => solver::WEAK,
+    }
+}
+
+// The terms of an expression over the variables of the boxes, or None if it refers to a child which
+// is not laid out by the constraints.
+fn expression_terms(
+    children: &[&style::Node],
+    boxes: &[BoxVariables],
+    expression: &ConstraintExpression,
+    factor: f64,
+) -> Option<Vec<(f64, usize)>> {
+    let mut terms: Vec<(f64, usize)> = vec![];
+
+    for (coefficient, variable) in &expression.terms {
+        let variables = match variable.item {
+            ConstraintItem::Parent => boxes[0],
+            ConstraintItem::Child(index) if index < children.len() && is_in_flow(children[index]) => boxes[index + 1],
+            ConstraintItem::Child(_) => return None,
+        };
+
+        let coefficient = *coefficient as f64 * factor;
+        match variable.attribute {
+            ConstraintAttribute::Left => terms.push((coefficient, variables[LEFT])),
+            ConstraintAttribute::Top => terms.push((coefficient, variables[TOP])),
+            ConstraintAttribute::Width => terms.push((coefficient, variables[WIDTH])),
+            ConstraintAttribute::Height => terms.push((coefficient, variables[HEIGHT])),
+            ConstraintAttribute::Right => {
+                terms.push((coefficient, variables[LEFT]));
+                terms.push((coefficient, variables[WIDTH]));
+            }
+            ConstraintAttribute::Bottom => {
+                terms.push((coefficient, variables[TOP]));
+                terms.push((coefficient, variables[HEIGHT]));
+            }
+            ConstraintAttribute::CenterX => {
+                terms.push((coefficient, variables[LEFT]));
+                terms.push((coefficient / 2.0, variables[WIDTH]));
+            }
+            ConstraintAttribute::CenterY => {
+                terms.push((coefficient, variables[TOP]));
+                terms.push((coefficient / 2.0, variables[HEIGHT]));
+            }
+        }
+    }
+
+    Some(terms)
+}
+
+// Constrains a variable to relate to a value.
+fn fix(solver: &mut Solver, variable: usize, relation: Relation, value: f32, strength: f64) {
+    let _ = solver.add_constraint(&[(1.0, variable)], -value as f64, relation, strength);
+}
+
+// Adds a constraint of the container, which is moved to one side as the difference of its two sides
+// related to zero. Constraints referring to children which are not laid out by the constraints, and
+// required constraints conflicting with earlier ones, are ignored.
+fn add_constraint(solver: &mut Solver, children: &[&style::Node], boxes: &[BoxVariables], constraint: &Constraint) {
+    let left = expression_terms(children, boxes, &constraint.left, 1.0);
+    let right = expression_terms(children, boxes, &constraint.right, -1.0);
+
+    if let (Some(mut terms), Some(right)) = (left, right) {
+        terms.extend(right);
+
+        let relation = match constraint.relation {
+            ConstraintRelation::Equal => Relation::Equal,
+            ConstraintRelation::LessOrEqual => Relation::LessOrEqual,
+            ConstraintRelation::GreaterOrEqual => Relation::GreaterOrEqual,
+        };
+
+        let constant = constraint.left.constant as f64 - constraint.right.constant as f64;
+        let _ = solver.add_constraint(&terms, constant, relation, strength(constraint.strength));
+    }
+}
+
+pub(super) fn compute(
+    node: &style::Node,
+    node_size: Size<Number>,
+    _parent_size: Size<AvailableSpace>,
+    direction: Direction,
+    _margin: Rect<f32>,
+    padding: Rect<f32>,
+    border: Rect<f32>,
+) -> ComputeResult {
+    let padding_border = padding_border(padding, border);
+
+    let node_inner_size = Size {
+        width: node_size.width - padding_border.horizontal(),
+        height: node_size.height - padding_border.vertical(),
+    };
+
+    // Children with display: contents are replaced by their own children, which is also how the
+    // children referred to by the constraints are counted.
+    let children = contents_children(node);
+
+    let mut solver = Solver::new();
+    let boxes: Vec<BoxVariables> = (0..children.len() + 1)
+        .map(|_| [solver.variable(), solver.variable(), solver.variable(), solver.variable()])
+        .collect();
+
+    // The container is the origin of the layout. Its size is fixed when definite, otherwise it is
+    // kept as small as possible while leaving room for its padding and border.
+    fix(&mut solver, boxes[0][LEFT], Relation::Equal, 0.0, solver::REQUIRED);
+    fix(&mut solver, boxes[0][TOP], Relation::Equal, 0.0, solver::REQUIRED);
+
+    for &(axis, size, padding_border) in
+        &[(WIDTH, node_size.width, padding_border.horizontal()), (HEIGHT, node_size.height, padding_border.vertical())]
+    {
+        match size {
+            Defined(size) => fix(&mut solver, boxes[0][axis], Relation::Equal, size, solver::REQUIRED),
+            Undefined => {
+                fix(&mut solver, boxes[0][axis], Relation::GreaterOrEqual, padding_border, solver::REQUIRED);
+                fix(&mut solver, boxes[0][axis], Relation::Equal, 0.0, DEFAULT_CONTAINER_SIZE);
+            }
+        }
+    }
+
+    // Children keep their size and their minimum and maximum sizes, and are otherwise sized to their
+    // content unless stronger constraints size them differently.
+    for (index, &child) in children.iter().enumerate().filter(|(_, child)| is_in_flow(child)) {
+        let variables = boxes[index + 1];
+
+        let size = Size {
+            width: child.size.width.resolve(node_inner_size.width),
+            height: child.size.height.resolve(node_inner_size.height),
+        };
+
+        let intrinsic_size = if size.width.is_defined() && size.height.is_defined() {
+            Size { width: size.width.or_else(0.0), height: size.height.or_else(0.0) }
+        } else {
+            compute_internal(
+                child,
+                size,
+                Size { width: AvailableSpace::MaxContent, height: AvailableSpace::MaxContent },
+                node_inner_size.width,
+                direction,
+            )
+            .size
+        };
+
+        for &(axis, size, min, max, intrinsic, inner_size) in &[
+            (
+                WIDTH,
+                size.width,
+                child.min_size.width,
+                child.max_size.width,
+                intrinsic_size.width,
+                node_inner_size.width,
+            ),
+            (
+                HEIGHT,
+                size.height,
+                child.min_size.height,
+                child.max_size.height,
+                intrinsic_size.height,
+                node_inner_size.height,
+            ),
+        ] {
+            let variable = variables[axis];
+
+            fix(&mut solver, variable, Relation::GreaterOrEqual, 0.0, solver::REQUIRED);
+            if let Defined(min) = min.resolve(inner_size) {
+                fix(&mut solver, variable, Relation::GreaterOrEqual, min, solver::REQUIRED);
+            }
+            if let Defined(max) = max.resolve(inner_size) {
+                fix(&mut solver, variable, Relation::LessOrEqual, max, solver::REQUIRED);
+            }
+
+            match size {
+                Defined(size) => fix(&mut solver, variable, Relation::Equal, size, solver::REQUIRED),
+                Undefined => fix(&mut solver, variable, Relation::Equal, intrinsic, solver::MEDIUM),
+            }
+        }
+    }
+
+    for constraint in node.constraints.iter() {
+        add_constraint(&mut solver, &children, &boxes, constraint);
+    }
+
+    // Children without a position are placed at the start of the content box.
+    for (index, _) in children.iter().enumerate().filter(|(_, child)| is_in_flow(child)) {
+        let variables = boxes[index + 1];
+        fix(&mut solver, variables[LEFT], Relation::Equal, padding_border.start, DEFAULT_POSITION);
+        fix(&mut solver, variables[TOP], Relation::Equal, padding_border.top, DEFAULT_POSITION);
+    }
+
+    let value = |variable: usize| solver.value(variable) as f32;
+
+    let container_size = Size {
+        width: node_size.width.or_else(value(boxes[0][WIDTH])),
+        height: node_size.height.or_else(value(boxes[0][HEIGHT])),
+    };
+
+    let mut content_size: Size<f32> = Size { width: 0.0, height: 0.0 };
+    let mut baselines: Option<(f32, f32)> = None;
+
+    let children: Vec<layout::Node> = children
+        .into_iter()
+        .enumerate()
+        .filter(|(_, child)| is_in_flow(child))
+        .map(|(index, child)| {
+            let variables = boxes[index + 1];
+            let size = Size { width: value(variables[WIDTH]).max(0.0), height: value(variables[HEIGHT]).max(0.0) };
+
+            let result = compute_internal(
+                child,
+                size.map(Defined),
+                size.map(AvailableSpace::Definite),
+                (container_size.width - padding_border.horizontal()).to_number(),
+                direction,
+            );
+
+            let position = child.position.map(|n| n.resolve(container_size.width.to_number()));
+            let location = Point {
+                x: value(variables[LEFT]) + (position.start.or_else(0.0) - position.end.or_else(0.0)),
+                y: value(variables[TOP]) + (position.top.or_else(0.0) - position.bottom.or_else(0.0)),
+            };
+
+            let overflow_size = child_overflow(child, result.size, result.content_size, direction, Direction::LTR);
+            content_size.width =
+                content_size.width.max(location.x + result.size.width.max(overflow_size.width) + padding_border.end);
+            content_size.height = content_size
+                .height
+                .max(location.y + result.size.height.max(overflow_size.height) + padding_border.bottom);
+
+            // The baselines of the container are those of its first and last in-flow children.
+            baselines = Some(match baselines {
+                Some((baseline, _)) => (baseline, location.y + result.last_baseline),
+                None => (location.y + result.baseline, location.y + result.last_baseline),
+            });
+
+            layout::Node {
+                order: index as u32,
+                size: result.size,
+                content_size: result.content_size,
+                location,
+                baseline: result.baseline,
+                last_baseline: result.last_baseline,
+                visible: child.visibility == Visibility::Visible,
+                children: Box::new(result.children),
+            }
+        })
+        .collect();
+
+    let (baseline, last_baseline) = baselines.unwrap_or((container_size.height, container_size.height));
+
+    let static_position = Point { x: padding_border.start, y: padding_border.top };
+    let (children, content_size) =
+        finish_layout(node, children, container_size, border, &|_, _| static_position, direction, content_size);
+
+    ComputeResult { size: container_size, content_size, baseline, last_baseline, children }
+}
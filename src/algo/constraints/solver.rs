@@ -0,0 +1,351 @@
+use std::collections::BTreeMap;
+
+// An incremental implementation of the Cassowary linear arithmetic constraint solving algorithm, see
+// "The Cassowary Linear Arithmetic Constraint Solving Algorithm" by Badros, Borning and Stuckey.
+// Constraints are added one at a time, each updating the simplex tableau of the previous ones, so
+// the solution is kept optimal for the constraints added so far.
+//
+// Computations are done in double precision as pivoting accumulates rounding errors.
+
+const EPSILON: f64 = 1.0e-8;
+
+fn near_zero(value: f64) -> bool {
+    value.abs() < EPSILON
+}
+
+// Non-required constraints are weighted such that a stronger constraint takes precedence over any
+// number of weaker ones in practice, while required constraints are never left unsatisfied.
+pub(super) const REQUIRED: f64 = 1_001_001_000.0;
+pub(super) const STRONG: f64 = 1_000_000.0;
+pub(super) const MEDIUM: f64 = 1_000.0;
+pub(super) const WEAK: f64 = 1.0;
+
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug)]
+enum SymbolKind {
+    // A variable of the layout, which is unrestricted in sign.
+    External,
+    // The slack of an inequality, which is never negative.
+    Slack,
+    // The error of a non-required constraint, which is minimized by the objective.
+    Error,
+    // A placeholder marking a required equality, which is always zero.
+    Dummy,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug)]
+struct Symbol {
+    id: usize,
+    kind: SymbolKind,
+}
+
+// A row of the tableau, the constant plus the sum of the symbols multiplied by their coefficients.
+// Symbols are kept ordered so that the choice of pivots, and hence the solution among several
+// optimal ones, is deterministic.
+#[derive(Clone, Default)]
+struct Row {
+    cells: BTreeMap<Symbol, f64>,
+    constant: f64,
+}
+
+impl Row {
+    fn new(constant: f64) -> Row {
+        Row { cells: BTreeMap::new(), constant }
+    }
+
+    fn coefficient_for(&self, symbol: Symbol) -> f64 {
+        self.cells.get(&symbol).copied().unwrap_or(0.0)
+    }
+
+    fn insert_symbol(&mut self, symbol: Symbol, coefficient: f64) {
+        let value = self.coefficient_for(symbol) + coefficient;
+        if near_zero(value) {
+            self.cells.remove(&symbol);
+        } else {
+            self.cells.insert(symbol, value);
+        }
+    }
+
+    fn insert_row(&mut self, other: &Row, coefficient: f64) {
+        self.constant += other.constant * coefficient;
+        for (symbol, value) in &other.cells {
+            self.insert_symbol(*symbol, value * coefficient);
+        }
+    }
+
+    fn reverse_sign(&mut self) {
+        self.constant = -self.constant;
+        self.cells.values_mut().for_each(|value| *value = -*value);
+    }
+
+    // Solves the row, which equals zero, for the given symbol so that the row becomes its value.
+    fn solve_for(&mut self, symbol: Symbol) {
+        let coefficient = -1.0 / self.cells.remove(&symbol).unwrap_or(1.0);
+        self.constant *= coefficient;
+        self.cells.values_mut().for_each(|value| *value *= coefficient);
+    }
+
+    // Solves the row, which equals the symbol on the left, for the symbol on the right.
+    fn solve_for_symbols(&mut self, left: Symbol, right: Symbol) {
+        self.insert_symbol(left, -1.0);
+        self.solve_for(right);
+    }
+
+    fn substitute(&mut self, symbol: Symbol, row: &Row) {
+        if let Some(coefficient) = self.cells.remove(&symbol) {
+            self.insert_row(row, coefficient);
+        }
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub(super) enum Relation {
+    Equal,
+    LessOrEqual,
+    GreaterOrEqual,
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub(super) enum SolverError {
+    // A required constraint conflicts with the required constraints added before it.
+    Unsatisfiable,
+    // The objective can be decreased indefinitely.
+    Unbounded,
+}
+
+// Which of the objective rows to optimize, the errors of the constraints, or the artificial
+// objective used to find a feasible solution when adding a constraint.
+#[derive(Copy, Clone, PartialEq)]
+enum Objective {
+    Errors,
+    Artificial,
+}
+
+pub(super) struct Solver {
+    rows: BTreeMap<Symbol, Row>,
+    variables: Vec<Symbol>,
+    objective: Row,
+    artificial: Option<Row>,
+    next_id: usize,
+}
+
+impl Solver {
+    pub(super) fn new() -> Solver {
+        Solver { rows: BTreeMap::new(), variables: vec![], objective: Row::default(), artificial: None, next_id: 0 }
+    }
+
+    fn symbol(&mut self, kind: SymbolKind) -> Symbol {
+        self.next_id += 1;
+        Symbol { id: self.next_id, kind }
+    }
+
+    // Creates a new variable, returning its index.
+    pub(super) fn variable(&mut self) -> usize {
+        let symbol = self.symbol(SymbolKind::External);
+        self.variables.push(symbol);
+        self.variables.len() - 1
+    }
+
+    pub(super) fn value(&self, variable: usize) -> f64 {
+        self.rows.get(&self.variables[variable]).map(|row| row.constant).unwrap_or(0.0)
+    }
+
+    // Adds the constraint that the sum of the terms and the constant relates to zero as given. The
+    // tableau is left unchanged when the constraint can't be added.
+    pub(super) fn add_constraint(
+        &mut self,
+        terms: &[(f64, usize)],
+        constant: f64,
+        relation: Relation,
+        strength: f64,
+    ) -> Result<(), SolverError> {
+        let is_required = strength >= REQUIRED;
+
+        // 1. Create a row for the constraint with the basic variables substituted by their rows.
+        // Inequalities get a slack symbol, and constraints which are not required error symbols
+        // which are added to the objective weighted by the strength.
+        let mut row = Row::new(constant);
+        for (coefficient, variable) in terms.iter().filter(|(coefficient, _)| !near_zero(*coefficient)) {
+            let symbol = self.variables[*variable];
+            match self.rows.get(&symbol) {
+                Some(basic) => row.insert_row(basic, *coefficient),
+                None => row.insert_symbol(symbol, *coefficient),
+            }
+        }
+
+        let mut objective = self.objective.clone();
+        let (marker, other) = match relation {
+            Relation::LessOrEqual | Relation::GreaterOrEqual => {
+                let coefficient = if relation == Relation::LessOrEqual { 1.0 } else { -1.0 };
+                let slack = self.symbol(SymbolKind::Slack);
+                row.insert_symbol(slack, coefficient);
+
+                if is_required {
+                    (slack, None)
+                } else {
+                    let error = self.symbol(SymbolKind::Error);
+                    row.insert_symbol(error, -coefficient);
+                    objective.insert_symbol(error, strength);
+                    (slack, Some(error))
+                }
+            }
+            Relation::Equal if is_required => {
+                let dummy = self.symbol(SymbolKind::Dummy);
+                row.insert_symbol(dummy, 1.0);
+                (dummy, None)
+            }
+            Relation::Equal => {
+                let plus = self.symbol(SymbolKind::Error);
+                let minus = self.symbol(SymbolKind::Error);
+                row.insert_symbol(plus, -1.0);
+                row.insert_symbol(minus, 1.0);
+                objective.insert_symbol(plus, strength);
+                objective.insert_symbol(minus, strength);
+                (plus, Some(minus))
+            }
+        };
+
+        if row.constant < 0.0 {
+            row.reverse_sign();
+        }
+
+        // 2. Choose the symbol to solve the row for, an external variable if there is one, otherwise
+        // a new slack or error symbol with a negative coefficient.
+        let subject = row.cells.keys().copied().find(|symbol| symbol.kind == SymbolKind::External).or_else(|| {
+            std::iter::once(marker).chain(other).find(|symbol| {
+                matches!(symbol.kind, SymbolKind::Slack | SymbolKind::Error) && row.coefficient_for(*symbol) < 0.0
+            })
+        });
+
+        let saved_rows = self.rows.clone();
+        let saved_objective = std::mem::replace(&mut self.objective, objective);
+
+        // 3. A row of dummy symbols only is satisfied when its constant is zero, in which case it is
+        // redundant. Without a subject a feasible solution is searched for with an artificial variable.
+        let result = match subject {
+            None if row.cells.keys().all(|symbol| symbol.kind == SymbolKind::Dummy) => {
+                if near_zero(row.constant) {
+                    row.solve_for(marker);
+                    self.insert_row(marker, row);
+                    Ok(())
+                } else {
+                    Err(SolverError::Unsatisfiable)
+                }
+            }
+            None => self.add_with_artificial_variable(row),
+            Some(subject) => {
+                row.solve_for(subject);
+                self.insert_row(subject, row);
+                Ok(())
+            }
+        };
+
+        // 4. Optimize the objective for the new tableau.
+        let result = result.and_then(|_| self.optimize(Objective::Errors));
+
+        if result.is_err() {
+            self.rows = saved_rows;
+            self.objective = saved_objective;
+        }
+
+        result
+    }
+
+    // Makes the symbol basic with the given row, substituting it in the other rows.
+    fn insert_row(&mut self, symbol: Symbol, row: Row) {
+        self.substitute(symbol, &row);
+        self.rows.insert(symbol, row);
+    }
+
+    fn substitute(&mut self, symbol: Symbol, row: &Row) {
+        self.rows.values_mut().for_each(|other| other.substitute(symbol, row));
+        self.objective.substitute(symbol, row);
+        if let Some(artificial) = &mut self.artificial {
+            artificial.substitute(symbol, row);
+        }
+    }
+
+    // Adds the row with a new artificial variable as its subject, and minimizes the artificial
+    // variable. The row is satisfiable when the artificial variable can be brought to zero, after
+    // which the artificial variable is removed from the tableau again.
+    fn add_with_artificial_variable(&mut self, row: Row) -> Result<(), SolverError> {
+        let artificial = self.symbol(SymbolKind::Slack);
+        self.rows.insert(artificial, row.clone());
+        self.artificial = Some(row);
+
+        let optimized = self.optimize(Objective::Artificial);
+        let is_satisfied = self.artificial.take().map(|row| near_zero(row.constant)).unwrap_or(false);
+        optimized?;
+
+        // The artificial variable may still be basic with a zero constant, in which case it is
+        // pivoted out of the basis.
+        if let Some(mut row) = self.rows.remove(&artificial) {
+            if !row.cells.is_empty() {
+                let entering = row
+                    .cells
+                    .keys()
+                    .copied()
+                    .find(|symbol| matches!(symbol.kind, SymbolKind::Slack | SymbolKind::Error))
+                    .ok_or(SolverError::Unsatisfiable)?;
+
+                row.solve_for_symbols(artificial, entering);
+                self.insert_row(entering, row);
+            }
+        }
+
+        self.rows.values_mut().for_each(|row| {
+            row.cells.remove(&artificial);
+        });
+        self.objective.cells.remove(&artificial);
+
+        if is_satisfied {
+            Ok(())
+        } else {
+            Err(SolverError::Unsatisfiable)
+        }
+    }
+
+    // The primal simplex method. Pivots until no symbol of the objective has a negative coefficient,
+    // choosing the leaving row by the minimum ratio test to keep the tableau feasible.
+    fn optimize(&mut self, objective: Objective) -> Result<(), SolverError> {
+        loop {
+            let row = match objective {
+                Objective::Errors => &self.objective,
+                Objective::Artificial => self.artificial.as_ref().unwrap(),
+            };
+
+            let entering = row
+                .cells
+                .iter()
+                .find(|(symbol, coefficient)| symbol.kind != SymbolKind::Dummy && **coefficient < 0.0)
+                .map(|(symbol, _)| *symbol);
+
+            let entering = match entering {
+                Some(entering) => entering,
+                None => return Ok(()),
+            };
+
+            let leaving = self
+                .rows
+                .iter()
+                .filter(|(symbol, _)| symbol.kind != SymbolKind::External)
+                .filter_map(|(symbol, row)| {
+                    let coefficient = row.coefficient_for(entering);
+                    if coefficient < 0.0 {
+                        Some((*symbol, -row.constant / coefficient))
+                    } else {
+                        None
+                    }
+                })
+                .fold(None, |best: Option<(Symbol, f64)>, (symbol, ratio)| match best {
+                    Some((_, best_ratio)) if best_ratio <= ratio => best,
+                    _ => Some((symbol, ratio)),
+                })
+                .map(|(symbol, _)| symbol)
+                .ok_or(SolverError::Unbounded)?;
+
+            let mut row = self.rows.remove(&leaving).unwrap();
+            row.solve_for_symbols(leaving, entering);
+            self.insert_row(entering, row);
+        }
+    }
+}
@@ -0,0 +1,166 @@
+use crate::layout;
+
+use crate::style;
+use crate::style::{CustomLayout, Direction, LayoutChildren, Visibility};
+
+use crate::number::Number::*;
+use crate::number::*;
+
+use crate::geometry::{Point, Rect, Size};
+
+use super::{
+    child_overflow, compute_internal, contents_children, finish_layout, is_in_flow, padding_border, ComputeResult,
+};
+
+// User-defined layout. The in-flow children of the node are handed to its layout algorithm, which
+// sizes the content box and places the children within it, laying them out through the handle
+// below. The layout the algorithm last computed for a child is the one it ends up with.
+
+// The size of a child, with any dimension which isn't given taken from its style, within its minimum
+// and maximum size.
+fn child_size(child: &style::Node, size: Size<Number>, inner_size: Size<Number>) -> Size<Number> {
+    Size {
+        width: size
+            .width
+            .or_else(child.size.width.resolve(inner_size.width))
+            .maybe_max(child.min_size.width.resolve(inner_size.width))
+            .maybe_min(child.max_size.width.resolve(inner_size.width)),
+        height: size
+            .height
+            .or_else(child.size.height.resolve(inner_size.height))
+            .maybe_max(child.min_size.height.resolve(inner_size.height))
+            .maybe_min(child.max_size.height.resolve(inner_size.height)),
+    }
+}
+
+struct Children<'a> {
+    nodes: Vec<(usize, &'a style::Node)>,
+    results: Vec<Option<ComputeResult>>,
+    inner_size: Size<Number>,
+    direction: Direction,
+}
+
+impl<'a> LayoutChildren for Children<'a> {
+    fn count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    fn style(&self, index: usize) -> &style::Node {
+        self.nodes[index].1
+    }
+
+    fn compute(&mut self, index: usize, size: Size<Number>, available_space: Size<AvailableSpace>) -> Size<f32> {
+        let child = self.nodes[index].1;
+        let size = child_size(child, size, self.inner_size);
+        let result = compute_internal(child, size, available_space, self.inner_size.width, self.direction);
+        let size = result.size;
+        self.results[index] = Some(result);
+        size
+    }
+}
+
+pub(super) fn compute(
+    node: &style::Node,
+    node_size: Size<Number>,
+    parent_size: Size<AvailableSpace>,
+    direction: Direction,
+    margin: Rect<f32>,
+    padding: Rect<f32>,
+    border: Rect<f32>,
+) -> ComputeResult {
+    let padding_border = padding_border(padding, border);
+
+    let node_inner_size = Size {
+        width: node_size.width - padding_border.horizontal(),
+        height: node_size.height - padding_border.vertical(),
+    };
+
+    let available_space = Size {
+        width: node_size.width.or_else(parent_size.width - margin.horizontal()) - padding_border.horizontal(),
+        height: node_size.height.or_else(parent_size.height - margin.vertical()) - padding_border.vertical(),
+    };
+
+    let nodes: Vec<(usize, &style::Node)> =
+        contents_children(node).into_iter().enumerate().filter(|(_, child)| is_in_flow(child)).collect();
+
+    let mut handle =
+        Children { results: nodes.iter().map(|_| None).collect(), nodes, inner_size: node_inner_size, direction };
+
+    let CustomLayout { size, locations } = match node.algorithm {
+        Some(ref algorithm) => algorithm.compute(node_inner_size, available_space, &mut handle),
+        None => CustomLayout { size: Size { width: 0.0, height: 0.0 }, locations: vec![] },
+    };
+
+    let container_size = Size {
+        width: node_size.width.or_else(size.width + padding_border.horizontal()),
+        height: node_size.height.or_else(size.height + padding_border.vertical()),
+    };
+
+    let inner_size = Size {
+        width: container_size.width - padding_border.horizontal(),
+        height: container_size.height - padding_border.vertical(),
+    };
+
+    let mut content_size: Size<f32> = Size { width: 0.0, height: 0.0 };
+    let mut baselines: Option<(f32, f32)> = None;
+
+    let Children { nodes, results, .. } = handle;
+
+    let children: Vec<layout::Node> = nodes
+        .into_iter()
+        .zip(results)
+        .enumerate()
+        .map(|(index, ((order, child), result))| {
+            // Children the algorithm didn't lay out are sized to their content.
+            let result = result.unwrap_or_else(|| {
+                compute_internal(
+                    child,
+                    child_size(child, Size { width: Undefined, height: Undefined }, inner_size.map(Defined)),
+                    inner_size.map(AvailableSpace::Definite),
+                    Defined(inner_size.width),
+                    direction,
+                )
+            });
+
+            let position = child.position.map(|n| n.resolve(Defined(inner_size.width)));
+            let offset = locations.get(index).copied().unwrap_or(Point { x: 0.0, y: 0.0 });
+
+            let location = Point {
+                x: padding_border.start + offset.x + (position.start.or_else(0.0) - position.end.or_else(0.0)),
+                y: padding_border.top + offset.y + (position.top.or_else(0.0) - position.bottom.or_else(0.0)),
+            };
+
+            let overflow_size = child_overflow(child, result.size, result.content_size, direction, Direction::LTR);
+            content_size.width =
+                content_size.width.max(location.x + result.size.width.max(overflow_size.width) + padding_border.end);
+            content_size.height = content_size
+                .height
+                .max(location.y + result.size.height.max(overflow_size.height) + padding_border.bottom);
+
+            // The baselines of the node are those of its first and last in-flow children.
+            baselines = Some(match baselines {
+                Some((baseline, _)) => (baseline, location.y + result.last_baseline),
+                None => (location.y + result.baseline, location.y + result.last_baseline),
+            });
+
+            layout::Node {
+                order: order as u32,
+                size: result.size,
+                content_size: result.content_size,
+                location,
+                baseline: result.baseline,
+                last_baseline: result.last_baseline,
+                visible: child.visibility == Visibility::Visible,
+                children: Box::new(result.children),
+            }
+        })
+        .collect();
+
+    let (baseline, last_baseline) = baselines.unwrap_or((container_size.height, container_size.height));
+
+    let static_position = Point { x: padding_border.start, y: padding_border.top };
+    let (children, content_size) =
+        finish_layout(node, children, container_size, border, &|_, _| static_position, direction, content_size);
+
+    ComputeResult { size: container_size, content_size, baseline, last_baseline, children }
+}
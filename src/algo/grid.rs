@@ -0,0 +1,1077 @@
+use std::f32;
+
+use crate::layout;
+
+use crate::style;
+use crate::style::{
+    AlignContent, AlignSelf, AlignmentSafety, Dimension, Direction, FlexDirection, GridPlacement, GridTrack,
+    GridTrackRepetition, JustifyContent, MaxTrackSizingFunction, MinTrackSizingFunction, TrackSizingFunction,
+    Visibility,
+};
+
+use crate::number::Number::*;
+use crate::number::*;
+
+use crate::geometry::{Line, Point, Rect, Size};
+
+use super::{
+    child_overflow, compute_internal, contents_children, finish_layout, is_in_flow, padding_border, ComputeResult,
+};
+
+pub(super) mod masonry;
+
+// Grid layout reuses the axis helpers of the flex layout. Columns are sized along the inline
+// axis, the main axis of a row, and rows along the block axis, the main axis of a column.
+const COLUMNS: FlexDirection = FlexDirection::Row;
+const ROWS: FlexDirection = FlexDirection::Column;
+
+struct GridItem<'a> {
+    node: &'a style::Node,
+    order: u32,
+
+    row: Line<usize>,
+    column: Line<usize>,
+
+    margin: Rect<f32>,
+}
+
+impl<'a> GridItem<'a> {
+    fn span(&self, axis: FlexDirection) -> Line<usize> {
+        if axis == COLUMNS {
+            self.column
+        } else {
+            self.row
+        }
+    }
+}
+
+struct GridTrackSize {
+    min: MinTrackSizingFunction,
+    max: MaxTrackSizingFunction,
+
+    // Tracks repeated to fit the container which end up without any items are collapsed. They
+    // have no size and no gaps on either side.
+    collapsible: bool,
+    collapsed: bool,
+
+    base_size: f32,
+    growth_limit: f32,
+    offset: f32,
+}
+
+impl GridTrackSize {
+    fn new(sizing: TrackSizingFunction, collapsible: bool) -> GridTrackSize {
+        GridTrackSize {
+            min: sizing.min,
+            max: sizing.max,
+            collapsible,
+            collapsed: false,
+            base_size: 0.0,
+            growth_limit: f32::INFINITY,
+            offset: 0.0,
+        }
+    }
+
+    fn is_flexible(&self) -> bool {
+        matches!(self.max, MaxTrackSizingFunction::Flex(_))
+    }
+
+    fn flex_factor(&self) -> f32 {
+        match self.max {
+            MaxTrackSizingFunction::Flex(flex) => flex,
+            _ => 0.0,
+        }
+    }
+
+    fn has_intrinsic_min(&self) -> bool {
+        matches!(
+            self.min,
+            MinTrackSizingFunction::Auto | MinTrackSizingFunction::MinContent | MinTrackSizingFunction::MaxContent
+        )
+    }
+
+    fn has_intrinsic_max(&self) -> bool {
+        matches!(
+            self.max,
+            MaxTrackSizingFunction::Auto | MaxTrackSizingFunction::MinContent | MaxTrackSizingFunction::MaxContent
+        )
+    }
+}
+
+pub(super) fn compute(
+    node: &style::Node,
+    node_size: Size<Number>,
+    parent_size: Size<AvailableSpace>,
+    direction: Direction,
+    margin: Rect<f32>,
+    padding: Rect<f32>,
+    border: Rect<f32>,
+) -> ComputeResult {
+    let padding_border = padding_border(padding, border);
+
+    let node_inner_size = Size {
+        width: node_size.width - padding_border.horizontal(),
+        height: node_size.height - padding_border.vertical(),
+    };
+
+    let available_space = Size {
+        width: node_size.width.or_else(parent_size.width - margin.horizontal()) - padding_border.horizontal(),
+        height: node_size.height.or_else(parent_size.height - margin.vertical()) - padding_border.vertical(),
+    };
+
+    // The width of the gap is the gap between columns and the height the gap between rows.
+    let gap = Size {
+        width: node.gap.width.resolve(node_inner_size.width).or_else(0.0),
+        height: node.gap.height.resolve(node_inner_size.height).or_else(0.0),
+    };
+
+    // 7.1. The Explicit Grid. Repeated tracks are expanded, filling the container when the
+    // number of repetitions is automatic.
+
+    let explicit_columns = expand_tracks(&node.grid_template_columns, node_inner_size.width, gap.width);
+    let explicit_rows = expand_tracks(&node.grid_template_rows, node_inner_size.height, gap.height);
+
+    // 8. Placing Grid Items. Items are placed in order-modified document order.
+
+    let mut items: Vec<GridItem> = contents_children(node)
+        .into_iter()
+        .enumerate()
+        .filter(|(_, child)| is_in_flow(child))
+        .map(|(order, child)| GridItem {
+            node: child,
+            order: order as u32,
+            row: Line { start: 0, end: 0 },
+            column: Line { start: 0, end: 0 },
+            margin: child.margin.map(|n| n.resolve(node_inner_size.width).or_else(0.0)),
+        })
+        .collect();
+
+    items.sort_by_key(|item| item.node.order);
+
+    let (row_offset, row_count, column_offset, column_count) =
+        place_items(node, &mut items, explicit_rows.len(), explicit_columns.len());
+
+    // The implicit grid consists of the explicit tracks surrounded by implicit tracks sized by
+    // grid-auto-rows and grid-auto-columns, created for items placed outside the explicit grid.
+
+    let mut columns = implicit_tracks(&explicit_columns, &node.grid_auto_columns, column_offset, column_count);
+    let mut rows = implicit_tracks(&explicit_rows, &node.grid_auto_rows, row_offset, row_count);
+
+    collapse_empty_tracks(&mut columns, &items, COLUMNS);
+    collapse_empty_tracks(&mut rows, &items, ROWS);
+
+    // 11. Grid Sizing. Columns are sized first so that the widths of the items are known when
+    // sizing the rows, which lets items do height-for-width sizing of their content.
+
+    let column_contribution =
+        |item: &GridItem, constraint: AvailableSpace| column_contribution(item, constraint, direction);
+
+    let column_space =
+        track_sizing_space(node_inner_size.width, available_space.width, node.max_size.width, node.min_size.width);
+    size_tracks(
+        &mut columns,
+        &items,
+        COLUMNS,
+        node_inner_size.width,
+        column_space,
+        gap.width,
+        stretches_tracks_justify(node.justify_content),
+        &column_contribution,
+    );
+
+    let row_contribution = |item: &GridItem, constraint: AvailableSpace| {
+        let area_width = tracks_size(&columns[item.column.start..item.column.end], gap.width);
+        let width = item_width(item, area_width);
+        let height = item.node.size.height.resolve(Undefined);
+        let min_height = item.node.min_size.height.resolve(Undefined);
+        let max_height = item.node.max_size.height.resolve(Undefined);
+
+        let height = match height {
+            Defined(height) => height,
+            Undefined => {
+                compute_internal(
+                    item.node,
+                    Size { width, height: Undefined },
+                    Size { width: AvailableSpace::Definite(area_width), height: constraint },
+                    area_width.to_number(),
+                    direction,
+                )
+                .size
+                .height
+            }
+        };
+
+        height.maybe_max(min_height).maybe_min(max_height) + item.margin.vertical()
+    };
+
+    let row_space =
+        track_sizing_space(node_inner_size.height, available_space.height, node.max_size.height, node.min_size.height);
+    size_tracks(
+        &mut rows,
+        &items,
+        ROWS,
+        node_inner_size.height,
+        row_space,
+        gap.height,
+        stretches_tracks_align(node.align_content),
+        &row_contribution,
+    );
+
+    let container_size = Size {
+        width: node_size.width.or_else(tracks_size(&columns, gap.width) + padding_border.horizontal()),
+        height: node_size.height.or_else(tracks_size(&rows, gap.height) + padding_border.vertical()),
+    };
+
+    // 10.5. Aligning the Grid. Any space left after sizing the tracks is distributed according
+    // to justify-content between the columns and align-content between the rows.
+
+    align_tracks(
+        &mut columns,
+        container_size.width - padding_border.horizontal(),
+        gap.width,
+        justify_tracks(node.justify_content, direction),
+        node.justify_content_safety,
+        padding_border.start,
+    );
+    align_tracks(
+        &mut rows,
+        container_size.height - padding_border.vertical(),
+        gap.height,
+        node.align_content,
+        node.align_content_safety,
+        padding_border.top,
+    );
+
+    // 9. Grid Items are laid out within the grid areas given by the tracks they span. Items
+    // without a definite size are stretched to fill their area unless they have auto margins,
+    // and are otherwise aligned within it. Items are aligned along the block axis per align-self
+    // and placed at the start of the inline axis.
+
+    let mut content_size: Size<f32> = Size { width: 0.0, height: 0.0 };
+
+    let children: Vec<layout::Node> = items
+        .iter()
+        .map(|item| {
+            let area = Rect {
+                start: columns[item.column.start].offset,
+                end: columns[item.column.end - 1].offset + columns[item.column.end - 1].base_size,
+                top: rows[item.row.start].offset,
+                bottom: rows[item.row.end - 1].offset + rows[item.row.end - 1].base_size,
+            };
+
+            let area_size = Size { width: area.end - area.start, height: area.bottom - area.top };
+            let align_self = item.node.align_self(node);
+
+            let height = match item.node.size.height.resolve(area_size.height.to_number()) {
+                Defined(height) => Defined(height),
+                Undefined => {
+                    if align_self == AlignSelf::Stretch
+                        && item.node.margin.top != Dimension::Auto
+                        && item.node.margin.bottom != Dimension::Auto
+                    {
+                        Defined(area_size.height - item.margin.vertical())
+                    } else {
+                        Undefined
+                    }
+                }
+            };
+
+            let size = Size {
+                width: item_width(item, area_size.width),
+                height: height
+                    .maybe_max(item.node.min_size.height.resolve(area_size.height.to_number()))
+                    .maybe_min(item.node.max_size.height.resolve(area_size.height.to_number())),
+            };
+
+            let result = compute_internal(
+                item.node,
+                size,
+                area_size.map(AvailableSpace::Definite),
+                area_size.width.to_number(),
+                direction,
+            );
+
+            let free_space = Size {
+                width: area_size.width - result.size.width - item.margin.horizontal(),
+                height: area_size.height - result.size.height - item.margin.vertical(),
+            };
+
+            let offset_x = match (item.node.margin.start, item.node.margin.end) {
+                (Dimension::Auto, Dimension::Auto) => free_space.width / 2.0,
+                (Dimension::Auto, _) => free_space.width,
+                _ => 0.0,
+            };
+
+            let offset_y = match (item.node.margin.top, item.node.margin.bottom) {
+                (Dimension::Auto, Dimension::Auto) => free_space.height / 2.0,
+                (Dimension::Auto, _) => free_space.height,
+                _ if item.node.align_self_safety(node) == AlignmentSafety::Safe && free_space.height < 0.0 => 0.0,
+                _ => match align_self {
+                    AlignSelf::FlexEnd | AlignSelf::End | AlignSelf::LastBaseline => free_space.height,
+                    AlignSelf::Center => free_space.height / 2.0,
+                    _ => 0.0,
+                },
+            };
+
+            let position = item.node.position.map(|n| n.resolve(area_size.width.to_number()));
+
+            let location = Point {
+                x: area.start
+                    + item.margin.start
+                    + offset_x
+                    + (position.start.or_else(0.0) - position.end.or_else(0.0)),
+                y: area.top + item.margin.top + offset_y + (position.top.or_else(0.0) - position.bottom.or_else(0.0)),
+            };
+
+            let overflow_size = child_overflow(item.node, result.size, result.content_size, direction, direction);
+
+            content_size.width = content_size
+                .width
+                .max(location.x + (result.size.width + item.margin.end).max(overflow_size.width) + padding_border.end);
+            content_size.height = content_size.height.max(
+                location.y
+                    + (result.size.height + item.margin.bottom).max(overflow_size.height)
+                    + padding_border.bottom,
+            );
+
+            layout::Node {
+                order: item.order,
+                size: result.size,
+                content_size: result.content_size,
+                location,
+                baseline: result.baseline,
+                last_baseline: result.last_baseline,
+                visible: item.node.visibility == Visibility::Visible,
+                children: Box::new(result.children),
+            }
+        })
+        .collect();
+
+    // The baselines of the grid are taken from the first item of its first row and the last item
+    // of its last row. Without items they are synthesized from the bottom of the border box.
+
+    let baseline = items
+        .iter()
+        .zip(children.iter())
+        .min_by_key(|(item, _)| (item.row.start, item.column.start))
+        .map(|(_, layout)| layout.location.y + layout.baseline)
+        .unwrap_or(container_size.height);
+
+    let last_baseline = items
+        .iter()
+        .zip(children.iter())
+        .max_by_key(|(item, _)| (item.row.end, item.column.end))
+        .map(|(_, layout)| layout.location.y + layout.last_baseline)
+        .unwrap_or(container_size.height);
+
+    let static_position = Point { x: padding_border.start, y: padding_border.top };
+    let (children, content_size) =
+        finish_layout(node, children, container_size, border, &|_, _| static_position, direction, content_size);
+
+    ComputeResult { size: container_size, content_size, baseline, last_baseline, children }
+}
+
+// The contribution of an item to the size of the columns it spans, which is its width along with
+// its margins when sized under the given constraint.
+fn column_contribution(item: &GridItem, constraint: AvailableSpace, direction: Direction) -> f32 {
+    let width = item.node.size.width.resolve(Undefined);
+    let min_width = item.node.min_size.width.resolve(Undefined);
+    let max_width = item.node.max_size.width.resolve(Undefined);
+
+    let width = match width {
+        Defined(width) => width,
+        Undefined => {
+            compute_internal(
+                item.node,
+                Size { width: Undefined, height: item.node.size.height.resolve(Undefined) },
+                Size { width: constraint, height: AvailableSpace::MaxContent },
+                Undefined,
+                direction,
+            )
+            .size
+            .width
+        }
+    };
+
+    width.maybe_max(min_width).maybe_min(max_width) + item.margin.horizontal()
+}
+
+// Columns are distributed per justify-content, which is mapped to the equivalent content
+// distribution with left and right resolved against the direction.
+fn justify_tracks(justify_content: JustifyContent, direction: Direction) -> AlignContent {
+    match justify_content {
+        JustifyContent::FlexStart | JustifyContent::Start => AlignContent::Start,
+        JustifyContent::FlexEnd | JustifyContent::End => AlignContent::End,
+        JustifyContent::Center => AlignContent::Center,
+        JustifyContent::SpaceBetween => AlignContent::SpaceBetween,
+        JustifyContent::SpaceAround => AlignContent::SpaceAround,
+        JustifyContent::SpaceEvenly => AlignContent::SpaceEvenly,
+        JustifyContent::Stretch | JustifyContent::Normal => AlignContent::Stretch,
+        JustifyContent::Left if direction == Direction::RTL => AlignContent::End,
+        JustifyContent::Right if direction != Direction::RTL => AlignContent::End,
+        JustifyContent::Left | JustifyContent::Right => AlignContent::Start,
+    }
+}
+
+// The width of an item laid out in a grid area, items without a definite width are stretched
+// to fill the area unless they have auto margins in the inline axis.
+fn item_width(item: &GridItem, area_width: f32) -> Number {
+    let width = match item.node.size.width.resolve(area_width.to_number()) {
+        Defined(width) => Defined(width),
+        Undefined => {
+            if item.node.margin.start != Dimension::Auto && item.node.margin.end != Dimension::Auto {
+                Defined(area_width - item.margin.horizontal())
+            } else {
+                Undefined
+            }
+        }
+    };
+
+    width
+        .maybe_max(item.node.min_size.width.resolve(area_width.to_number()))
+        .maybe_min(item.node.max_size.width.resolve(area_width.to_number()))
+}
+
+// Normal and stretch content distribution stretch auto sized tracks to fill the grid container.
+fn stretches_tracks_justify(justify_content: JustifyContent) -> bool {
+    justify_content == JustifyContent::Normal || justify_content == JustifyContent::Stretch
+}
+
+fn stretches_tracks_align(align_content: AlignContent) -> bool {
+    align_content == AlignContent::Normal || align_content == AlignContent::Stretch
+}
+
+// Expands the tracks of a grid template, returning each track along with whether it was repeated
+// to fit the container so that it can be collapsed when empty.
+fn expand_tracks(template: &[GridTrack], inner_size: Number, gap: f32) -> Vec<(TrackSizingFunction, bool)> {
+    // 7.2.3.2. Repeat-to-fill. The number of repetitions is the largest number which doesn't make
+    // the grid overflow its container, treating each track as its maximum size if it is definite
+    // and its minimum size otherwise. Without a definite container size the tracks repeat once.
+    let fixed_size = |sizing: &TrackSizingFunction| match (sizing.max, sizing.min) {
+        (MaxTrackSizingFunction::Points(points), _) => points,
+        (MaxTrackSizingFunction::Percent(percent), _) => (inner_size * percent).or_else(0.0),
+        (_, MinTrackSizingFunction::Points(points)) => points,
+        (_, MinTrackSizingFunction::Percent(percent)) => (inner_size * percent).or_else(0.0),
+        _ => 0.0,
+    };
+
+    let (fixed_tracks, fixed_total) = template.iter().fold((0, 0.0), |(count, total), track| match track {
+        GridTrack::Single(sizing) => (count + 1, total + fixed_size(sizing)),
+        GridTrack::Repeat(GridTrackRepetition::Count(repetitions), tracks) => (
+            count + tracks.len() * *repetitions as usize,
+            total + tracks.iter().map(fixed_size).sum::<f32>() * *repetitions as f32,
+        ),
+        GridTrack::Repeat(_, _) => (count, total),
+    });
+
+    let auto_repetitions = |tracks: &Vec<TrackSizingFunction>| match inner_size {
+        Defined(inner_size) => {
+            let free_space = inner_size - fixed_total - gap * (fixed_tracks as f32 - 1.0).max(0.0);
+            let repetition_size = tracks.iter().map(fixed_size).sum::<f32>() + gap * tracks.len() as f32;
+
+            if repetition_size > 0.0 {
+                ((free_space + if fixed_tracks == 0 { gap } else { 0.0 }) / repetition_size).floor().max(1.0) as usize
+            } else {
+                1
+            }
+        }
+        Undefined => 1,
+    };
+
+    template
+        .iter()
+        .flat_map(|track| match track {
+            GridTrack::Single(sizing) => vec![(*sizing, false)],
+            GridTrack::Repeat(repetition, tracks) => {
+                let (repetitions, collapsible) = match repetition {
+                    GridTrackRepetition::Count(repetitions) => (*repetitions as usize, false),
+                    GridTrackRepetition::AutoFill => (auto_repetitions(tracks), false),
+                    GridTrackRepetition::AutoFit => (auto_repetitions(tracks), true),
+                };
+
+                (0..repetitions).flat_map(|_| tracks.iter().map(|sizing| (*sizing, collapsible))).collect()
+            }
+        })
+        .collect()
+}
+
+// The line index an item starts at, if placed explicitly, and the number of tracks it spans.
+type Placement = (Option<i32>, i32);
+
+// Resolves the placement of an item in one axis to the zero based line index its start is placed
+// at, relative to the start of the explicit grid, and the number of tracks it spans. The start is
+// None if the item is placed automatically in this axis.
+fn resolve_placement(placement: Line<GridPlacement>, explicit_tracks: usize) -> Placement {
+    let line = |line: i16| {
+        if line > 0 {
+            Some(line as i32 - 1)
+        } else if line < 0 {
+            Some(explicit_tracks as i32 + 1 + line as i32)
+        } else {
+            None
+        }
+    };
+
+    let span = |span: u16| (span as i32).max(1);
+
+    match (placement.start, placement.end) {
+        (GridPlacement::Line(start), GridPlacement::Line(end)) => match (line(start), line(end)) {
+            (Some(start), Some(end)) if start == end => (Some(start), 1),
+            (Some(start), Some(end)) => (Some(start.min(end)), (end - start).abs()),
+            (Some(start), None) => (Some(start), 1),
+            (None, Some(end)) => (Some(end - 1), 1),
+            (None, None) => (None, 1),
+        },
+        (GridPlacement::Line(start), GridPlacement::Span(n)) => (line(start), span(n)),
+        (GridPlacement::Line(start), GridPlacement::Auto) => (line(start), 1),
+        (GridPlacement::Span(n), GridPlacement::Line(end)) => (line(end).map(|end| end - span(n)), span(n)),
+        (GridPlacement::Auto, GridPlacement::Line(end)) => (line(end).map(|end| end - 1), 1),
+        (GridPlacement::Span(n), _) | (GridPlacement::Auto, GridPlacement::Span(n)) => (None, span(n)),
+        (GridPlacement::Auto, GridPlacement::Auto) => (None, 1),
+    }
+}
+
+// 8.5. Grid Item Placement Algorithm. Places each item in the grid, returning how many implicit
+// rows come before the explicit grid, the total number of rows, and the same for columns.
+fn place_items(
+    node: &style::Node,
+    items: &mut [GridItem],
+    explicit_rows: usize,
+    explicit_columns: usize,
+) -> (usize, usize, usize, usize) {
+    // The algorithm is written for the axis items are flowed along, which is the columns for
+    // row flow. The major axis is the one new tracks are created in to fit more items.
+    let is_row_flow = node.grid_auto_flow.is_row();
+    let is_dense = node.grid_auto_flow.is_dense();
+
+    let (explicit_major, explicit_minor) =
+        if is_row_flow { (explicit_rows, explicit_columns) } else { (explicit_columns, explicit_rows) };
+
+    let placements: Vec<(Placement, Placement)> = items
+        .iter()
+        .map(|item| {
+            let row = resolve_placement(item.node.grid_row, explicit_rows);
+            let column = resolve_placement(item.node.grid_column, explicit_columns);
+            if is_row_flow {
+                (row, column)
+            } else {
+                (column, row)
+            }
+        })
+        .collect();
+
+    // Placed areas as major start, major end, minor start, minor end.
+    let mut areas: Vec<Option<(i32, i32, i32, i32)>> = vec![None; items.len()];
+
+    let is_free = |areas: &Vec<Option<(i32, i32, i32, i32)>>, area: (i32, i32, i32, i32)| {
+        areas
+            .iter()
+            .flatten()
+            .all(|placed| area.0 >= placed.1 || area.1 <= placed.0 || area.2 >= placed.3 || area.3 <= placed.2)
+    };
+
+    // 1. Position anything that's not auto-positioned.
+    for (i, placement) in placements.iter().enumerate() {
+        if let ((Some(major), major_span), (Some(minor), minor_span)) = *placement {
+            areas[i] = Some((major, major + major_span, minor, minor + minor_span));
+        }
+    }
+
+    // 2. Process the items locked to a given major track, searching for the first free position
+    // along the minor axis. Sparse placement never goes back to positions before an item
+    // previously placed in the same track by this step.
+    let mut cursors: Vec<(i32, i32)> = vec![];
+    for (i, placement) in placements.iter().enumerate() {
+        if let ((Some(major), major_span), (None, minor_span)) = *placement {
+            let cursor = cursors.iter().find(|(track, _)| *track == major).map(|(_, minor)| *minor);
+            let mut minor = if is_dense { 0 } else { cursor.unwrap_or(0) };
+
+            while !is_free(&areas, (major, major + major_span, minor, minor + minor_span)) {
+                minor += 1;
+            }
+
+            areas[i] = Some((major, major + major_span, minor, minor + minor_span));
+            cursors.retain(|(track, _)| *track != major);
+            cursors.push((major, minor + minor_span));
+        }
+    }
+
+    // 3. Determine the tracks in the implicit grid along the minor axis, which has room for the
+    // explicit tracks, all items placed so far, and the largest span of the remaining items.
+    let minor_start = areas.iter().flatten().map(|area| area.2).fold(0, i32::min);
+    let minor_end = areas
+        .iter()
+        .flatten()
+        .map(|area| area.3)
+        .chain(placements.iter().map(|placement| minor_start + (placement.1).1))
+        .fold(explicit_minor as i32, i32::max);
+
+    // 4. Position the remaining grid items using a cursor that moves along the minor axis and
+    // wraps to the next major track.
+    let major_start = areas.iter().flatten().map(|area| area.0).fold(0, i32::min);
+    let mut cursor = (major_start, minor_start);
+
+    for (i, placement) in placements.iter().enumerate() {
+        if areas[i].is_some() {
+            continue;
+        }
+
+        let ((_, major_span), (minor, minor_span)) = *placement;
+
+        match minor {
+            Some(minor) => {
+                if is_dense {
+                    cursor.0 = major_start;
+                } else if minor < cursor.1 {
+                    cursor.0 += 1;
+                }
+                cursor.1 = minor;
+
+                while !is_free(&areas, (cursor.0, cursor.0 + major_span, minor, minor + minor_span)) {
+                    cursor.0 += 1;
+                }
+            }
+            None => {
+                if is_dense {
+                    cursor = (major_start, minor_start);
+                }
+
+                loop {
+                    if cursor.1 + minor_span > minor_end {
+                        cursor = (cursor.0 + 1, minor_start);
+                    } else if is_free(&areas, (cursor.0, cursor.0 + major_span, cursor.1, cursor.1 + minor_span)) {
+                        break;
+                    } else {
+                        cursor.1 += 1;
+                    }
+                }
+            }
+        }
+
+        areas[i] = Some((cursor.0, cursor.0 + major_span, cursor.1, cursor.1 + minor_span));
+    }
+
+    let areas: Vec<(i32, i32, i32, i32)> = areas.into_iter().flatten().collect();
+
+    let major_start = areas.iter().map(|area| area.0).fold(0, i32::min);
+    let major_end = areas.iter().map(|area| area.1).fold(explicit_major as i32, i32::max);
+    let minor_end = areas.iter().map(|area| area.3).fold(minor_end, i32::max);
+
+    items.iter_mut().zip(areas.iter()).for_each(|(item, area)| {
+        let major = Line { start: (area.0 - major_start) as usize, end: (area.1 - major_start) as usize };
+        let minor = Line { start: (area.2 - minor_start) as usize, end: (area.3 - minor_start) as usize };
+
+        if is_row_flow {
+            item.row = major;
+            item.column = minor;
+        } else {
+            item.row = minor;
+            item.column = major;
+        }
+    });
+
+    let major = (-major_start as usize, (major_end - major_start) as usize);
+    let minor = (-minor_start as usize, (minor_end - minor_start) as usize);
+
+    if is_row_flow {
+        (major.0, major.1, minor.0, minor.1)
+    } else {
+        (minor.0, minor.1, major.0, major.1)
+    }
+}
+
+// Creates the tracks of the implicit grid, where implicit tracks before and after the explicit
+// tracks cycle through the auto track sizes.
+fn implicit_tracks(
+    explicit: &[(TrackSizingFunction, bool)],
+    auto: &[TrackSizingFunction],
+    before: usize,
+    count: usize,
+) -> Vec<GridTrackSize> {
+    let auto_track = |index: isize| {
+        if auto.is_empty() {
+            TrackSizingFunction::auto()
+        } else {
+            let len = auto.len() as isize;
+            auto[(((index % len) + len) % len) as usize]
+        }
+    };
+
+    (0..count)
+        .map(|track| {
+            let index = track as isize - before as isize;
+            if index < 0 {
+                GridTrackSize::new(auto_track(index), false)
+            } else if (index as usize) < explicit.len() {
+                let (sizing, collapsible) = explicit[index as usize];
+                GridTrackSize::new(sizing, collapsible)
+            } else {
+                GridTrackSize::new(auto_track(index - explicit.len() as isize), false)
+            }
+        })
+        .collect()
+}
+
+fn collapse_empty_tracks(tracks: &mut [GridTrackSize], items: &[GridItem], axis: FlexDirection) {
+    tracks.iter_mut().enumerate().filter(|(_, track)| track.collapsible).for_each(|(index, track)| {
+        track.collapsed = items.iter().all(|item| {
+            let span = item.span(axis);
+            index < span.start || index >= span.end
+        });
+    });
+}
+
+// The space the tracks are sized into. It is definite when the container size is, otherwise the
+// tracks are sized under a min-content or max-content constraint. A definite maximum size limits
+// the space of a container sized to its content, and a minimum size is filled by flexible tracks.
+fn track_sizing_space(inner_size: Number, available_space: AvailableSpace, max: Dimension, min: Dimension) -> Number {
+    match (inner_size, available_space) {
+        (Defined(inner_size), _) => Defined(inner_size),
+        (Undefined, AvailableSpace::MinContent) => Undefined,
+        _ => max.resolve(Undefined).or_else(min.resolve(Undefined)),
+    }
+}
+
+// The total size of the tracks along with the gaps between them. Collapsed tracks have no gaps.
+fn tracks_size(tracks: &[GridTrackSize], gap: f32) -> f32 {
+    let count = tracks.iter().filter(|track| !track.collapsed).count();
+    tracks.iter().map(|track| track.base_size).sum::<f32>() + gap * (count.max(1) - 1) as f32
+}
+
+// 11.3 - 11.8. The track sizing algorithm.
+#[allow(clippy::too_many_arguments)]
+fn size_tracks(
+    tracks: &mut [GridTrackSize],
+    items: &[GridItem],
+    axis: FlexDirection,
+    inner_size: Number,
+    space: Number,
+    gap: f32,
+    stretch: bool,
+    contribution: &dyn Fn(&GridItem, AvailableSpace) -> f32,
+) {
+    let is_min_content = inner_size.is_undefined() && space.is_undefined();
+    let gaps = |span: Line<usize>, tracks: &[GridTrackSize]| {
+        gap * (tracks[span.start..span.end].iter().filter(|track| !track.collapsed).count().max(1) - 1) as f32
+    };
+
+    // 11.4. Initialize Track Sizes. Percentages of an indefinite container size behave as auto.
+    tracks.iter_mut().for_each(|track| {
+        track.min = match track.min {
+            MinTrackSizingFunction::Percent(percent) => match inner_size * percent {
+                Defined(size) => MinTrackSizingFunction::Points(size),
+                Undefined => MinTrackSizingFunction::Auto,
+            },
+            min => min,
+        };
+
+        track.max = match track.max {
+            MaxTrackSizingFunction::Percent(percent) => match inner_size * percent {
+                Defined(size) => MaxTrackSizingFunction::Points(size),
+                Undefined => MaxTrackSizingFunction::Auto,
+            },
+            max => max,
+        };
+
+        if track.collapsed {
+            track.min = MinTrackSizingFunction::Points(0.0);
+            track.max = MaxTrackSizingFunction::Points(0.0);
+        }
+
+        track.base_size = match track.min {
+            MinTrackSizingFunction::Points(points) => points,
+            _ => 0.0,
+        };
+
+        track.growth_limit = match track.max {
+            MaxTrackSizingFunction::Points(points) => points.max(track.base_size),
+            _ => f32::INFINITY,
+        };
+    });
+
+    // 11.5. Resolve Intrinsic Track Sizes. Items spanning a single track size it directly, items
+    // spanning several tracks distribute the space they need beyond the tracks' current sizes
+    // equally between those of the tracks with intrinsic sizing functions. Items are handled
+    // in order of increasing span.
+
+    let mut sorted_items: Vec<&GridItem> = items.iter().collect();
+    sorted_items.sort_by_key(|item| item.span(axis).end - item.span(axis).start);
+
+    // The minimum contribution of an item is its min-content contribution, or its max-content
+    // contribution when the grid is sized under a max-content constraint.
+    let minimum_constraint =
+        if space.is_undefined() && !is_min_content { AvailableSpace::MaxContent } else { AvailableSpace::MinContent };
+
+    for item in sorted_items.iter() {
+        let span = item.span(axis);
+
+        if tracks[span.start..span.end].iter().any(|track| track.is_flexible()) {
+            continue;
+        }
+
+        if span.end - span.start == 1 {
+            let track = &mut tracks[span.start];
+
+            track.base_size = match track.min {
+                MinTrackSizingFunction::MinContent => {
+                    track.base_size.max(contribution(item, AvailableSpace::MinContent))
+                }
+                MinTrackSizingFunction::MaxContent => {
+                    track.base_size.max(contribution(item, AvailableSpace::MaxContent))
+                }
+                MinTrackSizingFunction::Auto => track.base_size.max(contribution(item, minimum_constraint)),
+                _ => track.base_size,
+            };
+
+            let limit = match track.max {
+                MaxTrackSizingFunction::MinContent => Some(contribution(item, AvailableSpace::MinContent)),
+                MaxTrackSizingFunction::MaxContent | MaxTrackSizingFunction::Auto => {
+                    Some(contribution(item, AvailableSpace::MaxContent))
+                }
+                _ => None,
+            };
+
+            if let Some(limit) = limit {
+                track.growth_limit =
+                    if track.growth_limit.is_infinite() { limit } else { track.growth_limit.max(limit) };
+            }
+        } else {
+            let span_gaps = gaps(span, tracks);
+            let spanned = &mut tracks[span.start..span.end];
+
+            let intrinsic_min = spanned.iter().filter(|track| track.has_intrinsic_min()).count();
+            let extra = contribution(item, minimum_constraint)
+                - span_gaps
+                - spanned.iter().map(|track| track.base_size).sum::<f32>();
+
+            if intrinsic_min > 0 && extra > 0.0 {
+                spanned
+                    .iter_mut()
+                    .filter(|track| track.has_intrinsic_min())
+                    .for_each(|track| track.base_size += extra / intrinsic_min as f32);
+            }
+
+            let intrinsic_max = spanned.iter().filter(|track| track.has_intrinsic_max()).count();
+            if intrinsic_max > 0 {
+                spanned.iter_mut().filter(|track| track.growth_limit.is_infinite()).for_each(|track| {
+                    track.growth_limit = track.base_size;
+                });
+
+                let extra = contribution(item, AvailableSpace::MaxContent)
+                    - span_gaps
+                    - spanned.iter().map(|track| track.growth_limit).sum::<f32>();
+
+                if extra > 0.0 {
+                    spanned
+                        .iter_mut()
+                        .filter(|track| track.has_intrinsic_max())
+                        .for_each(|track| track.growth_limit += extra / intrinsic_max as f32);
+                }
+            }
+        }
+
+        tracks[span.start..span.end].iter_mut().for_each(|track| {
+            track.growth_limit = track.growth_limit.max(track.base_size);
+        });
+    }
+
+    // Items spanning flexible tracks increase the base sizes of those tracks with intrinsic
+    // minimums to fit their minimum contribution, in proportion to their flex factors.
+    for item in sorted_items.iter() {
+        let span = item.span(axis);
+        let span_gaps = gaps(span, tracks);
+        let spanned = &mut tracks[span.start..span.end];
+
+        let flex_total: f32 = spanned
+            .iter()
+            .filter(|track| track.is_flexible() && track.has_intrinsic_min())
+            .map(|track| track.flex_factor())
+            .sum();
+
+        if flex_total > 0.0 {
+            let extra = contribution(item, minimum_constraint)
+                - span_gaps
+                - spanned.iter().map(|track| track.base_size).sum::<f32>();
+
+            if extra > 0.0 {
+                spanned
+                    .iter_mut()
+                    .filter(|track| track.is_flexible() && track.has_intrinsic_min())
+                    .for_each(|track| track.base_size += extra * track.flex_factor() / flex_total);
+            }
+        }
+    }
+
+    // Tracks without any items, and flexible tracks, have no growth limit beyond their base size.
+    tracks.iter_mut().for_each(|track| {
+        if track.growth_limit.is_infinite() || track.is_flexible() {
+            track.growth_limit = track.base_size;
+        }
+    });
+
+    // 11.6. Maximize Tracks. Free space is distributed equally to the base sizes of the tracks up to
+    // their growth limits. When sizing under a max-content constraint the free space is infinite.
+
+    match space {
+        Defined(space) => {
+            let mut free_space = space - tracks_size(tracks, gap);
+
+            while free_space > 0.001 {
+                let growable = tracks.iter().filter(|track| track.base_size < track.growth_limit).count();
+                if growable == 0 {
+                    break;
+                }
+
+                let share = free_space / growable as f32;
+                tracks.iter_mut().filter(|track| track.base_size < track.growth_limit).for_each(|track| {
+                    let growth = share.min(track.growth_limit - track.base_size);
+                    track.base_size += growth;
+                    free_space -= growth;
+                });
+            }
+        }
+        Undefined => {
+            if !is_min_content {
+                tracks.iter_mut().for_each(|track| track.base_size = track.growth_limit);
+            }
+        }
+    }
+
+    // 11.7. Expand Flexible Tracks. With a definite size the flexible tracks share the space left
+    // by the other tracks. Otherwise each flexible track and each item spanning flexible tracks
+    // demands a size of a flex fraction, and the largest of those is used. Under a min-content
+    // constraint flexible tracks stay at their base size.
+
+    if tracks.iter().any(|track| track.is_flexible()) && !is_min_content {
+        let flex_fraction = match space {
+            Defined(space) => find_fr_size(
+                tracks,
+                space - tracks_size(tracks, gap) + tracks.iter().map(|track| track.base_size).sum::<f32>(),
+            ),
+            Undefined => {
+                let track_fraction = tracks
+                    .iter()
+                    .filter(|track| track.is_flexible())
+                    .map(|track| {
+                        if track.flex_factor() > 1.0 {
+                            track.base_size / track.flex_factor()
+                        } else {
+                            track.base_size
+                        }
+                    })
+                    .fold(0.0, f32::max);
+
+                items
+                    .iter()
+                    .filter(|item| {
+                        let span = item.span(axis);
+                        tracks[span.start..span.end].iter().any(|track| track.is_flexible())
+                    })
+                    .map(|item| {
+                        let span = item.span(axis);
+                        let space = contribution(item, AvailableSpace::MaxContent) - gaps(span, tracks);
+                        find_fr_size(&tracks[span.start..span.end], space)
+                    })
+                    .fold(track_fraction, f32::max)
+            }
+        };
+
+        tracks.iter_mut().filter(|track| track.is_flexible()).for_each(|track| {
+            track.base_size = track.base_size.max(flex_fraction * track.flex_factor());
+        });
+    }
+
+    // 11.8. Stretch auto Tracks. Any remaining free space is distributed equally to the tracks with
+    // an auto maximum sizing function when the content distribution is normal or stretch.
+
+    if let Defined(space) = space {
+        let free_space = space - tracks_size(tracks, gap);
+        let auto_tracks =
+            tracks.iter().filter(|track| !track.collapsed && track.max == MaxTrackSizingFunction::Auto).count();
+
+        if stretch && free_space > 0.0 && auto_tracks > 0 {
+            tracks
+                .iter_mut()
+                .filter(|track| !track.collapsed && track.max == MaxTrackSizingFunction::Auto)
+                .for_each(|track| track.base_size += free_space / auto_tracks as f32);
+        }
+    }
+}
+
+// 11.7.1. Find the Size of an fr. The leftover space is shared between the flexible tracks, except
+// that tracks whose base size is larger than their share are treated as inflexible.
+fn find_fr_size(tracks: &[GridTrackSize], space: f32) -> f32 {
+    let mut inflexible: Vec<bool> = tracks.iter().map(|track| !track.is_flexible()).collect();
+
+    loop {
+        let leftover = space
+            - tracks
+                .iter()
+                .zip(inflexible.iter())
+                .filter(|(_, inflexible)| **inflexible)
+                .map(|(track, _)| track.base_size)
+                .sum::<f32>();
+        let flex_total = tracks
+            .iter()
+            .zip(inflexible.iter())
+            .filter(|(_, inflexible)| !**inflexible)
+            .map(|(track, _)| track.flex_factor())
+            .sum::<f32>()
+            .max(1.0);
+
+        let fraction = leftover.max(0.0) / flex_total;
+
+        let mut changed = false;
+        tracks.iter().zip(inflexible.iter_mut()).filter(|(_, inflexible)| !**inflexible).for_each(
+            |(track, inflexible)| {
+                if fraction * track.flex_factor() < track.base_size {
+                    *inflexible = true;
+                    changed = true;
+                }
+            },
+        );
+
+        if !changed {
+            return fraction;
+        }
+    }
+}
+
+// 10.5. Aligning the Grid. Positions the tracks within the container, distributing any free space
+// per the content distribution. Safe alignment and distributions without room fall back to
+// start alignment, or center alignment for space around and space evenly.
+fn align_tracks(
+    tracks: &mut [GridTrackSize],
+    inner_size: f32,
+    gap: f32,
+    alignment: AlignContent,
+    safety: AlignmentSafety,
+    start: f32,
+) {
+    let count = tracks.iter().filter(|track| !track.collapsed).count();
+    let free_space = inner_size - tracks_size(tracks, gap);
+
+    let alignment = match alignment {
+        _ if safety == AlignmentSafety::Safe && free_space < 0.0 => AlignContent::Start,
+        AlignContent::SpaceBetween if free_space < 0.0 || count < 2 => AlignContent::Start,
+        AlignContent::SpaceAround | AlignContent::SpaceEvenly if free_space < 0.0 => AlignContent::Center,
+        alignment => alignment,
+    };
+
+    let (offset, distribution) = match alignment {
+        AlignContent::FlexEnd | AlignContent::End => (free_space, 0.0),
+        AlignContent::Center => (free_space / 2.0, 0.0),
+        AlignContent::SpaceBetween => (0.0, free_space / (count - 1) as f32),
+        AlignContent::SpaceAround => (free_space / count as f32 / 2.0, free_space / count as f32),
+        AlignContent::SpaceEvenly => (free_space / (count + 1) as f32, free_space / (count + 1) as f32),
+        _ => (0.0, 0.0),
+    };
+
+    let mut position = start + offset;
+    tracks.iter_mut().for_each(|track| {
+        track.offset = position;
+        if !track.collapsed {
+            position += track.base_size + gap + distribution;
+        }
+    });
+}
@@ -0,0 +1,255 @@
+use crate::layout;
+
+use crate::style;
+use crate::style::{Dimension, Direction, MasonryAutoFlow, TrackSizingFunction, Visibility};
+
+use crate::number::*;
+
+use crate::geometry::{Line, Point, Rect, Size};
+
+use super::{
+    align_tracks, child_overflow, collapse_empty_tracks, column_contribution, compute_internal, contents_children,
+    expand_tracks, finish_layout, implicit_tracks, is_in_flow, item_width, justify_tracks, padding_border,
+    resolve_placement, size_tracks, stretches_tracks_justify, track_sizing_space, tracks_size, ComputeResult, GridItem,
+    COLUMNS,
+};
+
+// The top of an item placed in the given columns, which is below the items placed in any of them.
+fn column_top(column_heights: &[f32], columns: Line<usize>) -> f32 {
+    column_heights[columns.start..columns.end].iter().cloned().fold(0.0, f32::max)
+}
+
+// CSS Grid Layout Level 3, Masonry Layout. A masonry container is a grid with columns but without
+// rows. Its items are placed one after another in the columns, each directly below the items placed
+// earlier in the columns it spans, rather than in rows spanning the whole container.
+pub(in crate::algo) fn compute(
+    node: &style::Node,
+    node_size: Size<Number>,
+    parent_size: Size<AvailableSpace>,
+    direction: Direction,
+    margin: Rect<f32>,
+    padding: Rect<f32>,
+    border: Rect<f32>,
+) -> ComputeResult {
+    let padding_border = padding_border(padding, border);
+
+    let node_inner_size = Size {
+        width: node_size.width - padding_border.horizontal(),
+        height: node_size.height - padding_border.vertical(),
+    };
+
+    let available_width =
+        node_size.width.or_else(parent_size.width - margin.horizontal()) - padding_border.horizontal();
+
+    // The width of the gap is the gap between columns and the height the gap between the items
+    // placed in the same column.
+    let gap = Size {
+        width: node.gap.width.resolve(node_inner_size.width).or_else(0.0),
+        height: node.gap.height.resolve(node_inner_size.height).or_else(0.0),
+    };
+
+    // The columns are those of the grid template, repeated to fill the container when the number
+    // of repetitions is automatic. Without a template there is a single column.
+    let mut explicit_columns = expand_tracks(&node.grid_template_columns, node_inner_size.width, gap.width);
+    if explicit_columns.is_empty() {
+        explicit_columns.push((TrackSizingFunction::auto(), false));
+    }
+
+    let column_count = explicit_columns.len();
+    let mut columns = implicit_tracks(&explicit_columns, &node.grid_auto_columns, 0, column_count);
+
+    // Items are placed in order-modified document order. An item placed on a column line keeps its
+    // column, clamped to the columns of the container, while other items are placed automatically.
+    let mut items: Vec<(GridItem, Option<usize>)> = contents_children(node)
+        .into_iter()
+        .enumerate()
+        .filter(|(_, child)| is_in_flow(child))
+        .map(|(order, child)| {
+            let (start, span) = resolve_placement(child.grid_column, column_count);
+            let span = (span as usize).min(column_count);
+            let start = start.map(|start| (start.max(0) as usize).min(column_count - span));
+
+            let item = GridItem {
+                node: child,
+                order: order as u32,
+                row: Line { start: 0, end: 1 },
+                column: Line { start: start.unwrap_or(0), end: start.unwrap_or(0) + span },
+                margin: child.margin.map(|n| n.resolve(node_inner_size.width).or_else(0.0)),
+            };
+
+            (item, start)
+        })
+        .collect();
+
+    items.sort_by_key(|(item, _)| item.node.order);
+
+    // 2.4. Masonry Track Sizing. As the columns of automatically placed items are not known before
+    // the columns are sized, those items contribute to every column they could be placed in.
+    let sizing_items: Vec<GridItem> = items
+        .iter()
+        .flat_map(|(item, start)| {
+            let span = item.column.end - item.column.start;
+            let starts = match start {
+                Some(start) => *start..*start + 1,
+                None => 0..column_count - span + 1,
+            };
+
+            starts.map(move |start| GridItem {
+                node: item.node,
+                order: item.order,
+                row: item.row,
+                column: Line { start, end: start + span },
+                margin: item.margin,
+            })
+        })
+        .collect();
+
+    collapse_empty_tracks(&mut columns, &sizing_items, COLUMNS);
+
+    let column_contribution =
+        |item: &GridItem, constraint: AvailableSpace| column_contribution(item, constraint, direction);
+
+    let column_space =
+        track_sizing_space(node_inner_size.width, available_width, node.max_size.width, node.min_size.width);
+    size_tracks(
+        &mut columns,
+        &sizing_items,
+        COLUMNS,
+        node_inner_size.width,
+        column_space,
+        gap.width,
+        stretches_tracks_justify(node.justify_content),
+        &column_contribution,
+    );
+
+    let container_width = node_size.width.or_else(tracks_size(&columns, gap.width) + padding_border.horizontal());
+
+    align_tracks(
+        &mut columns,
+        container_width - padding_border.horizontal(),
+        gap.width,
+        justify_tracks(node.justify_content, direction),
+        node.justify_content_safety,
+        padding_border.start,
+    );
+
+    // 2.5. Masonry Placement. Each item is placed below the items placed earlier in the columns it
+    // spans. Packed items go in the columns where they end up highest, the earliest of them when
+    // several are as high, and other items in the columns after the previous item.
+    let mut column_heights: Vec<f32> = vec![0.0; column_count];
+    let mut next_column = 0;
+
+    let mut content_size: Size<f32> = Size { width: 0.0, height: 0.0 };
+    let mut baselines: Option<(f32, f32)> = None;
+
+    let children: Vec<layout::Node> = items
+        .iter_mut()
+        .map(|(item, start)| {
+            let span = item.column.end - item.column.start;
+            let top_at = |start: usize| column_top(&column_heights, Line { start, end: start + span });
+
+            let start = match (start, node.masonry_auto_flow) {
+                (Some(start), _) => *start,
+                (None, MasonryAutoFlow::Pack) => (0..column_count - span + 1)
+                    .min_by(|a, b| top_at(*a).partial_cmp(&top_at(*b)).unwrap())
+                    .unwrap_or(0),
+                (None, MasonryAutoFlow::Next) if next_column + span <= column_count => next_column,
+                (None, MasonryAutoFlow::Next) => 0,
+            };
+
+            item.column = Line { start, end: start + span };
+            next_column = item.column.end % column_count;
+
+            let area = Line {
+                start: columns[item.column.start].offset,
+                end: columns[item.column.end - 1].offset + columns[item.column.end - 1].base_size,
+            };
+            let area_width = area.end - area.start;
+            let top = column_top(&column_heights, item.column);
+
+            let size = Size {
+                width: item_width(item, area_width),
+                height: item
+                    .node
+                    .size
+                    .height
+                    .resolve(node_inner_size.height)
+                    .maybe_max(item.node.min_size.height.resolve(node_inner_size.height))
+                    .maybe_min(item.node.max_size.height.resolve(node_inner_size.height)),
+            };
+
+            let result = compute_internal(
+                item.node,
+                size,
+                Size { width: AvailableSpace::Definite(area_width), height: AvailableSpace::MaxContent },
+                area_width.to_number(),
+                direction,
+            );
+
+            let bottom = top + result.size.height + item.margin.vertical();
+            column_heights[start..start + span].iter_mut().for_each(|height| *height = bottom + gap.height);
+
+            let free_width = area_width - result.size.width - item.margin.horizontal();
+            let offset_x = match (item.node.margin.start, item.node.margin.end) {
+                (Dimension::Auto, Dimension::Auto) => free_width / 2.0,
+                (Dimension::Auto, _) => free_width,
+                _ => 0.0,
+            };
+
+            let position = item.node.position.map(|n| n.resolve(area_width.to_number()));
+
+            let location = Point {
+                x: area.start
+                    + item.margin.start
+                    + offset_x
+                    + (position.start.or_else(0.0) - position.end.or_else(0.0)),
+                y: padding_border.top
+                    + top
+                    + item.margin.top
+                    + (position.top.or_else(0.0) - position.bottom.or_else(0.0)),
+            };
+
+            let overflow_size = child_overflow(item.node, result.size, result.content_size, direction, direction);
+
+            content_size.width = content_size
+                .width
+                .max(location.x + (result.size.width + item.margin.end).max(overflow_size.width) + padding_border.end);
+            content_size.height = content_size.height.max(
+                location.y
+                    + (result.size.height + item.margin.bottom).max(overflow_size.height)
+                    + padding_border.bottom,
+            );
+
+            // The baselines of the container are taken from the first and last items placed.
+            baselines = Some(match baselines {
+                Some((baseline, _)) => (baseline, location.y + result.last_baseline),
+                None => (location.y + result.baseline, location.y + result.last_baseline),
+            });
+
+            layout::Node {
+                order: item.order,
+                size: result.size,
+                content_size: result.content_size,
+                location,
+                baseline: result.baseline,
+                last_baseline: result.last_baseline,
+                visible: item.node.visibility == Visibility::Visible,
+                children: Box::new(result.children),
+            }
+        })
+        .collect();
+
+    // The gap below the last item of each column is not part of the content.
+    let content_height = column_heights.iter().map(|height| height - gap.height).fold(0.0, f32::max);
+
+    let container_size =
+        Size { width: container_width, height: node_size.height.or_else(content_height + padding_border.vertical()) };
+
+    let (baseline, last_baseline) = baselines.unwrap_or((container_size.height, container_size.height));
+
+    let static_position = Point { x: padding_border.start, y: padding_border.top };
+    let (children, content_size) =
+        finish_layout(node, children, container_size, border, &|_, _| static_position, direction, content_size);
+
+    ComputeResult { size: container_size, content_size, baseline, last_baseline, children }
+}
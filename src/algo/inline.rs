@@ -0,0 +1,587 @@
+use std::ops::Range;
+
+use crate::layout;
+
+use crate::style;
+use crate::style::{Direction, Display, Float, PositionType, TextAlign, Visibility};
+
+use crate::number::Number::*;
+use crate::number::*;
+
+use crate::geometry::{Point, Rect, Size};
+
+use super::{
+    absolute_layout, compute_internal, contents_children, contents_layout, hidden_children, mirror_children,
+    ComputeResult,
+};
+
+pub(super) fn is_text(node: &style::Node) -> bool {
+    node.text.is_some() && node.children.is_empty()
+}
+
+// Children which take part in the inline formatting context of their block container rather than
+// being laid out as blocks.
+pub(super) fn is_inline_level(node: &style::Node) -> bool {
+    (is_text(node)
+        || node.display == Display::Inline
+        || node.display == Display::InlineBlock
+        || node.display == Display::InlineFlex)
+        && node.position_type != PositionType::Absolute
+        && node.float == Float::None
+}
+
+enum InlineItemKind {
+    // The start and end edges of an inline box, spaced by its margin, border and padding.
+    Start,
+    End,
+    // A segment of a run of text, with whether it is the last segment of the run.
+    Text(bool),
+    // An atomic inline like an inline-block, laid out as a whole along with its margins.
+    Atomic(Option<ComputeResult>, Rect<f32>),
+}
+
+struct InlineItem {
+    // The indices of the child the item belongs to and those of its inline ancestors, starting at
+    // the child of the block container.
+    path: Vec<usize>,
+    kind: InlineItemKind,
+    break_before: bool,
+
+    width: f32,
+    hang: f32,
+    height: f32,
+    baseline: f32,
+
+    line: usize,
+    location: Point<f32>,
+}
+
+impl InlineItem {
+    fn new(path: &[usize], kind: InlineItemKind, width: f32, height: f32, baseline: f32) -> InlineItem {
+        InlineItem {
+            path: path.to_vec(),
+            kind,
+            break_before: false,
+            width,
+            hang: 0.0,
+            height,
+            baseline,
+            line: 0,
+            location: Point { x: 0.0, y: 0.0 },
+        }
+    }
+
+    fn is_content(&self) -> bool {
+        matches!(self.kind, InlineItemKind::Text(_) | InlineItemKind::Atomic(_, _))
+    }
+}
+
+struct LineBox {
+    top: f32,
+    height: f32,
+    baseline: f32,
+}
+
+pub(super) struct InlineResult {
+    pub(super) height: f32,
+    pub(super) baselines: Option<(f32, f32)>,
+    pub(super) children: Vec<layout::Node>,
+}
+
+fn text_items(node: &style::Node, path: &[usize], items: &mut Vec<InlineItem>) {
+    let run = (node.text.as_ref().unwrap())();
+    let count = run.segments.len();
+
+    items.extend(run.segments.iter().enumerate().map(|(index, segment)| InlineItem {
+        hang: segment.trailing_whitespace,
+        ..InlineItem::new(
+            path,
+            InlineItemKind::Text(index + 1 == count),
+            segment.advance,
+            run.line_height,
+            run.baseline,
+        )
+    }));
+}
+
+fn collect_items(
+    children: &[&style::Node],
+    range: Range<usize>,
+    path: &[usize],
+    inner_width: Number,
+    available_width: AvailableSpace,
+    direction: Direction,
+    items: &mut Vec<InlineItem>,
+) {
+    for index in range {
+        let child = children[index];
+        if child.display == Display::None || child.position_type == PositionType::Absolute {
+            continue;
+        }
+
+        let path: Vec<usize> = path.iter().cloned().chain(Some(index)).collect();
+
+        if is_text(child) {
+            text_items(child, &path, items);
+        } else if child.display == Display::Inline && child.measure.is_none() {
+            // Only the inline edges of the margin, border and padding of inline boxes affect layout.
+            let edge = |margin: style::Dimension, border: style::Dimension, padding: style::Dimension| {
+                margin.resolve(inner_width).or_else(0.0)
+                    + border.resolve(inner_width).or_else(0.0)
+                    + padding.resolve(inner_width).or_else(0.0)
+            };
+
+            let start = edge(child.margin.start, child.border.start, child.padding.start);
+            let end = edge(child.margin.end, child.border.end, child.padding.end);
+
+            items.push(InlineItem::new(&path, InlineItemKind::Start, start, 0.0, 0.0));
+            let child_nodes = contents_children(child);
+            collect_items(&child_nodes, 0..child_nodes.len(), &path, inner_width, available_width, direction, items);
+            items.push(InlineItem::new(&path, InlineItemKind::End, end, 0.0, 0.0));
+        } else {
+            let margin = child.margin.map(|n| n.resolve(inner_width).or_else(0.0));
+
+            let result = compute_internal(
+                child,
+                Size {
+                    width: child
+                        .size
+                        .width
+                        .resolve(inner_width)
+                        .maybe_max(child.min_size.width.resolve(inner_width))
+                        .maybe_min(child.max_size.width.resolve(inner_width)),
+                    height: child
+                        .size
+                        .height
+                        .resolve(Undefined)
+                        .maybe_max(child.min_size.height.resolve(Undefined))
+                        .maybe_min(child.max_size.height.resolve(Undefined)),
+                },
+                Size { width: available_width - margin.horizontal(), height: AvailableSpace::MaxContent },
+                inner_width,
+                direction,
+            );
+
+            // The baseline of an inline-block is the baseline of its last line, or the bottom edge of
+            // its margin box when it is a scroll container.
+            let outer_size =
+                Size { width: result.size.width + margin.horizontal(), height: result.size.height + margin.vertical() };
+            let baseline =
+                if child.is_scroll_container() { outer_size.height } else { margin.top + result.last_baseline };
+
+            items.push(InlineItem::new(
+                &path,
+                InlineItemKind::Atomic(Some(result), margin),
+                outer_size.width,
+                outer_size.height,
+                baseline,
+            ));
+        }
+    }
+}
+
+// Lines may break before atomic inlines, the start of inline boxes, and segments of text. They don't
+// break right after the start of an inline box or before its end, nor between runs of text unless
+// the first one ends in whitespace.
+fn mark_break_opportunities(items: &mut [InlineItem]) {
+    let mut previous_content: Option<usize> = None;
+
+    for index in 0..items.len() {
+        let next_content = items[index..].iter().find(|item| item.is_content()).map(|item| &item.kind);
+
+        let joins_text = match (previous_content.map(|index| &items[index]), next_content) {
+            (Some(previous), Some(InlineItemKind::Text(_))) => match previous.kind {
+                InlineItemKind::Text(is_last) => is_last && previous.hang == 0.0,
+                _ => false,
+            },
+            _ => false,
+        };
+
+        items[index].break_before = index > 0
+            && !joins_text
+            && !matches!(
+                (&items[index - 1].kind, &items[index].kind),
+                (InlineItemKind::Start, _) | (_, InlineItemKind::End)
+            );
+
+        if items[index].is_content() {
+            previous_content = Some(index);
+        }
+    }
+}
+
+// The content between two break opportunities, which is kept together on a line. Whitespace at the
+// end hangs past the end of a line.
+struct Chunk {
+    items: Range<usize>,
+    width: f32,
+    hang: f32,
+}
+
+fn chunks(items: &[InlineItem]) -> Vec<Chunk> {
+    let mut chunks: Vec<Chunk> = vec![];
+
+    for (index, item) in items.iter().enumerate() {
+        if index == 0 || item.break_before {
+            chunks.push(Chunk { items: index..index, width: 0.0, hang: 0.0 });
+        }
+
+        let chunk = chunks.last_mut().unwrap();
+        chunk.items.end = index + 1;
+        chunk.width += item.width;
+
+        match item.kind {
+            InlineItemKind::End => (),
+            _ => chunk.hang = item.hang,
+        }
+    }
+
+    chunks
+}
+
+// Shrinks the content to fit within the available width, between the width of its widest piece
+// that can't be broken and the width of all of it on a single line.
+fn fit_content_width(items: &[InlineItem], available_width: AvailableSpace) -> f32 {
+    let chunks = chunks(items);
+    let min_content = chunks.iter().map(|chunk| chunk.width - chunk.hang).fold(0.0, f32::max);
+    let max_content =
+        chunks.iter().map(|chunk| chunk.width).sum::<f32>() - chunks.last().map_or(0.0, |chunk| chunk.hang);
+
+    match available_width {
+        AvailableSpace::Definite(width) => max_content.min(width.max(min_content)),
+        AvailableSpace::MinContent => min_content,
+        AvailableSpace::MaxContent => max_content,
+    }
+}
+
+// Breaks the items into lines starting at the given position. For each line, the space for the line
+// is found by calling fit with the position of the line and the width of its first piece, returning
+// the position the line fits at along with the left and right edges of the space. Items are aligned
+// vertically by their baselines and horizontally per the text alignment.
+fn break_lines(
+    items: &mut [InlineItem],
+    top: f32,
+    fit: &dyn Fn(f32, f32) -> (f32, f32, f32),
+    text_align: TextAlign,
+    direction: Direction,
+) -> Vec<LineBox> {
+    let chunks = chunks(items);
+    let mut lines: Vec<LineBox> = vec![];
+    let mut top = top;
+    let mut first = 0;
+
+    while first < chunks.len() {
+        let (line_top, left, right) = fit(top, chunks[first].width - chunks[first].hang);
+
+        let mut end = first;
+        let mut width = 0.0;
+        while end < chunks.len() && (end == first || width + chunks[end].width - chunks[end].hang <= right - left) {
+            width += chunks[end].width;
+            end += 1;
+        }
+
+        let line_items = chunks[first].items.start..chunks[end - 1].items.end;
+        let line_width = width - chunks[end - 1].hang;
+
+        let ascent = items[line_items.clone()]
+            .iter()
+            .filter(|item| item.is_content())
+            .map(|item| item.baseline)
+            .fold(0.0, f32::max);
+        let descent = items[line_items.clone()]
+            .iter()
+            .filter(|item| item.is_content())
+            .map(|item| item.height - item.baseline)
+            .fold(0.0, f32::max);
+
+        let free_space = (right - left) - line_width;
+        let is_last = end == chunks.len();
+
+        let offset = match text_align {
+            TextAlign::Start | TextAlign::Justify => 0.0,
+            TextAlign::End => free_space,
+            TextAlign::Center => free_space / 2.0,
+            TextAlign::Left if direction == Direction::RTL => free_space,
+            TextAlign::Right if direction != Direction::RTL => free_space,
+            TextAlign::Left | TextAlign::Right => 0.0,
+        };
+
+        let spacing = if text_align == TextAlign::Justify && !is_last && end - first > 1 {
+            free_space.max(0.0) / (end - first - 1) as f32
+        } else {
+            0.0
+        };
+
+        let mut x = left + offset;
+        for chunk in &chunks[first..end] {
+            for item in &mut items[chunk.items.clone()] {
+                item.line = lines.len();
+                item.location = Point { x, y: line_top + ascent - item.baseline };
+                x += item.width;
+            }
+            x += spacing;
+        }
+
+        lines.push(LineBox { top: line_top, height: ascent + descent, baseline: ascent });
+        top = line_top + ascent + descent;
+        first = end;
+    }
+
+    lines
+}
+
+// The first and last baselines of the lines the items are placed on.
+fn line_baselines(items: &[InlineItem], lines: &[LineBox]) -> Option<(f32, f32)> {
+    let first = items.iter().map(|item| item.line).min()?;
+    let last = items.iter().map(|item| item.line).max()?;
+    Some((lines[first].top + lines[first].baseline, lines[last].top + lines[last].baseline))
+}
+
+// Builds the layout of a child of an inline formatting context from its items, located relative to
+// the block container. Inline boxes and runs of text are sized to the bounds of their content, and
+// the segments of a run of text are laid out as its children.
+fn item_layout(
+    node: &style::Node,
+    order: usize,
+    depth: usize,
+    items: &mut [InlineItem],
+    lines: &[LineBox],
+    direction: Direction,
+) -> layout::Node {
+    if let InlineItemKind::Atomic(ref mut result, margin) = items[0].kind {
+        let result = result.take().unwrap();
+        return layout::Node {
+            order: order as u32,
+            size: result.size,
+            content_size: result.content_size,
+            location: Point { x: items[0].location.x + margin.start, y: items[0].location.y + margin.top },
+            baseline: result.baseline,
+            last_baseline: result.last_baseline,
+            visible: node.visibility == Visibility::Visible,
+            children: Box::new(result.children),
+        };
+    }
+
+    let left = items.iter().map(|item| item.location.x).fold(f32::INFINITY, f32::min);
+    let right = items.iter().map(|item| item.location.x + item.width).fold(f32::NEG_INFINITY, f32::max);
+    let edges = items.iter().fold((f32::INFINITY, f32::NEG_INFINITY), |(top, bottom), item| {
+        if item.is_content() {
+            (top.min(item.location.y), bottom.max(item.location.y + item.height))
+        } else {
+            (top, bottom)
+        }
+    });
+
+    // Inline boxes without content are placed at the baseline of their line.
+    let (top, bottom) = if edges.0.is_finite() { edges } else { (items[0].location.y, items[0].location.y) };
+
+    let location = Point { x: left, y: top };
+    let size = Size { width: right - left, height: bottom - top };
+
+    let child_nodes = contents_children(node);
+    let mut children: Vec<layout::Node> = if is_text(node) {
+        items
+            .iter()
+            .enumerate()
+            .map(|(index, item)| layout::Node {
+                order: index as u32,
+                size: Size { width: item.width, height: item.height },
+                content_size: Size { width: item.width, height: item.height },
+                location: Point { x: item.location.x, y: item.location.y },
+                baseline: item.baseline,
+                last_baseline: item.baseline,
+                visible: node.visibility == Visibility::Visible,
+                children: Box::new(vec![]),
+            })
+            .collect()
+    } else {
+        let len = items.len();
+        let mut children = child_layouts(&child_nodes, &mut items[1..len - 1], depth + 1, lines, direction);
+
+        children.append(&mut hidden_children(child_nodes.iter().copied()));
+        children
+    };
+
+    children.iter_mut().for_each(|child| {
+        child.location.x -= location.x;
+        child.location.y -= location.y;
+    });
+
+    // Absolutely positioned descendants of inline boxes are positioned relative to its bounds.
+    let mut content_size = size;
+    if !is_text(node) {
+        let mut absolute_children = absolute_layout(
+            child_nodes.iter().copied(),
+            size,
+            Rect { start: 0.0, end: 0.0, top: 0.0, bottom: 0.0 },
+            &|_, _| Point { x: 0.0, y: 0.0 },
+            direction,
+            &mut content_size,
+        );
+        children.append(&mut absolute_children);
+    }
+
+    // The top level children are mirrored by the block container, and nested children are mirrored
+    // within their inline box.
+    if direction == Direction::RTL {
+        mirror_children(&mut children, size.width);
+    }
+
+    children.sort_by_key(|child| child.order);
+    if !is_text(node) {
+        children = contents_layout(node, &mut children.into_iter());
+    }
+
+    let (baseline, last_baseline) = line_baselines(items, lines).unwrap_or((bottom, bottom));
+
+    layout::Node {
+        order: order as u32,
+        size,
+        content_size,
+        location,
+        baseline: baseline - top,
+        last_baseline: last_baseline - top,
+        visible: node.visibility == Visibility::Visible,
+        children: Box::new(children),
+    }
+}
+
+// Builds the layouts of the children the items belong to, which are consecutive. Children without
+// any items, like empty runs of text, are laid out without a size at the start of their parent.
+fn child_layouts(
+    children: &[&style::Node],
+    items: &mut [InlineItem],
+    depth: usize,
+    lines: &[LineBox],
+    direction: Direction,
+) -> Vec<layout::Node> {
+    let mut layouts: Vec<layout::Node> = vec![];
+    let mut items = items;
+
+    while !items.is_empty() {
+        let index = items[0].path[depth];
+        let count = items.iter().take_while(|item| item.path[depth] == index).count();
+        let (child_items, rest) = items.split_at_mut(count);
+
+        layouts.push(item_layout(children[index], index, depth, child_items, lines, direction));
+        items = rest;
+    }
+
+    layouts
+}
+
+// Lays out the given range of the children of a block container in lines, starting at the given
+// position. The children are those returned by contents_children for the container. Returns the
+// height of the lines along with the baselines of the first and last lines.
+pub(super) fn layout_inline(
+    node: &style::Node,
+    children: &[&style::Node],
+    range: Range<usize>,
+    inner_width: f32,
+    top: f32,
+    fit: &dyn Fn(f32, f32) -> (f32, f32, f32),
+    direction: Direction,
+) -> InlineResult {
+    let mut items: Vec<InlineItem> = vec![];
+    collect_items(
+        children,
+        range.clone(),
+        &[],
+        Defined(inner_width),
+        AvailableSpace::Definite(inner_width),
+        direction,
+        &mut items,
+    );
+    mark_break_opportunities(&mut items);
+
+    let lines = break_lines(&mut items, top, fit, node.text_align, direction);
+    let baselines = line_baselines(&items, &lines);
+    let height = lines.last().map_or(0.0, |line| line.top + line.height - top);
+
+    let mut layouts = child_layouts(children, &mut items, 0, &lines, direction);
+
+    let empty_children: Vec<usize> = range
+        .filter(|index| {
+            let child = children[*index];
+            is_inline_level(child)
+                && child.display != Display::None
+                && layouts.iter().all(|layout| layout.order != *index as u32)
+        })
+        .collect();
+
+    layouts.extend(empty_children.into_iter().map(|index| {
+        let (x, _, _) = fit(top, 0.0);
+        layout::Node {
+            order: index as u32,
+            size: Size { width: 0.0, height: 0.0 },
+            content_size: Size { width: 0.0, height: 0.0 },
+            location: Point { x, y: top },
+            baseline: 0.0,
+            last_baseline: 0.0,
+            visible: children[index].visibility == Visibility::Visible,
+            children: Box::new(vec![]),
+        }
+    }));
+
+    InlineResult { height, baselines, children: layouts }
+}
+
+// The width of the given range of the children of a block container when shrinking to fit.
+pub(super) fn intrinsic_width(
+    children: &[&style::Node],
+    range: Range<usize>,
+    available_width: AvailableSpace,
+    direction: Direction,
+) -> f32 {
+    let mut items: Vec<InlineItem> = vec![];
+    collect_items(children, range, &[], Undefined, available_width, direction, &mut items);
+    mark_break_opportunities(&mut items);
+    fit_content_width(&items, available_width)
+}
+
+// Lays out a run of text on its own, outside of an inline formatting context, like a block
+// container with only the text inside it.
+pub(super) fn compute_text(
+    node: &style::Node,
+    node_size: Size<Number>,
+    parent_size: Size<AvailableSpace>,
+    direction: Direction,
+) -> ComputeResult {
+    let mut items: Vec<InlineItem> = vec![];
+    text_items(node, &[], &mut items);
+    mark_break_opportunities(&mut items);
+
+    let width = node_size.width.or_else(fit_content_width(&items, parent_size.width));
+    let lines = break_lines(&mut items, 0.0, &|top, _| (top, 0.0, width), node.text_align, direction);
+
+    let size =
+        Size { width, height: node_size.height.or_else(lines.last().map_or(0.0, |line| line.top + line.height)) };
+
+    let mut content_size = size;
+    let mut children: Vec<layout::Node> = items
+        .iter()
+        .enumerate()
+        .map(|(index, item)| {
+            content_size.width = content_size.width.max(item.location.x + item.width);
+            content_size.height = content_size.height.max(item.location.y + item.height);
+
+            layout::Node {
+                order: index as u32,
+                size: Size { width: item.width, height: item.height },
+                content_size: Size { width: item.width, height: item.height },
+                location: item.location,
+                baseline: item.baseline,
+                last_baseline: item.baseline,
+                visible: node.visibility == Visibility::Visible,
+                children: Box::new(vec![]),
+            }
+        })
+        .collect();
+
+    if direction == Direction::RTL {
+        mirror_children(&mut children, size.width);
+    }
+
+    let (baseline, last_baseline) = line_baselines(&items, &lines).unwrap_or((size.height, size.height));
+    ComputeResult { size, content_size, baseline, last_baseline, children }
+}
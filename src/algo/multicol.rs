@@ -0,0 +1,255 @@
+use crate::layout;
+
+use crate::style;
+use crate::style::{ColumnFill, Direction};
+
+use crate::number::Number::*;
+use crate::number::*;
+
+use crate::geometry::{Point, Rect, Size};
+
+use super::block;
+use super::{
+    child_overflow, contents_children, finish_layout, flatten_contents_layout, is_in_flow, mirror_children,
+    padding_border, ComputeResult,
+};
+
+// CSS Multi-column Layout. The content of a multi-column container is laid out as a single column
+// of the column width, which is then broken into columns placed side by side. Columns only break
+// between the children of the container, so a child is never split between two columns.
+
+// 3.4. Pseudo-algorithm. The number of columns and their width follow from the column count and
+// width, whichever allows fewer columns, and the columns fill the width of the container.
+fn resolve_columns(node: &style::Node, inner_width: f32, gap: f32) -> (usize, f32) {
+    let column_count = node.column_count as usize;
+
+    let count = match node.column_width.resolve(Defined(inner_width)) {
+        Defined(width) if width.max(0.0) + gap > 0.0 => {
+            let fitting = (((inner_width + gap) / (width.max(0.0) + gap)).floor() as usize).max(1);
+            if column_count > 0 {
+                fitting.min(column_count)
+            } else {
+                fitting
+            }
+        }
+        _ => column_count.max(1),
+    };
+
+    let width = ((inner_width - gap * (count - 1) as f32) / count as f32).max(0.0);
+    (count, width)
+}
+
+// Breaks the content into columns of the given height, returning the index of the first child of
+// each column. Each column holds at least one child, and children which don't fit in a column move
+// to the next one. The first column starts at the top of the content box, later columns at the top
+// of their first child, so margins are truncated at column breaks.
+fn break_columns(extents: &[(f32, f32)], content_top: f32, height: f32) -> Vec<usize> {
+    let mut breaks: Vec<usize> = vec![0];
+    let mut column_top = content_top;
+
+    for (index, (top, bottom)) in extents.iter().enumerate() {
+        if index > *breaks.last().unwrap() && bottom - column_top > height {
+            breaks.push(index);
+            column_top = *top;
+        }
+    }
+
+    breaks
+}
+
+// 7.1. Column balancing. Balanced columns are as short as possible while the content fits within
+// the given number of columns, and at least as tall as the tallest child. Breaking the content only
+// changes at the heights between the top of a column and the bottom of a child, so the shortest of
+// those which fits is used.
+fn balance_columns(extents: &[(f32, f32)], content_top: f32, count: usize) -> f32 {
+    let tallest = extents.iter().map(|(top, bottom)| bottom - top).fold(0.0, f32::max);
+
+    let mut heights: Vec<f32> = extents
+        .iter()
+        .map(|(top, _)| *top)
+        .chain(std::iter::once(content_top))
+        .flat_map(|top| extents.iter().map(move |(_, bottom)| bottom - top))
+        .filter(|height| *height >= tallest)
+        .collect();
+
+    heights.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    heights.dedup();
+
+    let (mut low, mut high) = (0, heights.len());
+    while low < high {
+        let middle = (low + high) / 2;
+        if break_columns(extents, content_top, heights[middle]).len() <= count {
+            high = middle;
+        } else {
+            low = middle + 1;
+        }
+    }
+
+    heights.get(low).copied().unwrap_or(0.0)
+}
+
+pub(super) fn compute(
+    node: &style::Node,
+    node_size: Size<Number>,
+    parent_size: Size<AvailableSpace>,
+    direction: Direction,
+    margin: Rect<f32>,
+    padding: Rect<f32>,
+    border: Rect<f32>,
+) -> ComputeResult {
+    let padding_border = padding_border(padding, border);
+
+    // Without a definite width the container shrinks to fit its columns, each as wide as the column
+    // width or else the content of a single column, within the available space.
+    let width = match node_size.width {
+        Defined(width) => width,
+        Undefined => {
+            let gap = node.gap.width.resolve(Undefined).or_else(0.0);
+            let count = (node.column_count as usize).max(1);
+
+            let column_width = match node.column_width.resolve(Undefined) {
+                Defined(width) => width.max(0.0),
+                Undefined => {
+                    let content_size = match parent_size.width {
+                        AvailableSpace::MinContent => AvailableSpace::MinContent,
+                        _ => AvailableSpace::MaxContent,
+                    };
+
+                    block::compute(
+                        node,
+                        Size { width: Undefined, height: Undefined },
+                        Size { width: content_size, height: parent_size.height },
+                        direction,
+                        margin,
+                        padding,
+                        border,
+                    )
+                    .size
+                    .width
+                        - padding_border.horizontal()
+                }
+            };
+
+            let inner_width = column_width * count as f32 + gap * (count - 1) as f32;
+            let inner_width = match parent_size.width {
+                AvailableSpace::Definite(available) => {
+                    inner_width.min(available - margin.horizontal() - padding_border.horizontal()).max(0.0)
+                }
+                _ => inner_width,
+            };
+
+            inner_width + padding_border.horizontal()
+        }
+    };
+
+    let inner_width = width - padding_border.horizontal();
+    let inner_height = node_size.height - padding_border.vertical();
+
+    let gap = node.gap.width.resolve(Defined(inner_width)).or_else(0.0);
+    let (column_count, column_width) = resolve_columns(node, inner_width, gap);
+
+    let single_column_width = column_width + padding_border.horizontal();
+    let result = block::compute(
+        node,
+        Size { width: Defined(single_column_width), height: Undefined },
+        parent_size,
+        direction,
+        margin,
+        padding,
+        border,
+    );
+
+    // Hidden and absolutely positioned children are laid out again once the size of the container
+    // is known.
+    let child_nodes = contents_children(node);
+    let mut in_flow: Vec<layout::Node> = flatten_contents_layout(node, result.children)
+        .into_iter()
+        .filter(|child| is_in_flow(child_nodes[child.order as usize]))
+        .collect();
+
+    // The single column is mirrored back before it is broken into columns, which are mirrored again
+    // at the end.
+    if direction == Direction::RTL {
+        mirror_children(&mut in_flow, single_column_width);
+    }
+
+    let extents: Vec<(f32, f32)> =
+        in_flow.iter().map(|child| (child.location.y, child.location.y + child.size.height)).collect();
+
+    // 7.1. Column fill. Without a height the content is balanced between the columns, which it also
+    // is when balancing is asked for, though never taller than the container. Content which doesn't
+    // fit in the columns of a container with a height overflows into more columns.
+    let column_height = match (node.column_fill, inner_height) {
+        (ColumnFill::Auto, Defined(height)) => height.max(0.0),
+        (ColumnFill::Balance, Defined(height)) => {
+            balance_columns(&extents, padding_border.top, column_count).min(height.max(0.0))
+        }
+        (_, Undefined) => balance_columns(&extents, padding_border.top, column_count),
+    };
+
+    let breaks = if extents.is_empty() { vec![] } else { break_columns(&extents, padding_border.top, column_height) };
+    let column_tops: Vec<f32> = breaks
+        .iter()
+        .enumerate()
+        .map(|(column, index)| if column == 0 { padding_border.top } else { extents[*index].0 })
+        .collect();
+
+    for (index, child) in in_flow.iter_mut().enumerate() {
+        let column = breaks.iter().rposition(|first| *first <= index).unwrap_or(0);
+        child.location.x += column as f32 * (column_width + gap);
+        child.location.y -= column_tops[column] - padding_border.top;
+    }
+
+    let container_size = Size {
+        width,
+        height: node_size.height.or_else(
+            (column_height + padding_border.vertical())
+                .maybe_max(node.min_size.height.resolve(Undefined))
+                .maybe_min(node.max_size.height.resolve(Undefined)),
+        ),
+    };
+
+    // The baselines of the container are those of its first and last columns.
+    let (baseline, last_baseline) = match column_tops.last() {
+        Some(last_top) => (result.baseline, result.last_baseline - (last_top - padding_border.top)),
+        None => (container_size.height, container_size.height),
+    };
+
+    let mut content_size: Size<f32> = Size { width: 0.0, height: 0.0 };
+    for child in &in_flow {
+        let overflow_size =
+            child_overflow(child_nodes[child.order as usize], child.size, child.content_size, direction, direction);
+        content_size.width =
+            content_size.width.max(child.location.x + child.size.width.max(overflow_size.width) + padding_border.end);
+        content_size.height = content_size
+            .height
+            .max(child.location.y + child.size.height.max(overflow_size.height) + padding_border.bottom);
+    }
+
+    // Absolutely positioned children are placed at the start of the column their static position
+    // falls in, at the top of the following child.
+    let static_positions: Vec<Point<f32>> = (0..child_nodes.len())
+        .map(|order| match in_flow.iter().position(|child| child.order as usize > order) {
+            Some(index) => {
+                let column = breaks.iter().rposition(|first| *first <= index).unwrap_or(0);
+                Point { x: padding_border.start + column as f32 * (column_width + gap), y: in_flow[index].location.y }
+            }
+            None => Point {
+                x: padding_border.start + breaks.len().saturating_sub(1) as f32 * (column_width + gap),
+                y: in_flow.last().map(|child| child.location.y + child.size.height).unwrap_or(padding_border.top),
+            },
+        })
+        .collect();
+
+    let (children, content_size) = finish_layout(
+        node,
+        in_flow,
+        container_size,
+        border,
+        &|order, _| static_positions[order],
+        direction,
+        content_size,
+    );
+
+    ComputeResult { size: container_size, content_size, baseline, last_baseline, children }
+}
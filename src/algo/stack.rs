@@ -0,0 +1,224 @@
+use crate::layout;
+
+use crate::style;
+use crate::style::{AlignSelf, AlignmentSafety, Dimension, Direction, JustifySelf, Visibility};
+
+use crate::number::Number::*;
+use crate::number::*;
+
+use crate::geometry::{Point, Rect, Size};
+
+use super::{
+    child_overflow, compute_internal, contents_children, finish_layout, is_in_flow, padding_border, ComputeResult,
+};
+
+// Stack layout. The in-flow children of a stack are layered on top of each other in its content
+// box, later children above earlier ones, each aligned on its own within the content box. Without
+// a definite size the stack is as large as its largest child including margins.
+
+// Whether a child without a definite size fills the content box in the inline axis.
+fn stretches_inline(child: &style::Node) -> bool {
+    matches!(child.justify_self, JustifySelf::Auto | JustifySelf::Normal | JustifySelf::Stretch)
+        && child.margin.start != Dimension::Auto
+        && child.margin.end != Dimension::Auto
+}
+
+fn stretches_block(child: &style::Node, node: &style::Node) -> bool {
+    child.align_self(node) == AlignSelf::Stretch
+        && child.margin.top != Dimension::Auto
+        && child.margin.bottom != Dimension::Auto
+}
+
+// The offset of a child within the free space in the inline axis. Like the rest of the layout the
+// inline-start edge is to the left until right-to-left stacks are mirrored, so left and right
+// alignment are swapped for those.
+fn justify_offset(child: &style::Node, free_space: f32, direction: Direction) -> f32 {
+    match (child.margin.start, child.margin.end) {
+        (Dimension::Auto, Dimension::Auto) => free_space / 2.0,
+        (Dimension::Auto, _) => free_space,
+        (_, Dimension::Auto) => 0.0,
+        _ => match child.justify_self {
+            JustifySelf::End => free_space,
+            JustifySelf::Center => free_space / 2.0,
+            JustifySelf::Left if direction == Direction::RTL => free_space,
+            JustifySelf::Right if direction != Direction::RTL => free_space,
+            _ => 0.0,
+        },
+    }
+}
+
+fn align_offset(child: &style::Node, node: &style::Node, free_space: f32) -> f32 {
+    match (child.margin.top, child.margin.bottom) {
+        (Dimension::Auto, Dimension::Auto) => free_space / 2.0,
+        (Dimension::Auto, _) => free_space,
+        (_, Dimension::Auto) => 0.0,
+        _ if child.align_self_safety(node) == AlignmentSafety::Safe && free_space < 0.0 => 0.0,
+        _ => match child.align_self(node) {
+            AlignSelf::FlexEnd | AlignSelf::End | AlignSelf::LastBaseline => free_space,
+            AlignSelf::Center => free_space / 2.0,
+            _ => 0.0,
+        },
+    }
+}
+
+pub(super) fn compute(
+    node: &style::Node,
+    node_size: Size<Number>,
+    parent_size: Size<AvailableSpace>,
+    direction: Direction,
+    margin: Rect<f32>,
+    padding: Rect<f32>,
+    border: Rect<f32>,
+) -> ComputeResult {
+    let padding_border = padding_border(padding, border);
+
+    let node_inner_size = Size {
+        width: node_size.width - padding_border.horizontal(),
+        height: node_size.height - padding_border.vertical(),
+    };
+
+    let available_space = Size {
+        width: node_size.width.or_else(parent_size.width - margin.horizontal()) - padding_border.horizontal(),
+        height: node_size.height.or_else(parent_size.height - margin.vertical()) - padding_border.vertical(),
+    };
+
+    let child_nodes: Vec<(usize, &style::Node)> =
+        contents_children(node).into_iter().enumerate().filter(|(_, child)| is_in_flow(child)).collect();
+
+    let child_margin = |child: &style::Node| child.margin.map(|n| n.resolve(node_inner_size.width).or_else(0.0));
+
+    // The size of a child within the content box, stretched to fill it where it is definite and
+    // otherwise sized to its content.
+    let child_size = |child: &style::Node, inner_size: Size<Number>| {
+        let margin = child_margin(child);
+
+        let width = match child.size.width.resolve(inner_size.width) {
+            Defined(width) => Defined(width),
+            Undefined if stretches_inline(child) => inner_size.width - margin.horizontal(),
+            Undefined => Undefined,
+        };
+
+        let height = match child.size.height.resolve(inner_size.height) {
+            Defined(height) => Defined(height),
+            Undefined if stretches_block(child, node) => inner_size.height - margin.vertical(),
+            Undefined => Undefined,
+        };
+
+        Size {
+            width: width
+                .maybe_max(child.min_size.width.resolve(inner_size.width))
+                .maybe_min(child.max_size.width.resolve(inner_size.width)),
+            height: height
+                .maybe_max(child.min_size.height.resolve(inner_size.height))
+                .maybe_min(child.max_size.height.resolve(inner_size.height)),
+        }
+    };
+
+    // Without a definite size the content box is as large as the largest of the children, which
+    // are sized to their content within the available space.
+    let inner_size = if let (Defined(width), Defined(height)) = (node_inner_size.width, node_inner_size.height) {
+        Size { width, height }
+    } else {
+        let outer_size = child_nodes
+            .iter()
+            .map(|(_, child)| {
+                let margin = child_margin(child);
+                let result = compute_internal(
+                    child,
+                    child_size(child, node_inner_size),
+                    Size {
+                        width: available_space.width - margin.horizontal(),
+                        height: available_space.height - margin.vertical(),
+                    },
+                    node_inner_size.width,
+                    direction,
+                );
+
+                Size { width: result.size.width + margin.horizontal(), height: result.size.height + margin.vertical() }
+            })
+            .fold(Size { width: 0.0, height: 0.0 }, |size: Size<f32>, outer_size| Size {
+                width: size.width.max(outer_size.width),
+                height: size.height.max(outer_size.height),
+            });
+
+        Size {
+            width: node_inner_size.width.or_else(outer_size.width),
+            height: node_inner_size.height.or_else(outer_size.height),
+        }
+    };
+
+    let container_size = Size {
+        width: inner_size.width + padding_border.horizontal(),
+        height: inner_size.height + padding_border.vertical(),
+    };
+
+    let mut content_size: Size<f32> = Size { width: 0.0, height: 0.0 };
+    let mut baselines: Option<(f32, f32)> = None;
+
+    let children: Vec<layout::Node> = child_nodes
+        .iter()
+        .map(|(order, child)| {
+            let margin = child_margin(child);
+
+            let result = compute_internal(
+                child,
+                child_size(child, inner_size.map(Defined)),
+                inner_size.map(AvailableSpace::Definite),
+                Defined(inner_size.width),
+                direction,
+            );
+
+            let free_space = Size {
+                width: inner_size.width - result.size.width - margin.horizontal(),
+                height: inner_size.height - result.size.height - margin.vertical(),
+            };
+
+            let position = child.position.map(|n| n.resolve(Defined(inner_size.width)));
+
+            let location = Point {
+                x: padding_border.start
+                    + margin.start
+                    + justify_offset(child, free_space.width, direction)
+                    + (position.start.or_else(0.0) - position.end.or_else(0.0)),
+                y: padding_border.top
+                    + margin.top
+                    + align_offset(child, node, free_space.height)
+                    + (position.top.or_else(0.0) - position.bottom.or_else(0.0)),
+            };
+
+            let overflow_size = child_overflow(child, result.size, result.content_size, direction, direction);
+
+            content_size.width = content_size
+                .width
+                .max(location.x + (result.size.width + margin.end).max(overflow_size.width) + padding_border.end);
+            content_size.height = content_size.height.max(
+                location.y + (result.size.height + margin.bottom).max(overflow_size.height) + padding_border.bottom,
+            );
+
+            // The baselines of the stack are those of its first and last in-flow children.
+            baselines = Some(match baselines {
+                Some((baseline, _)) => (baseline, location.y + result.last_baseline),
+                None => (location.y + result.baseline, location.y + result.last_baseline),
+            });
+
+            layout::Node {
+                order: *order as u32,
+                size: result.size,
+                content_size: result.content_size,
+                location,
+                baseline: result.baseline,
+                last_baseline: result.last_baseline,
+                visible: child.visibility == Visibility::Visible,
+                children: Box::new(result.children),
+            }
+        })
+        .collect();
+
+    let (baseline, last_baseline) = baselines.unwrap_or((container_size.height, container_size.height));
+
+    let static_position = Point { x: padding_border.start, y: padding_border.top };
+    let (children, content_size) =
+        finish_layout(node, children, container_size, border, &|_, _| static_position, direction, content_size);
+
+    ComputeResult { size: container_size, content_size, baseline, last_baseline, children }
+}
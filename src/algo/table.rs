@@ -0,0 +1,582 @@
+use crate::layout;
+
+use crate::style;
+use crate::style::{Direction, Display, TableLayout, Visibility};
+
+use crate::number::Number::*;
+use crate::number::*;
+
+use crate::geometry::{Point, Rect, Size};
+
+use super::{
+    child_overflow, compute_internal, contents_children, finish_layout, hidden_layout, is_in_flow, padding_border,
+    ComputeResult,
+};
+
+// 17.2. The CSS table model. Children of a table other than row groups and columns are laid out
+// as its rows, and all children of a row as its cells. No anonymous table boxes are generated.
+struct TableRow<'a> {
+    node: &'a style::Node,
+    order: usize,
+
+    // The row group the row belongs to, as the index of the group among the children of the table.
+    group: Option<usize>,
+
+    height: f32,
+    top: f32,
+}
+
+struct TableCell<'a> {
+    node: &'a style::Node,
+    order: usize,
+
+    row: usize,
+    column: usize,
+    row_span: usize,
+    column_span: usize,
+
+    min_content_width: f32,
+    max_content_width: f32,
+}
+
+#[derive(Copy, Clone)]
+struct TableColumn {
+    min_width: f32,
+    max_width: f32,
+    width: f32,
+    left: f32,
+}
+
+// Spans of zero are treated as spanning a single row or column.
+fn span(span: u16) -> usize {
+    span.max(1) as usize
+}
+
+// Shares out extra space in proportion to the given weights, or equally when they are all zero.
+fn distribute(extra: f32, weights: &[f32]) -> Vec<f32> {
+    let total: f32 = weights.iter().sum();
+    weights
+        .iter()
+        .map(|weight| if total > 0.0 { extra * weight / total } else { extra / weights.len() as f32 })
+        .collect()
+}
+
+pub(super) fn compute(
+    node: &style::Node,
+    node_size: Size<Number>,
+    parent_size: Size<AvailableSpace>,
+    direction: Direction,
+    margin: Rect<f32>,
+    padding: Rect<f32>,
+    border: Rect<f32>,
+) -> ComputeResult {
+    let padding_border = padding_border(padding, border);
+
+    let node_inner_size = Size {
+        width: node_size.width - padding_border.horizontal(),
+        height: node_size.height - padding_border.vertical(),
+    };
+
+    // 17.6.1. The separated borders model. Border spacing separates the cells from each other and
+    // from the edges of the table.
+    let spacing = Size {
+        width: node.border_spacing.width.resolve(node_inner_size.width).or_else(0.0),
+        height: node.border_spacing.height.resolve(node_inner_size.height).or_else(0.0),
+    };
+
+    let mut rows: Vec<TableRow> = vec![];
+    for (order, child) in contents_children(node).into_iter().enumerate().filter(|(_, child)| is_in_flow(child)) {
+        match child.display {
+            Display::TableColumn => (),
+            Display::TableRowGroup => {
+                rows.extend(contents_children(child).into_iter().enumerate().filter(|(_, row)| is_in_flow(row)).map(
+                    |(row_order, row)| TableRow {
+                        node: row,
+                        order: row_order,
+                        group: Some(order),
+                        height: 0.0,
+                        top: 0.0,
+                    },
+                ))
+            }
+            _ => rows.push(TableRow { node: child, order, group: None, height: 0.0, top: 0.0 }),
+        }
+    }
+
+    let mut cells = place_cells(&rows);
+
+    // Columns may be given a width by column elements, each applying to as many columns as it spans.
+    let mut column_widths: Vec<Number> = vec![];
+    for child in contents_children(node).into_iter().filter(|child| is_in_flow(child)) {
+        if child.display == Display::TableColumn {
+            let width = child.size.width.resolve(node_inner_size.width);
+            column_widths.resize(column_widths.len() + span(child.column_span), width);
+        }
+    }
+
+    let column_count =
+        cells.iter().map(|cell| cell.column + cell.column_span).max().unwrap_or(0).max(column_widths.len());
+    column_widths.resize(column_count, Undefined);
+
+    let columns_spacing = if column_count > 0 { spacing.width * (column_count + 1) as f32 } else { 0.0 };
+    let rows_spacing = if rows.is_empty() { 0.0 } else { spacing.height * (rows.len() + 1) as f32 };
+
+    // 17.5.2. Table width algorithms. Fixed table layout only applies to tables with a width,
+    // others use automatic table layout.
+    let (width, mut columns) = match (node.table_layout, node_size.width) {
+        (TableLayout::Fixed, Defined(width)) => {
+            let columns = fixed_columns(
+                &cells,
+                &column_widths,
+                width - padding_border.horizontal() - columns_spacing,
+                spacing.width,
+            );
+            let columns_width: f32 = columns.iter().map(|column| column.width).sum();
+            (width.max(columns_width + columns_spacing + padding_border.horizontal()), columns)
+        }
+        _ => {
+            for cell in cells.iter_mut() {
+                let content_width = |constraint| {
+                    compute_internal(
+                        cell.node,
+                        Size { width: Undefined, height: cell.node.size.height.resolve(Undefined) },
+                        Size { width: constraint, height: AvailableSpace::MaxContent },
+                        Undefined,
+                        direction,
+                    )
+                    .size
+                    .width
+                };
+
+                // A cell with a width is that wide, unless its content doesn't fit.
+                let min_content_width = content_width(AvailableSpace::MinContent);
+                let (min_content_width, max_content_width) = match cell.node.size.width.resolve(node_inner_size.width) {
+                    Defined(width) => (min_content_width.max(width), min_content_width.max(width)),
+                    Undefined => (min_content_width, content_width(AvailableSpace::MaxContent).max(min_content_width)),
+                };
+
+                cell.min_content_width = min_content_width;
+                cell.max_content_width = max_content_width;
+            }
+
+            let mut columns = auto_columns(&cells, &column_widths, spacing.width);
+
+            let min_width: f32 = columns.iter().map(|column| column.min_width).sum();
+            let max_width: f32 = columns.iter().map(|column| column.max_width).sum();
+            let outer_spacing = columns_spacing + padding_border.horizontal();
+
+            // Without a width the table shrinks to fit its columns within the available space. It is
+            // never narrower than the minimum widths of its columns.
+            let width = match node_size.width {
+                Defined(width) => width,
+                Undefined => match parent_size.width - margin.horizontal() {
+                    AvailableSpace::Definite(available) => (max_width + outer_spacing).min(available),
+                    AvailableSpace::MinContent => min_width + outer_spacing,
+                    AvailableSpace::MaxContent => max_width + outer_spacing,
+                },
+            }
+            .max(min_width + outer_spacing);
+
+            let available = width - outer_spacing;
+            let weights: Vec<f32> = columns.iter().map(|column| column.max_width).collect();
+            let extra = distribute((available - max_width).max(0.0), &weights);
+
+            for (column, extra) in columns.iter_mut().zip(extra) {
+                column.width = if available <= min_width {
+                    column.min_width
+                } else if available <= max_width {
+                    column.min_width
+                        + (column.max_width - column.min_width) * (available - min_width) / (max_width - min_width)
+                } else {
+                    column.max_width + extra
+                };
+            }
+
+            (width, columns)
+        }
+    };
+
+    let mut left = padding_border.start + spacing.width;
+    for column in columns.iter_mut() {
+        column.left = left;
+        left += column.width + spacing.width;
+    }
+
+    let inner_width = width - padding_border.horizontal();
+    let spanned_width = |column: usize, column_span: usize| {
+        columns[column..column + column_span].iter().map(|column| column.width).sum::<f32>()
+            + spacing.width * (column_span - 1) as f32
+    };
+
+    let layout_cell = |cell: &TableCell, height: Number| {
+        let width = spanned_width(cell.column, cell.column_span);
+        compute_internal(
+            cell.node,
+            Size { width: Defined(width), height },
+            Size { width: AvailableSpace::Definite(width), height: AvailableSpace::MaxContent },
+            Defined(inner_width),
+            direction,
+        )
+    };
+
+    // 17.5.3. Table height algorithms. Rows are as tall as their tallest cell, with cells spanning
+    // several rows making those rows taller when needed.
+
+    let cell_heights: Vec<f32> = cells
+        .iter()
+        .map(|cell| {
+            let height = cell
+                .node
+                .size
+                .height
+                .resolve(Undefined)
+                .maybe_max(cell.node.min_size.height.resolve(Undefined))
+                .maybe_min(cell.node.max_size.height.resolve(Undefined));
+            layout_cell(cell, height).size.height
+        })
+        .collect();
+
+    for row in rows.iter_mut() {
+        row.height = row.node.size.height.resolve(Undefined).or_else(0.0);
+    }
+
+    for (cell, height) in cells.iter().zip(&cell_heights).filter(|(cell, _)| cell.row_span == 1) {
+        rows[cell.row].height = rows[cell.row].height.max(*height);
+    }
+
+    let mut spanning: Vec<(&TableCell, &f32)> =
+        cells.iter().zip(&cell_heights).filter(|(cell, _)| cell.row_span > 1).collect();
+    spanning.sort_by_key(|(cell, _)| cell.row_span);
+
+    for (cell, height) in spanning {
+        let spanned = &mut rows[cell.row..cell.row + cell.row_span];
+        let spanned_height =
+            spanned.iter().map(|row| row.height).sum::<f32>() + spacing.height * (cell.row_span - 1) as f32;
+
+        if *height > spanned_height {
+            let weights: Vec<f32> = spanned.iter().map(|row| row.height).collect();
+            for (row, extra) in spanned.iter_mut().zip(distribute(height - spanned_height, &weights)) {
+                row.height += extra;
+            }
+        }
+    }
+
+    // A table with a height is at least as tall as its rows, which grow to fill it.
+    let content_height = rows.iter().map(|row| row.height).sum::<f32>() + rows_spacing + padding_border.vertical();
+    let height = node_size.height.or_else(content_height).max(content_height);
+
+    if height > content_height && !rows.is_empty() {
+        let weights: Vec<f32> = rows.iter().map(|row| row.height).collect();
+        for (row, extra) in rows.iter_mut().zip(distribute(height - content_height, &weights)) {
+            row.height += extra;
+        }
+    }
+
+    let mut top = padding_border.top + spacing.height;
+    for row in rows.iter_mut() {
+        row.top = top;
+        top += row.height + spacing.height;
+    }
+
+    let container_size = Size { width, height };
+
+    // Cells are stretched to the height of the rows they span, with their content at the top.
+    let row_left = padding_border.start + spacing.width;
+    let row_width = if column_count > 0 { spanned_width(0, column_count) } else { 0.0 };
+
+    let mut cell_layouts: Vec<Vec<layout::Node>> = rows.iter().map(|_| vec![]).collect();
+    for cell in cells.iter() {
+        let height = rows[cell.row..cell.row + cell.row_span].iter().map(|row| row.height).sum::<f32>()
+            + spacing.height * (cell.row_span - 1) as f32;
+        let result = layout_cell(cell, Defined(height));
+
+        cell_layouts[cell.row].push(layout::Node {
+            order: cell.order as u32,
+            size: result.size,
+            content_size: result.content_size,
+            location: Point { x: columns[cell.column].left - row_left, y: 0.0 },
+            baseline: result.baseline,
+            last_baseline: result.last_baseline,
+            visible: cell.node.visibility == Visibility::Visible,
+            children: Box::new(result.children),
+        });
+    }
+
+    // The baseline of a row is that of its first cell, and the baselines of the table are those of
+    // its first and last rows. Without them they are synthesized from the bottom of the border box.
+    let mut row_layouts: Vec<Option<layout::Node>> = rows
+        .iter()
+        .zip(cell_layouts)
+        .map(|(row, cells)| {
+            let baseline = cells.first().map_or(row.height, |cell| cell.baseline);
+            let last_baseline = cells.first().map_or(row.height, |cell| cell.last_baseline);
+            Some(part_layout(
+                row.node,
+                row.order,
+                Point { x: row_left, y: row.top },
+                Size { width: row_width, height: row.height },
+                (baseline, last_baseline),
+                cells,
+                direction,
+            ))
+        })
+        .collect();
+
+    let baseline = rows.first().map_or(height, |row| row.top + row_layouts[0].as_ref().unwrap().baseline);
+    let last_baseline =
+        rows.last().map_or(height, |row| row.top + row_layouts[rows.len() - 1].as_ref().unwrap().last_baseline);
+
+    let rows_top = padding_border.top + spacing.height;
+    let rows_height = rows.last().map_or(0.0, |row| row.top + row.height - rows_top);
+
+    let mut children: Vec<layout::Node> = vec![];
+    let mut first_column = 0;
+
+    for (order, child) in contents_children(node).into_iter().enumerate().filter(|(_, child)| is_in_flow(child)) {
+        match child.display {
+            // Columns cover the rows of the table, and don't lay out any content of their own.
+            Display::TableColumn => {
+                let column_span = span(child.column_span).min(column_count - first_column);
+                let location =
+                    Point { x: columns.get(first_column).map_or(row_left, |column| column.left), y: rows_top };
+                let size = Size {
+                    width: if column_span > 0 { spanned_width(first_column, column_span) } else { 0.0 },
+                    height: rows_height,
+                };
+                first_column += column_span;
+
+                children.push(layout::Node {
+                    order: order as u32,
+                    size,
+                    content_size: size,
+                    location,
+                    baseline: size.height,
+                    last_baseline: size.height,
+                    visible: child.visibility == Visibility::Visible,
+                    children: Box::new(
+                        child.children.iter().enumerate().map(|(order, child)| hidden_layout(order, child)).collect(),
+                    ),
+                });
+            }
+
+            // Row groups cover their rows, which are positioned relative to them.
+            Display::TableRowGroup => {
+                let group_rows: Vec<layout::Node> = rows
+                    .iter()
+                    .zip(row_layouts.iter_mut())
+                    .filter(|(row, _)| row.group == Some(order))
+                    .filter_map(|(_, layout)| layout.take())
+                    .collect();
+
+                let top = group_rows.first().map_or(rows_top, |row| row.location.y);
+                let bottom = group_rows.last().map_or(top, |row| row.location.y + row.size.height);
+                let baseline = group_rows.first().map_or(bottom - top, |row| row.location.y - top + row.baseline);
+                let last_baseline =
+                    group_rows.last().map_or(bottom - top, |row| row.location.y - top + row.last_baseline);
+
+                let group_rows = group_rows
+                    .into_iter()
+                    .map(|row| layout::Node { location: Point { x: 0.0, y: row.location.y - top }, ..row })
+                    .collect();
+
+                children.push(part_layout(
+                    child,
+                    order,
+                    Point { x: row_left, y: top },
+                    Size { width: row_width, height: bottom - top },
+                    (baseline, last_baseline),
+                    group_rows,
+                    direction,
+                ));
+            }
+
+            _ => (),
+        }
+    }
+
+    children.extend(row_layouts.into_iter().flatten());
+
+    let child_nodes = contents_children(node);
+    let mut content_size = container_size;
+    for child in &children {
+        let overflow_size =
+            child_overflow(child_nodes[child.order as usize], child.size, child.content_size, direction, direction);
+        content_size.width = content_size.width.max(child.location.x + child.size.width.max(overflow_size.width));
+        content_size.height = content_size.height.max(child.location.y + child.size.height.max(overflow_size.height));
+    }
+
+    let static_position = Point { x: padding_border.start, y: padding_border.top };
+    let (children, content_size) =
+        finish_layout(node, children, container_size, border, &|_, _| static_position, direction, content_size);
+
+    ComputeResult { size: container_size, content_size, baseline, last_baseline, children }
+}
+
+// 17.5. Visual layout of table contents. Cells are placed in the rows from left to right, in the
+// first column which is not yet covered by a cell spanning down from an earlier row. Row spans
+// don't extend past the last row.
+fn place_cells<'a>(rows: &[TableRow<'a>]) -> Vec<TableCell<'a>> {
+    let mut cells: Vec<TableCell> = vec![];
+    let mut occupied: Vec<Vec<bool>> = rows.iter().map(|_| vec![]).collect();
+
+    for (row_index, row) in rows.iter().enumerate() {
+        let mut column = 0;
+
+        for (order, child) in contents_children(row.node).into_iter().enumerate().filter(|(_, child)| is_in_flow(child))
+        {
+            while occupied[row_index].get(column).cloned().unwrap_or(false) {
+                column += 1;
+            }
+
+            let row_span = span(child.row_span).min(rows.len() - row_index);
+            let column_span = span(child.column_span);
+
+            for slots in occupied[row_index..row_index + row_span].iter_mut() {
+                if slots.len() < column + column_span {
+                    slots.resize(column + column_span, false);
+                }
+                slots[column..column + column_span].iter_mut().for_each(|slot| *slot = true);
+            }
+
+            cells.push(TableCell {
+                node: child,
+                order,
+                row: row_index,
+                column,
+                row_span,
+                column_span,
+                min_content_width: 0.0,
+                max_content_width: 0.0,
+            });
+
+            column += column_span;
+        }
+    }
+
+    cells
+}
+
+// 17.5.2.2. Automatic table layout. The minimum and maximum widths of the columns are those of
+// their widest cells, or their own width when they have one and their cells fit. Cells spanning
+// several columns then widen those columns when they don't fit, starting with the narrowest spans.
+fn auto_columns(cells: &[TableCell], column_widths: &[Number], spacing: f32) -> Vec<TableColumn> {
+    let mut columns: Vec<TableColumn> =
+        vec![TableColumn { min_width: 0.0, max_width: 0.0, width: 0.0, left: 0.0 }; column_widths.len()];
+
+    for cell in cells.iter().filter(|cell| cell.column_span == 1) {
+        let column = &mut columns[cell.column];
+        column.min_width = column.min_width.max(cell.min_content_width);
+        column.max_width = column.max_width.max(cell.max_content_width);
+    }
+
+    for (column, width) in columns.iter_mut().zip(column_widths) {
+        if let Defined(width) = width {
+            column.min_width = column.min_width.max(*width);
+            column.max_width = column.min_width;
+        }
+    }
+
+    let mut spanning: Vec<&TableCell> = cells.iter().filter(|cell| cell.column_span > 1).collect();
+    spanning.sort_by_key(|cell| cell.column_span);
+
+    for cell in spanning {
+        let spanned = &mut columns[cell.column..cell.column + cell.column_span];
+        let inner_spacing = spacing * (cell.column_span - 1) as f32;
+        let weights: Vec<f32> = spanned.iter().map(|column| column.max_width).collect();
+
+        let min_width: f32 = spanned.iter().map(|column| column.min_width).sum::<f32>() + inner_spacing;
+        if cell.min_content_width > min_width {
+            for (column, extra) in spanned.iter_mut().zip(distribute(cell.min_content_width - min_width, &weights)) {
+                column.min_width += extra;
+            }
+        }
+
+        let max_width: f32 = spanned.iter().map(|column| column.max_width).sum::<f32>() + inner_spacing;
+        if cell.max_content_width > max_width {
+            for (column, extra) in spanned.iter_mut().zip(distribute(cell.max_content_width - max_width, &weights)) {
+                column.max_width += extra;
+            }
+        }
+    }
+
+    columns.iter_mut().for_each(|column| column.max_width = column.max_width.max(column.min_width));
+    columns
+}
+
+// 17.5.2.1. Fixed table layout. Columns take their width from column elements, or otherwise from
+// the cells of the first row, with cells spanning several columns divided equally between them.
+// The other columns share the remaining width equally, and when there are none the columns with
+// a width grow to fill the table.
+fn fixed_columns(cells: &[TableCell], column_widths: &[Number], available: f32, spacing: f32) -> Vec<TableColumn> {
+    let mut widths: Vec<Number> = column_widths.to_vec();
+
+    for cell in cells.iter().filter(|cell| cell.row == 0) {
+        if let Defined(width) = cell.node.size.width.resolve(Defined(available)) {
+            let width = (width - spacing * (cell.column_span - 1) as f32) / cell.column_span as f32;
+            for column_width in widths[cell.column..cell.column + cell.column_span].iter_mut() {
+                if column_width.is_undefined() {
+                    *column_width = Defined(width);
+                }
+            }
+        }
+    }
+
+    let fixed_width: f32 = widths.iter().map(|width| width.or_else(0.0)).sum();
+    let auto_count = widths.iter().filter(|width| width.is_undefined()).count();
+
+    let mut columns: Vec<TableColumn> = widths
+        .iter()
+        .map(|width| TableColumn {
+            min_width: 0.0,
+            max_width: 0.0,
+            width: width.or_else((available - fixed_width).max(0.0) / auto_count.max(1) as f32),
+            left: 0.0,
+        })
+        .collect();
+
+    if auto_count == 0 && fixed_width < available && !columns.is_empty() {
+        let weights: Vec<f32> = columns.iter().map(|column| column.width).collect();
+        for (column, extra) in columns.iter_mut().zip(distribute(available - fixed_width, &weights)) {
+            column.width += extra;
+        }
+    }
+
+    columns
+}
+
+// Completes the layout of a row, row group or column from the layouts of its in-flow children,
+// adding those of its hidden and absolutely positioned children and mirroring them for
+// right-to-left tables.
+fn part_layout(
+    node: &style::Node,
+    order: usize,
+    location: Point<f32>,
+    size: Size<f32>,
+    baselines: (f32, f32),
+    children: Vec<layout::Node>,
+    direction: Direction,
+) -> layout::Node {
+    let child_nodes = contents_children(node);
+    let mut content_size = size;
+    for child in &children {
+        let overflow_size =
+            child_overflow(child_nodes[child.order as usize], child.size, child.content_size, direction, direction);
+        content_size.width = content_size.width.max(child.location.x + child.size.width.max(overflow_size.width));
+        content_size.height = content_size.height.max(child.location.y + child.size.height.max(overflow_size.height));
+    }
+
+    let zero = Rect { start: 0.0, end: 0.0, top: 0.0, bottom: 0.0 };
+    let (children, content_size) =
+        finish_layout(node, children, size, zero, &|_, _| Point { x: 0.0, y: 0.0 }, direction, content_size);
+
+    layout::Node {
+        order: order as u32,
+        size,
+        content_size,
+        location,
+        baseline: baselines.0,
+        last_baseline: baselines.1,
+        visible: node.visibility == Visibility::Visible,
+        children: Box::new(children),
+    }
+}
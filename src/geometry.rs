@@ -11,6 +11,14 @@ pub struct Rect<T> {
     pub bottom: T,
 }
 
+/// The start and end of an item along one axis, e.g. the lines a grid item spans.
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct Line<T> {
+    pub start: T,
+    pub end: T,
+}
+
 impl<T> Rect<T> {
     pub(crate) fn map<R, F>(self, f: F) -> Rect<R>
     where
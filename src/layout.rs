@@ -5,7 +5,11 @@ use crate::geometry::{Point, Size};
 pub struct LayoutNode {
     pub(crate) order: u32,
     pub size: Size<f32>,
+    pub content_size: Size<f32>,
     pub location: Point<f32>,
+    pub baseline: f32,
+    pub last_baseline: f32,
+    pub visible: bool,
     pub children: Box<Vec<LayoutNode>>,
 }
 
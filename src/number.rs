@@ -219,3 +219,64 @@ impl ops::Div<Number> for Number {
         }
     }
 }
+
+#[repr(C)]
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum AvailableSpace {
+    Definite(f32),
+    MinContent,
+    MaxContent,
+}
+
+impl Default for AvailableSpace {
+    fn default() -> AvailableSpace {
+        AvailableSpace::MaxContent
+    }
+}
+
+impl AvailableSpace {
+    pub fn is_definite(self) -> bool {
+        match self {
+            AvailableSpace::Definite(_) => true,
+            AvailableSpace::MinContent | AvailableSpace::MaxContent => false,
+        }
+    }
+}
+
+impl From<Number> for AvailableSpace {
+    fn from(number: Number) -> AvailableSpace {
+        match number {
+            Number::Defined(val) => AvailableSpace::Definite(val),
+            Number::Undefined => AvailableSpace::MaxContent,
+        }
+    }
+}
+
+impl ToNumber for AvailableSpace {
+    fn to_number(self) -> Number {
+        match self {
+            AvailableSpace::Definite(val) => Number::Defined(val),
+            _ => Number::Undefined,
+        }
+    }
+}
+
+impl OrElse<AvailableSpace> for Number {
+    fn or_else(self, other: AvailableSpace) -> AvailableSpace {
+        match self {
+            Number::Defined(val) => AvailableSpace::Definite(val),
+            Number::Undefined => other,
+        }
+    }
+}
+
+impl ops::Sub<f32> for AvailableSpace {
+    type Output = AvailableSpace;
+
+    fn sub(self, rhs: f32) -> AvailableSpace {
+        match self {
+            AvailableSpace::Definite(val) => AvailableSpace::Definite(val - rhs),
+            _ => self,
+        }
+    }
+}
@@ -1,5 +1,81 @@
-use crate::geometry::{Rect, Size};
-use crate::number::Number;
+use std::ops;
+
+use crate::geometry::{Line, Point, Rect, Size};
+use crate::number::{AvailableSpace, Number};
+
+/// Measures the content of a leaf node. It is called with the node's known width and height,
+/// leaving a dimension `Undefined` when it should be sized to content, as well as the space
+/// available to the node, and returns the node's size. A min-content available size asks for
+/// the smallest size the content fits in, e.g. text broken at every soft wrap opportunity.
+pub type MeasureFunc = Box<dyn Fn(Size<Number>, Size<AvailableSpace>) -> Size<f32>>;
+
+/// Measures a run of text laid out inline, returning the segments between its break opportunities
+/// along with the height and baseline of its lines.
+pub type TextFunc = Box<dyn Fn() -> TextRun>;
+
+/// A piece of text between two break opportunities. Lines only break between segments, and any
+/// whitespace at the end of the segment, which is included in its advance, hangs past the end of
+/// a line.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct TextSegment {
+    pub advance: f32,
+    pub trailing_whitespace: f32,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct TextRun {
+    pub segments: Vec<TextSegment>,
+    pub line_height: f32,
+    pub baseline: f32,
+}
+
+/// A user-defined layout algorithm, which lays out the children of a node in place of the
+/// algorithm of its display.
+pub type LayoutFunc = Box<dyn LayoutAlgorithm>;
+
+/// Lays out the in-flow children of a node. It is called with the node's known content box size,
+/// leaving a dimension `Undefined` when it should be sized to content, as well as the space
+/// available to the content box, and returns the size of the content box along with the location
+/// of each child relative to it. Children are laid out, and can be measured as often as needed,
+/// through the given handle; a child is given the size it was last laid out with, or otherwise is
+/// sized to its content. The layout is physical, so it isn't mirrored in right-to-left nodes.
+pub trait LayoutAlgorithm {
+    fn compute(
+        &self,
+        node_size: Size<Number>,
+        available_space: Size<AvailableSpace>,
+        children: &mut dyn LayoutChildren,
+    ) -> CustomLayout;
+}
+
+/// The in-flow children of a node laid out by a user-defined layout algorithm, in order.
+pub trait LayoutChildren {
+    fn count(&self) -> usize;
+
+    /// The style of a child.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is not less than `count()`.
+    fn style(&self, index: usize) -> &Node;
+
+    /// Lays out a child with the known size of its border box and the space available to it,
+    /// returning the size of its border box. A dimension left `Undefined` is taken from the style of
+    /// the child, or sized to its content, and margins are left to the layout algorithm.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is not less than `count()`.
+    fn compute(&mut self, index: usize, size: Size<Number>, available_space: Size<AvailableSpace>) -> Size<f32>;
+}
+
+/// The result of a user-defined layout algorithm. Children without a location are placed at the
+/// start of the content box.
+#[derive(Clone, Debug)]
+pub struct CustomLayout {
+    pub size: Size<f32>,
+    pub locations: Vec<Point<f32>>,
+}
 
 #[repr(C)]
 #[derive(Copy, Clone, PartialEq, Debug)]
@@ -9,6 +85,10 @@ pub enum AlignItems {
     Center,
     Baseline,
     Stretch,
+    Start,
+    End,
+    Normal,
+    LastBaseline,
 }
 
 impl Default for AlignItems {
@@ -26,6 +106,10 @@ pub enum AlignSelf {
     Center,
     Baseline,
     Stretch,
+    Start,
+    End,
+    Normal,
+    LastBaseline,
 }
 
 impl Default for AlignSelf {
@@ -43,6 +127,10 @@ pub enum AlignContent {
     Stretch,
     SpaceBetween,
     SpaceAround,
+    SpaceEvenly,
+    Start,
+    End,
+    Normal,
 }
 
 impl Default for AlignContent {
@@ -51,6 +139,22 @@ impl Default for AlignContent {
     }
 }
 
+/// Whether an alignment is kept when the aligned content overflows its container. Unsafe
+/// alignment is honored regardless, safe alignment falls back to start alignment so that
+/// the overflowing content does not end up past the start edge where it can't be scrolled to.
+#[repr(C)]
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum AlignmentSafety {
+    Unsafe,
+    Safe,
+}
+
+impl Default for AlignmentSafety {
+    fn default() -> AlignmentSafety {
+        AlignmentSafety::Unsafe
+    }
+}
+
 #[repr(C)]
 #[derive(Copy, Clone, PartialEq, Debug)]
 pub enum Direction {
@@ -70,6 +174,20 @@ impl Default for Direction {
 pub enum Display {
     Flex,
     None,
+    Grid,
+    Block,
+    Inline,
+    InlineBlock,
+    InlineFlex,
+    Contents,
+    Table,
+    TableRowGroup,
+    TableRow,
+    TableCell,
+    TableColumn,
+    Masonry,
+    Constraints,
+    Stack,
 }
 
 impl Default for Display {
@@ -116,6 +234,12 @@ pub enum JustifyContent {
     SpaceBetween,
     SpaceAround,
     SpaceEvenly,
+    Start,
+    End,
+    Left,
+    Right,
+    Stretch,
+    Normal,
 }
 
 impl Default for JustifyContent {
@@ -124,6 +248,48 @@ impl Default for JustifyContent {
     }
 }
 
+/// Aligns a child of a stack in the inline axis. Along with the alignment in the block axis this
+/// places the child at one of the nine anchor positions of the stack. Left and right are physical
+/// while start and end depend on the direction, and auto stretches the child like normal.
+#[repr(C)]
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum JustifySelf {
+    Auto,
+    Start,
+    End,
+    Center,
+    Left,
+    Right,
+    Stretch,
+    Normal,
+}
+
+impl Default for JustifySelf {
+    fn default() -> JustifySelf {
+        JustifySelf::Auto
+    }
+}
+
+/// Aligns the lines of inline content within a block container. Left and right are physical while
+/// start and end depend on the direction. Justified lines, except the last, are stretched to fill the
+/// container by spacing out the content between break opportunities.
+#[repr(C)]
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum TextAlign {
+    Start,
+    End,
+    Left,
+    Right,
+    Center,
+    Justify,
+}
+
+impl Default for TextAlign {
+    fn default() -> TextAlign {
+        TextAlign::Start
+    }
+}
+
 #[repr(C)]
 #[derive(Copy, Clone, PartialEq, Debug)]
 pub enum Overflow {
@@ -138,6 +304,22 @@ impl Default for Overflow {
     }
 }
 
+/// Whether space is reserved for the vertical scrollbar of a scroll container when the
+/// scrollbar is not always shown, and whether the space is reserved on both inline edges.
+#[repr(C)]
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum ScrollbarGutter {
+    Auto,
+    Stable,
+    StableBothEdges,
+}
+
+impl Default for ScrollbarGutter {
+    fn default() -> ScrollbarGutter {
+        ScrollbarGutter::Auto
+    }
+}
+
 #[repr(C)]
 #[derive(Copy, Clone, PartialEq, Debug)]
 pub enum PositionType {
@@ -151,6 +333,38 @@ impl Default for PositionType {
     }
 }
 
+/// Floats are taken out of the flow of a block container and placed against its left or right edge,
+/// with the content after them flowing alongside. Floats have no effect on flex and grid items.
+#[repr(C)]
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum Float {
+    None,
+    Left,
+    Right,
+}
+
+impl Default for Float {
+    fn default() -> Float {
+        Float::None
+    }
+}
+
+/// Moves a child of a block container below the earlier floats on the given sides.
+#[repr(C)]
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum Clear {
+    None,
+    Left,
+    Right,
+    Both,
+}
+
+impl Default for Clear {
+    fn default() -> Clear {
+        Clear::None
+    }
+}
+
 #[repr(C)]
 #[derive(Copy, Clone, PartialEq, Debug)]
 pub enum FlexWrap {
@@ -165,6 +379,363 @@ impl Default for FlexWrap {
     }
 }
 
+/// Whether a node is drawn, reported as `visible` on its layout. Unlike in CSS the visibility
+/// is not inherited: a node is visible unless it sets a different visibility itself, regardless
+/// of the visibility of its ancestors, so hiding a subtree means hiding each of its nodes.
+#[repr(C)]
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum Visibility {
+    Visible,
+    Hidden,
+    Collapse,
+}
+
+impl Default for Visibility {
+    fn default() -> Visibility {
+        Visibility::Visible
+    }
+}
+
+/// The direction in which grid items without a definite position are placed, and whether
+/// they may be placed in holes left earlier in the grid.
+#[repr(C)]
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum GridAutoFlow {
+    Row,
+    Column,
+    RowDense,
+    ColumnDense,
+}
+
+impl Default for GridAutoFlow {
+    fn default() -> GridAutoFlow {
+        GridAutoFlow::Row
+    }
+}
+
+impl GridAutoFlow {
+    pub(crate) fn is_row(self) -> bool {
+        self == GridAutoFlow::Row || self == GridAutoFlow::RowDense
+    }
+
+    pub(crate) fn is_dense(self) -> bool {
+        self == GridAutoFlow::RowDense || self == GridAutoFlow::ColumnDense
+    }
+}
+
+/// How the items of a masonry container without a definite column are placed. Pack places each
+/// item in the column where it ends up highest, next in the columns after the previous item.
+#[repr(C)]
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum MasonryAutoFlow {
+    Pack,
+    Next,
+}
+
+impl Default for MasonryAutoFlow {
+    fn default() -> MasonryAutoFlow {
+        MasonryAutoFlow::Pack
+    }
+}
+
+/// One end of the placement of a grid item. Lines are numbered from 1 at the start of the
+/// explicit grid, or from -1 at its end. A span on one end places the item relative to the
+/// other end, or automatically when that is not a line either.
+#[repr(C)]
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum GridPlacement {
+    Auto,
+    Line(i16),
+    Span(u16),
+}
+
+impl Default for GridPlacement {
+    fn default() -> GridPlacement {
+        GridPlacement::Auto
+    }
+}
+
+impl Default for Line<GridPlacement> {
+    fn default() -> Line<GridPlacement> {
+        Line { start: Default::default(), end: Default::default() }
+    }
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum MinTrackSizingFunction {
+    Auto,
+    MinContent,
+    MaxContent,
+    Points(f32),
+    Percent(f32),
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum MaxTrackSizingFunction {
+    Auto,
+    MinContent,
+    MaxContent,
+    Points(f32),
+    Percent(f32),
+    Flex(f32),
+}
+
+/// The size of a grid track, between a minimum and a maximum sizing function as in `minmax()`.
+#[repr(C)]
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct TrackSizingFunction {
+    pub min: MinTrackSizingFunction,
+    pub max: MaxTrackSizingFunction,
+}
+
+impl Default for TrackSizingFunction {
+    fn default() -> TrackSizingFunction {
+        TrackSizingFunction::auto()
+    }
+}
+
+impl TrackSizingFunction {
+    pub fn auto() -> TrackSizingFunction {
+        TrackSizingFunction { min: MinTrackSizingFunction::Auto, max: MaxTrackSizingFunction::Auto }
+    }
+
+    pub fn points(points: f32) -> TrackSizingFunction {
+        TrackSizingFunction { min: MinTrackSizingFunction::Points(points), max: MaxTrackSizingFunction::Points(points) }
+    }
+
+    pub fn percent(percent: f32) -> TrackSizingFunction {
+        TrackSizingFunction {
+            min: MinTrackSizingFunction::Percent(percent),
+            max: MaxTrackSizingFunction::Percent(percent),
+        }
+    }
+
+    /// A flexible track taking a share of the free space, with an automatic minimum like `1fr`.
+    pub fn fr(flex: f32) -> TrackSizingFunction {
+        TrackSizingFunction { min: MinTrackSizingFunction::Auto, max: MaxTrackSizingFunction::Flex(flex) }
+    }
+
+    pub fn min_content() -> TrackSizingFunction {
+        TrackSizingFunction { min: MinTrackSizingFunction::MinContent, max: MaxTrackSizingFunction::MinContent }
+    }
+
+    pub fn max_content() -> TrackSizingFunction {
+        TrackSizingFunction { min: MinTrackSizingFunction::MaxContent, max: MaxTrackSizingFunction::MaxContent }
+    }
+
+    pub fn minmax(min: MinTrackSizingFunction, max: MaxTrackSizingFunction) -> TrackSizingFunction {
+        TrackSizingFunction { min, max }
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum GridTrackRepetition {
+    Count(u16),
+    AutoFill,
+    AutoFit,
+}
+
+/// An entry of a grid template, either a single track or tracks repeated like `repeat()`.
+/// Tracks repeated to fill the container must have a fixed minimum or maximum size. Empty
+/// tracks repeated to fit the container are collapsed.
+#[derive(Clone, PartialEq, Debug)]
+pub enum GridTrack {
+    Single(TrackSizingFunction),
+    Repeat(GridTrackRepetition, Vec<TrackSizingFunction>),
+}
+
+/// How the content of a multi-column container is shared between its columns when it has a
+/// height. Balanced columns are as short as possible while auto fills each column in turn, with
+/// content that doesn't fit overflowing into more columns in the inline direction.
+#[repr(C)]
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum ColumnFill {
+    Balance,
+    Auto,
+}
+
+impl Default for ColumnFill {
+    fn default() -> ColumnFill {
+        ColumnFill::Balance
+    }
+}
+
+/// How the columns of a table are sized. Automatic layout sizes them to the content of all of
+/// their cells, while fixed layout only considers the widths of the columns and of the cells in
+/// the first row, sharing the remaining width of the table between the other columns.
+#[repr(C)]
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum TableLayout {
+    Auto,
+    Fixed,
+}
+
+impl Default for TableLayout {
+    fn default() -> TableLayout {
+        TableLayout::Auto
+    }
+}
+
+/// An edge, center or dimension of a box in a constraint layout. Positions are relative to the
+/// border box of the container and are physical, they are not mirrored for right-to-left containers.
+#[repr(C)]
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum ConstraintAttribute {
+    Left,
+    Right,
+    Top,
+    Bottom,
+    Width,
+    Height,
+    CenterX,
+    CenterY,
+}
+
+/// The box an attribute belongs to, the constraint container itself or one of its children by index.
+/// Children are indexed after those with `Display::Contents` have been replaced by their own children.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum ConstraintItem {
+    Parent,
+    Child(usize),
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct ConstraintVariable {
+    pub item: ConstraintItem,
+    pub attribute: ConstraintAttribute,
+}
+
+impl ConstraintVariable {
+    pub fn new(item: ConstraintItem, attribute: ConstraintAttribute) -> ConstraintVariable {
+        ConstraintVariable { item, attribute }
+    }
+}
+
+/// A linear expression over the variables of a constraint layout, the sum of its terms, each a
+/// variable multiplied by a coefficient, and a constant. Expressions are built from variables and
+/// constants with the arithmetic operators, e.g. `a + b * 0.5 + 8.0`.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct ConstraintExpression {
+    pub terms: Vec<(f32, ConstraintVariable)>,
+    pub constant: f32,
+}
+
+impl From<ConstraintVariable> for ConstraintExpression {
+    fn from(variable: ConstraintVariable) -> ConstraintExpression {
+        ConstraintExpression { terms: vec![(1.0, variable)], constant: 0.0 }
+    }
+}
+
+impl From<f32> for ConstraintExpression {
+    fn from(constant: f32) -> ConstraintExpression {
+        ConstraintExpression { terms: vec![], constant }
+    }
+}
+
+impl<T: Into<ConstraintExpression>> ops::Add<T> for ConstraintExpression {
+    type Output = ConstraintExpression;
+
+    fn add(mut self, rhs: T) -> ConstraintExpression {
+        let rhs = rhs.into();
+        self.terms.extend(rhs.terms);
+        self.constant += rhs.constant;
+        self
+    }
+}
+
+impl<T: Into<ConstraintExpression>> ops::Sub<T> for ConstraintExpression {
+    type Output = ConstraintExpression;
+
+    fn sub(self, rhs: T) -> ConstraintExpression {
+        self + rhs.into() * -1.0
+    }
+}
+
+impl ops::Mul<f32> for ConstraintExpression {
+    type Output = ConstraintExpression;
+
+    fn mul(self, rhs: f32) -> ConstraintExpression {
+        ConstraintExpression {
+            terms: self.terms.into_iter().map(|(coefficient, variable)| (coefficient * rhs, variable)).collect(),
+            constant: self.constant * rhs,
+        }
+    }
+}
+
+impl<T: Into<ConstraintExpression>> ops::Add<T> for ConstraintVariable {
+    type Output = ConstraintExpression;
+
+    fn add(self, rhs: T) -> ConstraintExpression {
+        ConstraintExpression::from(self) + rhs
+    }
+}
+
+impl<T: Into<ConstraintExpression>> ops::Sub<T> for ConstraintVariable {
+    type Output = ConstraintExpression;
+
+    fn sub(self, rhs: T) -> ConstraintExpression {
+        ConstraintExpression::from(self) - rhs
+    }
+}
+
+impl ops::Mul<f32> for ConstraintVariable {
+    type Output = ConstraintExpression;
+
+    fn mul(self, rhs: f32) -> ConstraintExpression {
+        ConstraintExpression::from(self) * rhs
+    }
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum ConstraintRelation {
+    Equal,
+    LessOrEqual,
+    GreaterOrEqual,
+}
+
+/// The priority of a constraint. Required constraints must be satisfied, the others are satisfied
+/// as far as possible, where a stronger constraint always takes precedence over weaker ones.
+#[repr(C)]
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum ConstraintStrength {
+    Required,
+    Strong,
+    Medium,
+    Weak,
+}
+
+impl Default for ConstraintStrength {
+    fn default() -> ConstraintStrength {
+        ConstraintStrength::Required
+    }
+}
+
+/// A linear equality or inequality between two expressions.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Constraint {
+    pub left: ConstraintExpression,
+    pub relation: ConstraintRelation,
+    pub right: ConstraintExpression,
+    pub strength: ConstraintStrength,
+}
+
+impl Constraint {
+    pub fn new<L, R>(left: L, relation: ConstraintRelation, right: R) -> Constraint
+    where
+        L: Into<ConstraintExpression>,
+        R: Into<ConstraintExpression>,
+    {
+        Constraint { left: left.into(), relation, right: right.into(), strength: ConstraintStrength::Required }
+    }
+
+    pub fn with_strength(self, strength: ConstraintStrength) -> Constraint {
+        Constraint { strength, ..self }
+    }
+}
+
 #[repr(C)]
 #[derive(Copy, Clone, PartialEq, Debug)]
 pub enum Dimension {
@@ -207,23 +778,37 @@ pub struct StyleNode {
     pub display: Display,
 
     pub position_type: PositionType,
+    pub float: Float,
+    pub clear: Clear,
     pub direction: Direction,
     pub flex_direction: FlexDirection,
 
     pub flex_wrap: FlexWrap,
-    pub overflow: Overflow,
+    pub overflow_x: Overflow,
+    pub overflow_y: Overflow,
+    pub scrollbar_width: f32,
+    pub scrollbar_gutter: ScrollbarGutter,
+    pub visibility: Visibility,
 
     pub align_items: AlignItems,
+    pub align_items_safety: AlignmentSafety,
     pub align_self: AlignSelf,
+    pub align_self_safety: AlignmentSafety,
     pub align_content: AlignContent,
+    pub align_content_safety: AlignmentSafety,
 
     pub justify_content: JustifyContent,
+    pub justify_content_safety: AlignmentSafety,
+    pub justify_self: JustifySelf,
+
+    pub text_align: TextAlign,
 
     pub position: Rect<Dimension>,
     pub margin: Rect<Dimension>,
     pub padding: Rect<Dimension>,
     pub border: Rect<Dimension>,
 
+    pub order: i32,
     pub flex_grow: f32,
     pub flex_shrink: f32,
     pub flex_basis: Dimension,
@@ -232,8 +817,45 @@ pub struct StyleNode {
     pub min_size: Size<Dimension>,
     pub max_size: Size<Dimension>,
 
+    pub gap: Size<Dimension>,
+
+    pub grid_template_rows: Box<Vec<GridTrack>>,
+    pub grid_template_columns: Box<Vec<GridTrack>>,
+    pub grid_auto_rows: Box<Vec<TrackSizingFunction>>,
+    pub grid_auto_columns: Box<Vec<TrackSizingFunction>>,
+    pub grid_auto_flow: GridAutoFlow,
+    pub masonry_auto_flow: MasonryAutoFlow,
+
+    pub grid_row: Line<GridPlacement>,
+    pub grid_column: Line<GridPlacement>,
+
+    pub table_layout: TableLayout,
+    pub border_spacing: Size<Dimension>,
+
+    /// The number of rows and columns a table cell spans, or for a table column the number of
+    /// columns it applies to. Zero is treated as one.
+    pub row_span: u16,
+    pub column_span: u16,
+
+    /// Makes a block container lay out its content in columns when either is set. A column count of
+    /// zero is automatic, and the gap between the columns is the width of the gap.
+    pub column_count: u16,
+    pub column_width: Dimension,
+    pub column_fill: ColumnFill,
+
+    /// The constraints laying out the children of a constraint container.
+    pub constraints: Box<Vec<Constraint>>,
+
     pub aspect_ratio: Number,
 
+    pub measure: Option<MeasureFunc>,
+
+    /// Makes a leaf node a run of text, which is laid out inline regardless of its display.
+    pub text: Option<TextFunc>,
+
+    /// Lays out the children of the node with a user-defined algorithm regardless of its display.
+    pub algorithm: Option<LayoutFunc>,
+
     pub children: Box<Vec<StyleNode>>,
 }
 
@@ -245,23 +867,37 @@ impl Default for Node {
             display: Default::default(),
 
             position_type: Default::default(),
+            float: Default::default(),
+            clear: Default::default(),
             direction: Default::default(),
             flex_direction: Default::default(),
 
             flex_wrap: Default::default(),
-            overflow: Default::default(),
+            overflow_x: Default::default(),
+            overflow_y: Default::default(),
+            scrollbar_width: 0.0,
+            scrollbar_gutter: Default::default(),
+            visibility: Default::default(),
 
             align_items: Default::default(),
+            align_items_safety: Default::default(),
             align_self: Default::default(),
+            align_self_safety: Default::default(),
             align_content: Default::default(),
+            align_content_safety: Default::default(),
 
             justify_content: Default::default(),
+            justify_content_safety: Default::default(),
+            justify_self: Default::default(),
+
+            text_align: Default::default(),
 
             position: Default::default(),
             margin: Default::default(),
             padding: Default::default(),
             border: Default::default(),
 
+            order: 0,
             flex_grow: 0.0,
             flex_shrink: 1.0,
             flex_basis: Dimension::Auto,
@@ -270,8 +906,38 @@ impl Default for Node {
             min_size: Default::default(),
             max_size: Default::default(),
 
+            gap: Default::default(),
+
+            grid_template_rows: Box::new(vec![]),
+            grid_template_columns: Box::new(vec![]),
+            grid_auto_rows: Box::new(vec![]),
+            grid_auto_columns: Box::new(vec![]),
+            grid_auto_flow: Default::default(),
+            masonry_auto_flow: Default::default(),
+
+            grid_row: Default::default(),
+            grid_column: Default::default(),
+
+            table_layout: Default::default(),
+            border_spacing: Default::default(),
+
+            row_span: 1,
+            column_span: 1,
+
+            column_count: 0,
+            column_width: Default::default(),
+            column_fill: Default::default(),
+
+            constraints: Box::new(vec![]),
+
             aspect_ratio: Default::default(),
 
+            measure: None,
+
+            text: None,
+
+            algorithm: None,
+
             children: Box::new(vec![]),
         }
     }
@@ -341,17 +1007,93 @@ impl Node {
         }
     }
 
+    // A box clipping its content in either axis is a scroll container, as visible overflow in the
+    // other axis then behaves as auto.
+    pub(crate) fn is_scroll_container(&self) -> bool {
+        self.overflow_x != Overflow::Visible || self.overflow_y != Overflow::Visible
+    }
+
+    pub(crate) fn is_multicol(&self) -> bool {
+        self.column_count > 0 || matches!(self.column_width, Dimension::Points(_) | Dimension::Percent(_))
+    }
+
+    // The space reserved for scrollbars. Scrolling in the block axis reserves space for a scrollbar at
+    // the inline-end edge, or at both inline edges for a gutter on both edges, when the scrollbar is
+    // always shown or the gutter is stable. Scrolling in the inline axis reserves space at the bottom.
+    pub(crate) fn scrollbar_gutter(&self) -> Rect<f32> {
+        let vertical = self.overflow_y == Overflow::Scroll
+            || (self.is_scroll_container() && self.scrollbar_gutter != ScrollbarGutter::Auto);
+        let both_edges = vertical && self.scrollbar_gutter == ScrollbarGutter::StableBothEdges;
+        let horizontal = self.overflow_x == Overflow::Scroll;
+
+        Rect {
+            start: if both_edges { self.scrollbar_width } else { 0.0 },
+            end: if vertical { self.scrollbar_width } else { 0.0 },
+            top: 0.0,
+            bottom: if horizontal { self.scrollbar_width } else { 0.0 },
+        }
+    }
+
+    pub(crate) fn direction(&self, parent_direction: Direction) -> Direction {
+        match self.direction {
+            Direction::Inherit => parent_direction,
+            direction => direction,
+        }
+    }
+
     pub(crate) fn align_self(&self, parent: &Node) -> AlignSelf {
-        if self.align_self == AlignSelf::Auto {
+        let align_self = if self.align_self == AlignSelf::Auto {
             match parent.align_items {
                 AlignItems::FlexStart => AlignSelf::FlexStart,
                 AlignItems::FlexEnd => AlignSelf::FlexEnd,
                 AlignItems::Center => AlignSelf::Center,
                 AlignItems::Baseline => AlignSelf::Baseline,
                 AlignItems::Stretch => AlignSelf::Stretch,
+                AlignItems::Start => AlignSelf::Start,
+                AlignItems::End => AlignSelf::End,
+                AlignItems::Normal => AlignSelf::Normal,
+                AlignItems::LastBaseline => AlignSelf::LastBaseline,
             }
         } else {
             self.align_self
+        };
+
+        // Flex items with normal alignment are stretched.
+        if align_self == AlignSelf::Normal {
+            AlignSelf::Stretch
+        } else {
+            align_self
+        }
+    }
+
+    pub(crate) fn align_self_safety(&self, parent: &Node) -> AlignmentSafety {
+        if self.align_self == AlignSelf::Auto {
+            parent.align_items_safety
+        } else {
+            self.align_self_safety
+        }
+    }
+
+    // Content distribution in the cross axis behaves as stretch when normal.
+    pub(crate) fn align_content(&self) -> AlignContent {
+        if self.align_content == AlignContent::Normal {
+            AlignContent::Stretch
+        } else {
+            self.align_content
+        }
+    }
+
+    // Resolves justify-content to a value the flex layout algorithm handles. Normal and stretch
+    // behave as flex-start, left and right are physical directions which depend on the direction
+    // of a row and behave as start in a column.
+    pub(crate) fn justify_content(&self, direction: Direction) -> JustifyContent {
+        match (self.justify_content, self.flex_direction.is_row(), direction) {
+            (JustifyContent::Normal, _, _) | (JustifyContent::Stretch, _, _) => JustifyContent::FlexStart,
+            (JustifyContent::Left, true, Direction::RTL) | (JustifyContent::Right, true, Direction::LTR) => {
+                JustifyContent::End
+            }
+            (JustifyContent::Left, _, _) | (JustifyContent::Right, _, _) => JustifyContent::Start,
+            (justify_content, _, _) => justify_content,
         }
     }
 }
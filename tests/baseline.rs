@@ -0,0 +1,110 @@
+#[cfg(test)]
+mod baseline {
+    #[test]
+    fn baseline_synthesized_from_border_box() {
+        let layout = stretch::compute(&stretch::style::Node {
+            size: stretch::geometry::Size {
+                width: stretch::style::Dimension::Points(100.0000),
+                height: stretch::style::Dimension::Points(50.0000),
+            },
+            ..Default::default()
+        });
+
+        assert_eq!(layout.baseline, 50.0000);
+    }
+
+    #[test]
+    fn baseline_of_first_item_in_column() {
+        let layout = stretch::compute(&stretch::style::Node {
+            flex_direction: stretch::style::FlexDirection::Column,
+            padding: stretch::geometry::Rect { top: stretch::style::Dimension::Points(10.0000), ..Default::default() },
+            children: Box::new(vec![
+                stretch::style::Node {
+                    size: stretch::geometry::Size {
+                        height: stretch::style::Dimension::Points(20.0000),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+                stretch::style::Node {
+                    size: stretch::geometry::Size {
+                        height: stretch::style::Dimension::Points(30.0000),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+            ]),
+            ..Default::default()
+        });
+
+        assert_eq!(layout.size.height, 60.0000);
+        assert_eq!(layout.baseline, 30.0000);
+        assert_eq!(layout.children[0].baseline, 20.0000);
+        assert_eq!(layout.children[1].baseline, 30.0000);
+    }
+
+    #[test]
+    fn baseline_of_baseline_aligned_items() {
+        let layout = stretch::compute(&stretch::style::Node {
+            align_items: stretch::style::AlignItems::FlexStart,
+            children: Box::new(vec![
+                stretch::style::Node {
+                    size: stretch::geometry::Size {
+                        width: stretch::style::Dimension::Points(50.0000),
+                        height: stretch::style::Dimension::Points(80.0000),
+                    },
+                    ..Default::default()
+                },
+                stretch::style::Node {
+                    align_self: stretch::style::AlignSelf::Baseline,
+                    size: stretch::geometry::Size {
+                        width: stretch::style::Dimension::Points(50.0000),
+                        height: stretch::style::Dimension::Points(20.0000),
+                    },
+                    margin: stretch::geometry::Rect {
+                        top: stretch::style::Dimension::Points(5.0000),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+            ]),
+            ..Default::default()
+        });
+
+        assert_eq!(layout.size.height, 80.0000);
+        assert_eq!(layout.baseline, 25.0000);
+        assert_eq!(layout.children[1].location.y, 5.0000);
+    }
+
+    #[test]
+    fn last_baseline_of_last_item_in_column() {
+        let layout = stretch::compute(&stretch::style::Node {
+            flex_direction: stretch::style::FlexDirection::Column,
+            padding: stretch::geometry::Rect {
+                bottom: stretch::style::Dimension::Points(10.0000),
+                ..Default::default()
+            },
+            children: Box::new(vec![
+                stretch::style::Node {
+                    size: stretch::geometry::Size {
+                        height: stretch::style::Dimension::Points(20.0000),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+                stretch::style::Node {
+                    size: stretch::geometry::Size {
+                        height: stretch::style::Dimension::Points(30.0000),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+            ]),
+            ..Default::default()
+        });
+
+        assert_eq!(layout.size.height, 60.0000);
+        assert_eq!(layout.baseline, 20.0000);
+        assert_eq!(layout.last_baseline, 50.0000);
+    }
+}
@@ -0,0 +1,319 @@
+#[cfg(test)]
+mod block {
+    use stretch::geometry::{Rect, Size};
+    use stretch::style::{Dimension, Display, Node};
+
+    #[test]
+    fn block_stacks_children() {
+        let layout = stretch::compute(&Node {
+            display: Display::Block,
+            size: Size { width: Dimension::Points(100.0000), ..Default::default() },
+            children: Box::new(vec![
+                Node {
+                    display: Display::Block,
+                    size: Size { height: Dimension::Points(10.0000), ..Default::default() },
+                    ..Default::default()
+                },
+                Node {
+                    display: Display::Block,
+                    size: Size { height: Dimension::Points(20.0000), ..Default::default() },
+                    ..Default::default()
+                },
+            ]),
+            ..Default::default()
+        });
+
+        assert_eq!(layout.size.width, 100.0000);
+        assert_eq!(layout.size.height, 30.0000);
+
+        assert_eq!(layout.children[0].size.width, 100.0000);
+        assert_eq!(layout.children[0].location.y, 0.0000);
+        assert_eq!(layout.children[1].size.width, 100.0000);
+        assert_eq!(layout.children[1].location.y, 10.0000);
+    }
+
+    #[test]
+    fn block_collapses_sibling_margins() {
+        let layout = stretch::compute(&Node {
+            display: Display::Block,
+            size: Size { width: Dimension::Points(100.0000), ..Default::default() },
+            children: Box::new(vec![
+                Node {
+                    display: Display::Block,
+                    size: Size { height: Dimension::Points(10.0000), ..Default::default() },
+                    margin: Rect { bottom: Dimension::Points(20.0000), ..Default::default() },
+                    ..Default::default()
+                },
+                Node {
+                    display: Display::Block,
+                    size: Size { height: Dimension::Points(10.0000), ..Default::default() },
+                    margin: Rect { top: Dimension::Points(10.0000), ..Default::default() },
+                    ..Default::default()
+                },
+            ]),
+            ..Default::default()
+        });
+
+        assert_eq!(layout.size.height, 40.0000);
+        assert_eq!(layout.children[1].location.y, 30.0000);
+    }
+
+    #[test]
+    fn block_collapses_negative_sibling_margins() {
+        let layout = stretch::compute(&Node {
+            display: Display::Block,
+            size: Size { width: Dimension::Points(100.0000), ..Default::default() },
+            children: Box::new(vec![
+                Node {
+                    display: Display::Block,
+                    size: Size { height: Dimension::Points(10.0000), ..Default::default() },
+                    margin: Rect { bottom: Dimension::Points(20.0000), ..Default::default() },
+                    ..Default::default()
+                },
+                Node {
+                    display: Display::Block,
+                    size: Size { height: Dimension::Points(10.0000), ..Default::default() },
+                    margin: Rect { top: Dimension::Points(-5.0000), ..Default::default() },
+                    ..Default::default()
+                },
+            ]),
+            ..Default::default()
+        });
+
+        assert_eq!(layout.size.height, 35.0000);
+        assert_eq!(layout.children[1].location.y, 25.0000);
+    }
+
+    #[test]
+    fn block_collapses_parent_and_first_child_margins() {
+        let layout = stretch::compute(&Node {
+            display: Display::Block,
+            size: Size { width: Dimension::Points(100.0000), ..Default::default() },
+            children: Box::new(vec![Node {
+                display: Display::Block,
+                margin: Rect { top: Dimension::Points(10.0000), ..Default::default() },
+                children: Box::new(vec![Node {
+                    display: Display::Block,
+                    size: Size { height: Dimension::Points(20.0000), ..Default::default() },
+                    margin: Rect { top: Dimension::Points(30.0000), ..Default::default() },
+                    ..Default::default()
+                }]),
+                ..Default::default()
+            }]),
+            ..Default::default()
+        });
+
+        assert_eq!(layout.size.height, 50.0000);
+
+        assert_eq!(layout.children[0].size.height, 20.0000);
+        assert_eq!(layout.children[0].location.y, 30.0000);
+        assert_eq!(layout.children[0].children[0].location.y, 0.0000);
+    }
+
+    #[test]
+    fn block_padding_separates_parent_and_child_margins() {
+        let layout = stretch::compute(&Node {
+            display: Display::Block,
+            size: Size { width: Dimension::Points(100.0000), ..Default::default() },
+            children: Box::new(vec![Node {
+                display: Display::Block,
+                margin: Rect { top: Dimension::Points(10.0000), ..Default::default() },
+                padding: Rect { top: Dimension::Points(5.0000), ..Default::default() },
+                children: Box::new(vec![Node {
+                    display: Display::Block,
+                    size: Size { height: Dimension::Points(20.0000), ..Default::default() },
+                    margin: Rect { top: Dimension::Points(30.0000), ..Default::default() },
+                    ..Default::default()
+                }]),
+                ..Default::default()
+            }]),
+            ..Default::default()
+        });
+
+        assert_eq!(layout.size.height, 65.0000);
+
+        assert_eq!(layout.children[0].size.height, 55.0000);
+        assert_eq!(layout.children[0].location.y, 10.0000);
+        assert_eq!(layout.children[0].children[0].location.y, 35.0000);
+    }
+
+    #[test]
+    fn block_collapses_parent_and_last_child_margins() {
+        let layout = stretch::compute(&Node {
+            display: Display::Block,
+            size: Size { width: Dimension::Points(100.0000), ..Default::default() },
+            children: Box::new(vec![
+                Node {
+                    display: Display::Block,
+                    children: Box::new(vec![Node {
+                        display: Display::Block,
+                        size: Size { height: Dimension::Points(20.0000), ..Default::default() },
+                        margin: Rect { bottom: Dimension::Points(30.0000), ..Default::default() },
+                        ..Default::default()
+                    }]),
+                    ..Default::default()
+                },
+                Node {
+                    display: Display::Block,
+                    size: Size { height: Dimension::Points(10.0000), ..Default::default() },
+                    margin: Rect { top: Dimension::Points(10.0000), ..Default::default() },
+                    ..Default::default()
+                },
+            ]),
+            ..Default::default()
+        });
+
+        assert_eq!(layout.size.height, 60.0000);
+
+        assert_eq!(layout.children[0].size.height, 20.0000);
+        assert_eq!(layout.children[1].location.y, 50.0000);
+    }
+
+    #[test]
+    fn block_empty_child_collapses_through() {
+        let layout = stretch::compute(&Node {
+            display: Display::Block,
+            size: Size { width: Dimension::Points(100.0000), ..Default::default() },
+            children: Box::new(vec![
+                Node {
+                    display: Display::Block,
+                    size: Size { height: Dimension::Points(10.0000), ..Default::default() },
+                    margin: Rect { bottom: Dimension::Points(10.0000), ..Default::default() },
+                    ..Default::default()
+                },
+                Node {
+                    display: Display::Block,
+                    margin: Rect {
+                        top: Dimension::Points(20.0000),
+                        bottom: Dimension::Points(5.0000),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+                Node {
+                    display: Display::Block,
+                    size: Size { height: Dimension::Points(10.0000), ..Default::default() },
+                    margin: Rect { top: Dimension::Points(15.0000), ..Default::default() },
+                    ..Default::default()
+                },
+            ]),
+            ..Default::default()
+        });
+
+        assert_eq!(layout.size.height, 40.0000);
+
+        assert_eq!(layout.children[1].size.height, 0.0000);
+        assert_eq!(layout.children[2].location.y, 30.0000);
+    }
+
+    #[test]
+    fn block_auto_margins_center_child() {
+        let layout = stretch::compute(&Node {
+            display: Display::Block,
+            size: Size { width: Dimension::Points(100.0000), ..Default::default() },
+            children: Box::new(vec![Node {
+                display: Display::Block,
+                size: Size { width: Dimension::Points(50.0000), height: Dimension::Points(10.0000) },
+                margin: Rect { start: Dimension::Auto, end: Dimension::Auto, ..Default::default() },
+                ..Default::default()
+            }]),
+            ..Default::default()
+        });
+
+        assert_eq!(layout.children[0].size.width, 50.0000);
+        assert_eq!(layout.children[0].location.x, 25.0000);
+    }
+
+    #[test]
+    fn block_shrinks_to_fit_without_width() {
+        let layout = stretch::compute(&Node {
+            display: Display::Block,
+            children: Box::new(vec![
+                Node {
+                    display: Display::Block,
+                    size: Size { width: Dimension::Points(50.0000), height: Dimension::Points(10.0000) },
+                    ..Default::default()
+                },
+                Node {
+                    display: Display::Block,
+                    measure: Some(Box::new(|known, _| Size {
+                        width: match known.width {
+                            stretch::number::Number::Defined(width) => width,
+                            stretch::number::Number::Undefined => 30.0,
+                        },
+                        height: 10.0,
+                    })),
+                    ..Default::default()
+                },
+            ]),
+            ..Default::default()
+        });
+
+        assert_eq!(layout.size.width, 50.0000);
+        assert_eq!(layout.size.height, 20.0000);
+
+        assert_eq!(layout.children[1].size.width, 50.0000);
+        assert_eq!(layout.children[1].location.y, 10.0000);
+    }
+
+    #[test]
+    fn block_absolute_child_at_static_position() {
+        let layout = stretch::compute(&Node {
+            display: Display::Block,
+            size: Size { width: Dimension::Points(100.0000), height: Dimension::Points(100.0000) },
+            children: Box::new(vec![
+                Node {
+                    display: Display::Block,
+                    size: Size { height: Dimension::Points(10.0000), ..Default::default() },
+                    margin: Rect { bottom: Dimension::Points(5.0000), ..Default::default() },
+                    ..Default::default()
+                },
+                Node {
+                    position_type: stretch::style::PositionType::Absolute,
+                    size: Size { width: Dimension::Points(10.0000), height: Dimension::Points(10.0000) },
+                    ..Default::default()
+                },
+            ]),
+            ..Default::default()
+        });
+
+        assert_eq!(layout.children[1].location.x, 0.0000);
+        assert_eq!(layout.children[1].location.y, 15.0000);
+    }
+
+    #[test]
+    fn block_in_flex_and_flex_in_block() {
+        let layout = stretch::compute(&Node {
+            size: Size { width: Dimension::Points(200.0000), ..Default::default() },
+            children: Box::new(vec![Node {
+                display: Display::Block,
+                margin: Rect { top: Dimension::Points(10.0000), ..Default::default() },
+                children: Box::new(vec![
+                    Node {
+                        size: Size { width: Dimension::Points(80.0000), height: Dimension::Points(10.0000) },
+                        margin: Rect { bottom: Dimension::Points(10.0000), ..Default::default() },
+                        ..Default::default()
+                    },
+                    Node {
+                        children: Box::new(vec![Node {
+                            size: Size { width: Dimension::Points(30.0000), height: Dimension::Points(20.0000) },
+                            ..Default::default()
+                        }]),
+                        ..Default::default()
+                    },
+                ]),
+                ..Default::default()
+            }]),
+            ..Default::default()
+        });
+
+        assert_eq!(layout.size.height, 50.0000);
+
+        assert_eq!(layout.children[0].size.width, 80.0000);
+        assert_eq!(layout.children[0].size.height, 40.0000);
+        assert_eq!(layout.children[0].location.y, 10.0000);
+
+        assert_eq!(layout.children[0].children[1].size.width, 80.0000);
+        assert_eq!(layout.children[0].children[1].location.y, 20.0000);
+    }
+}
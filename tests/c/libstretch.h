@@ -9,6 +9,10 @@ typedef enum {
     ALIGN_CONTENT_STRETCH,
     ALIGN_CONTENT_SPACE_BETWEEN,
     ALIGN_CONTENT_SPACE_AROUND,
+    ALIGN_CONTENT_SPACE_EVENLY,
+    ALIGN_CONTENT_START,
+    ALIGN_CONTENT_END,
+    ALIGN_CONTENT_NORMAL,
 } AlignContent;
 
 typedef enum {
@@ -17,6 +21,10 @@ typedef enum {
     ALIGN_ITEMS_CENTER,
     ALIGN_ITEMS_BASELINE,
     ALIGN_ITEMS_STRETCH,
+    ALIGN_ITEMS_START,
+    ALIGN_ITEMS_END,
+    ALIGN_ITEMS_NORMAL,
+    ALIGN_ITEMS_LAST_BASELINE,
 } AlignItems;
 
 typedef enum {
@@ -26,8 +34,29 @@ typedef enum {
     ALIGN_SELF_CENTER,
     ALIGN_SELF_BASELINE,
     ALIGN_SELF_STRETCH,
+    ALIGN_SELF_START,
+    ALIGN_SELF_END,
+    ALIGN_SELF_NORMAL,
+    ALIGN_SELF_LAST_BASELINE,
 } AlignSelf;
 
+typedef enum {
+    ALIGNMENT_SAFETY_UNSAFE,
+    ALIGNMENT_SAFETY_SAFE,
+} AlignmentSafety;
+
+typedef enum {
+    CLEAR_NONE,
+    CLEAR_LEFT,
+    CLEAR_RIGHT,
+    CLEAR_BOTH,
+} Clear;
+
+typedef enum {
+    COLUMN_FILL_BALANCE,
+    COLUMN_FILL_AUTO,
+} ColumnFill;
+
 typedef enum {
     DIRECTION_INHERIT,
     DIRECTION_L_T_R,
@@ -37,6 +66,20 @@ typedef enum {
 typedef enum {
     DISPLAY_FLEX,
     DISPLAY_NONE,
+    DISPLAY_GRID,
+    DISPLAY_BLOCK,
+    DISPLAY_INLINE,
+    DISPLAY_INLINE_BLOCK,
+    DISPLAY_INLINE_FLEX,
+    DISPLAY_CONTENTS,
+    DISPLAY_TABLE,
+    DISPLAY_TABLE_ROW_GROUP,
+    DISPLAY_TABLE_ROW,
+    DISPLAY_TABLE_CELL,
+    DISPLAY_TABLE_COLUMN,
+    DISPLAY_MASONRY,
+    DISPLAY_CONSTRAINTS,
+    DISPLAY_STACK,
 } Display;
 
 typedef enum {
@@ -52,6 +95,19 @@ typedef enum {
     FLEX_WRAP_WRAP_REVERSE,
 } FlexWrap;
 
+typedef enum {
+    FLOAT_NONE,
+    FLOAT_LEFT,
+    FLOAT_RIGHT,
+} Float;
+
+typedef enum {
+    GRID_AUTO_FLOW_ROW,
+    GRID_AUTO_FLOW_COLUMN,
+    GRID_AUTO_FLOW_ROW_DENSE,
+    GRID_AUTO_FLOW_COLUMN_DENSE,
+} GridAutoFlow;
+
 typedef enum {
     JUSTIFY_CONTENT_FLEX_START,
     JUSTIFY_CONTENT_FLEX_END,
@@ -59,8 +115,30 @@ typedef enum {
     JUSTIFY_CONTENT_SPACE_BETWEEN,
     JUSTIFY_CONTENT_SPACE_AROUND,
     JUSTIFY_CONTENT_SPACE_EVENLY,
+    JUSTIFY_CONTENT_START,
+    JUSTIFY_CONTENT_END,
+    JUSTIFY_CONTENT_LEFT,
+    JUSTIFY_CONTENT_RIGHT,
+    JUSTIFY_CONTENT_STRETCH,
+    JUSTIFY_CONTENT_NORMAL,
 } JustifyContent;
 
+typedef enum {
+    JUSTIFY_SELF_AUTO,
+    JUSTIFY_SELF_START,
+    JUSTIFY_SELF_END,
+    JUSTIFY_SELF_CENTER,
+    JUSTIFY_SELF_LEFT,
+    JUSTIFY_SELF_RIGHT,
+    JUSTIFY_SELF_STRETCH,
+    JUSTIFY_SELF_NORMAL,
+} JustifySelf;
+
+typedef enum {
+    MASONRY_AUTO_FLOW_PACK,
+    MASONRY_AUTO_FLOW_NEXT,
+} MasonryAutoFlow;
+
 typedef enum {
     OVERFLOW_VISIBLE,
     OVERFLOW_HIDDEN,
@@ -72,6 +150,32 @@ typedef enum {
     POSITION_TYPE_ABSOLUTE,
 } PositionType;
 
+typedef enum {
+    SCROLLBAR_GUTTER_AUTO,
+    SCROLLBAR_GUTTER_STABLE,
+    SCROLLBAR_GUTTER_STABLE_BOTH_EDGES,
+} ScrollbarGutter;
+
+typedef enum {
+    TABLE_LAYOUT_AUTO,
+    TABLE_LAYOUT_FIXED,
+} TableLayout;
+
+typedef enum {
+    TEXT_ALIGN_START,
+    TEXT_ALIGN_END,
+    TEXT_ALIGN_LEFT,
+    TEXT_ALIGN_RIGHT,
+    TEXT_ALIGN_CENTER,
+    TEXT_ALIGN_JUSTIFY,
+} TextAlign;
+
+typedef enum {
+    VISIBILITY_VISIBLE,
+    VISIBILITY_HIDDEN,
+    VISIBILITY_COLLAPSE,
+} Visibility;
+
 typedef enum {
     UNIT_UNDEFINED,
     UNIT_AUTO,
@@ -106,28 +210,92 @@ typedef struct {
     float value;
 } Number;
 
+typedef enum {
+    GRID_PLACEMENT_AUTO,
+    GRID_PLACEMENT_LINE,
+    GRID_PLACEMENT_SPAN,
+} GRID_PLACEMENT;
+
+typedef struct {
+    GRID_PLACEMENT placement;
+    int16_t value;
+} GridPlacement;
+
+typedef struct {
+    GridPlacement start;
+    GridPlacement end;
+} Line_GridPlacement;
+
+typedef struct {
+    const void *data;
+    const void *vtable;
+} MeasureFunc;
+
+typedef struct {
+    const void *data;
+    const void *vtable;
+} TextFunc;
+
+typedef struct {
+    const void *data;
+    const void *vtable;
+} LayoutFunc;
+
 typedef struct {
     Display display;
     PositionType position_type;
+    Float float_;
+    Clear clear;
     Direction direction;
     FlexDirection flex_direction;
     FlexWrap flex_wrap;
-    Overflow overflow;
+    Overflow overflow_x;
+    Overflow overflow_y;
+    float scrollbar_width;
+    ScrollbarGutter scrollbar_gutter;
+    Visibility visibility;
     AlignItems align_items;
+    AlignmentSafety align_items_safety;
     AlignSelf align_self;
+    AlignmentSafety align_self_safety;
     AlignContent align_content;
+    AlignmentSafety align_content_safety;
     JustifyContent justify_content;
+    AlignmentSafety justify_content_safety;
+    JustifySelf justify_self;
+    TextAlign text_align;
     Rect_Dimension position;
     Rect_Dimension margin;
     Rect_Dimension padding;
     Rect_Dimension border;
+    int32_t order;
     float flex_grow;
     float flex_shrink;
     Dimension flex_basis;
     Size_Dimension size;
     Size_Dimension min_size;
     Size_Dimension max_size;
+    Size_Dimension gap;
+    void *grid_template_rows;
+    void *grid_template_columns;
+    void *grid_auto_rows;
+    void *grid_auto_columns;
+    GridAutoFlow grid_auto_flow;
+    MasonryAutoFlow masonry_auto_flow;
+    Line_GridPlacement grid_row;
+    Line_GridPlacement grid_column;
+    TableLayout table_layout;
+    Size_Dimension border_spacing;
+    uint16_t row_span;
+    uint16_t column_span;
+    uint16_t column_count;
+    Dimension column_width;
+    ColumnFill column_fill;
+    void *constraints;
     Number aspect_ratio;
+    MeasureFunc measure;
+    TextFunc text;
+    LayoutFunc algorithm;
     void *children;
 } StyleNode;
 
@@ -144,7 +312,11 @@ typedef struct {
 typedef struct {
     uint32_t order;
     Size_f32 size;
+    Size_f32 content_size;
     Point_f32 location;
+    float baseline;
+    float last_baseline;
+    bool visible;
     void *children;
 } LayoutNode;
 
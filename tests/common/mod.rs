@@ -0,0 +1,19 @@
+use stretch::geometry::Size;
+use stretch::number::{AvailableSpace, Number};
+
+// Lays out 30 words of 10 points width in lines of 10 points height, wrapping at the known or available width.
+pub fn text(known: Size<Number>, available: Size<AvailableSpace>) -> Size<f32> {
+    let width = match (known.width, available.width) {
+        (Number::Defined(width), _) => width,
+        (_, AvailableSpace::Definite(width)) => width.clamp(10.0, 300.0),
+        (_, AvailableSpace::MinContent) => 10.0,
+        (_, AvailableSpace::MaxContent) => 300.0,
+    };
+
+    let height = match known.height {
+        Number::Defined(height) => height,
+        Number::Undefined => (30.0 / (width / 10.0).floor().max(1.0)).ceil() * 10.0,
+    };
+
+    Size { width, height }
+}
@@ -0,0 +1,211 @@
+#[cfg(test)]
+mod constraints {
+    use stretch::geometry::Size;
+    use stretch::style::{
+        Constraint, ConstraintAttribute, ConstraintItem, ConstraintRelation, ConstraintStrength, ConstraintVariable,
+        Dimension, Display, Node,
+    };
+
+    #[test]
+    fn constraints_place_relative_to_sibling() {
+        let layout = stretch::compute(&Node {
+            display: Display::Constraints,
+            size: Size { width: Dimension::Points(200.0000), height: Dimension::Points(100.0000) },
+            children: Box::new(vec![
+                Node {
+                    size: Size { width: Dimension::Points(50.0000), height: Dimension::Points(20.0000) },
+                    ..Default::default()
+                },
+                Node {
+                    size: Size { width: Dimension::Points(30.0000), height: Dimension::Points(20.0000) },
+                    ..Default::default()
+                },
+            ]),
+            constraints: Box::new(vec![
+                Constraint::new(
+                    ConstraintVariable::new(ConstraintItem::Child(0), ConstraintAttribute::Top),
+                    ConstraintRelation::Equal,
+                    10.0,
+                ),
+                Constraint::new(
+                    ConstraintVariable::new(ConstraintItem::Child(1), ConstraintAttribute::Left),
+                    ConstraintRelation::Equal,
+                    ConstraintVariable::new(ConstraintItem::Child(0), ConstraintAttribute::Right) + 8.0,
+                ),
+                Constraint::new(
+                    ConstraintVariable::new(ConstraintItem::Child(1), ConstraintAttribute::Top),
+                    ConstraintRelation::Equal,
+                    ConstraintVariable::new(ConstraintItem::Child(0), ConstraintAttribute::Bottom),
+                ),
+            ]),
+            ..Default::default()
+        });
+
+        assert_eq!(layout.size.width, 200.0000);
+        assert_eq!(layout.size.height, 100.0000);
+
+        assert_eq!(layout.children[0].location.x, 0.0000);
+        assert_eq!(layout.children[0].location.y, 10.0000);
+        assert_eq!(layout.children[1].location.x, 58.0000);
+        assert_eq!(layout.children[1].location.y, 30.0000);
+        assert_eq!(layout.children[1].size.width, 30.0000);
+    }
+
+    #[test]
+    fn constraints_center_in_parent() {
+        let layout = stretch::compute(&Node {
+            display: Display::Constraints,
+            size: Size { width: Dimension::Points(200.0000), height: Dimension::Points(100.0000) },
+            children: Box::new(vec![Node {
+                size: Size { width: Dimension::Points(40.0000), height: Dimension::Points(20.0000) },
+                ..Default::default()
+            }]),
+            constraints: Box::new(vec![
+                Constraint::new(
+                    ConstraintVariable::new(ConstraintItem::Child(0), ConstraintAttribute::CenterX),
+                    ConstraintRelation::Equal,
+                    ConstraintVariable::new(ConstraintItem::Parent, ConstraintAttribute::CenterX),
+                ),
+                Constraint::new(
+                    ConstraintVariable::new(ConstraintItem::Child(0), ConstraintAttribute::CenterY),
+                    ConstraintRelation::Equal,
+                    ConstraintVariable::new(ConstraintItem::Parent, ConstraintAttribute::CenterY),
+                ),
+            ]),
+            ..Default::default()
+        });
+
+        assert_eq!(layout.children[0].location.x, 80.0000);
+        assert_eq!(layout.children[0].location.y, 40.0000);
+    }
+
+    #[test]
+    fn constraints_inequality_relative_to_parent() {
+        let layout = stretch::compute(&Node {
+            display: Display::Constraints,
+            size: Size { width: Dimension::Points(200.0000), height: Dimension::Points(100.0000) },
+            children: Box::new(vec![Node::default(), Node::default()]),
+            constraints: Box::new(vec![
+                Constraint::new(
+                    ConstraintVariable::new(ConstraintItem::Child(0), ConstraintAttribute::Width),
+                    ConstraintRelation::GreaterOrEqual,
+                    ConstraintVariable::new(ConstraintItem::Parent, ConstraintAttribute::Width) * 0.5,
+                ),
+                Constraint::new(
+                    ConstraintVariable::new(ConstraintItem::Child(1), ConstraintAttribute::Width),
+                    ConstraintRelation::Equal,
+                    150.0,
+                )
+                .with_strength(ConstraintStrength::Strong),
+                Constraint::new(
+                    ConstraintVariable::new(ConstraintItem::Child(1), ConstraintAttribute::Width),
+                    ConstraintRelation::LessOrEqual,
+                    120.0,
+                ),
+            ]),
+            ..Default::default()
+        });
+
+        assert_eq!(layout.children[0].size.width, 100.0000);
+        assert_eq!(layout.children[1].size.width, 120.0000);
+    }
+
+    #[test]
+    fn constraints_stronger_takes_precedence() {
+        let layout = stretch::compute(&Node {
+            display: Display::Constraints,
+            size: Size { width: Dimension::Points(200.0000), height: Dimension::Points(100.0000) },
+            children: Box::new(vec![Node {
+                size: Size { width: Dimension::Points(10.0000), height: Dimension::Points(10.0000) },
+                ..Default::default()
+            }]),
+            constraints: Box::new(vec![
+                Constraint::new(
+                    ConstraintVariable::new(ConstraintItem::Child(0), ConstraintAttribute::Left),
+                    ConstraintRelation::Equal,
+                    10.0,
+                )
+                .with_strength(ConstraintStrength::Weak),
+                Constraint::new(
+                    ConstraintVariable::new(ConstraintItem::Child(0), ConstraintAttribute::Left),
+                    ConstraintRelation::Equal,
+                    30.0,
+                )
+                .with_strength(ConstraintStrength::Strong),
+                Constraint::new(
+                    ConstraintVariable::new(ConstraintItem::Child(0), ConstraintAttribute::Left),
+                    ConstraintRelation::Equal,
+                    20.0,
+                )
+                .with_strength(ConstraintStrength::Medium),
+            ]),
+            ..Default::default()
+        });
+
+        assert_eq!(layout.children[0].location.x, 30.0000);
+    }
+
+    #[test]
+    fn constraints_conflicting_required_constraint_is_ignored() {
+        let layout = stretch::compute(&Node {
+            display: Display::Constraints,
+            size: Size { width: Dimension::Points(200.0000), height: Dimension::Points(100.0000) },
+            children: Box::new(vec![Node {
+                size: Size { width: Dimension::Points(50.0000), height: Dimension::Points(20.0000) },
+                ..Default::default()
+            }]),
+            constraints: Box::new(vec![
+                Constraint::new(
+                    ConstraintVariable::new(ConstraintItem::Child(0), ConstraintAttribute::Width),
+                    ConstraintRelation::Equal,
+                    80.0,
+                ),
+                Constraint::new(
+                    ConstraintVariable::new(ConstraintItem::Child(0), ConstraintAttribute::Right),
+                    ConstraintRelation::Equal,
+                    100.0,
+                ),
+            ]),
+            ..Default::default()
+        });
+
+        assert_eq!(layout.children[0].size.width, 50.0000);
+        assert_eq!(layout.children[0].location.x, 50.0000);
+    }
+
+    #[test]
+    fn constraints_size_container() {
+        let layout = stretch::compute(&Node {
+            display: Display::Constraints,
+            padding: stretch::geometry::Rect {
+                start: Dimension::Points(5.0000),
+                end: Dimension::Points(5.0000),
+                top: Dimension::Points(5.0000),
+                bottom: Dimension::Points(5.0000),
+            },
+            children: Box::new(vec![Node {
+                size: Size { width: Dimension::Points(50.0000), height: Dimension::Points(20.0000) },
+                ..Default::default()
+            }]),
+            constraints: Box::new(vec![
+                Constraint::new(
+                    ConstraintVariable::new(ConstraintItem::Parent, ConstraintAttribute::Right),
+                    ConstraintRelation::GreaterOrEqual,
+                    ConstraintVariable::new(ConstraintItem::Child(0), ConstraintAttribute::Right) + 10.0,
+                ),
+                Constraint::new(
+                    ConstraintVariable::new(ConstraintItem::Parent, ConstraintAttribute::Bottom),
+                    ConstraintRelation::GreaterOrEqual,
+                    ConstraintVariable::new(ConstraintItem::Child(0), ConstraintAttribute::Bottom) + 5.0,
+                ),
+            ]),
+            ..Default::default()
+        });
+
+        assert_eq!(layout.size.width, 65.0000);
+        assert_eq!(layout.size.height, 30.0000);
+
+        assert_eq!(layout.children[0].location.x, 5.0000);
+        assert_eq!(layout.children[0].location.y, 5.0000);
+    }
+}
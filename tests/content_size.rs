@@ -0,0 +1,212 @@
+#[cfg(test)]
+mod content_size {
+    #[test]
+    fn content_size_includes_margin_and_end_padding() {
+        let layout = stretch::compute(&stretch::style::Node {
+            flex_direction: stretch::style::FlexDirection::Column,
+            overflow_y: stretch::style::Overflow::Scroll,
+            size: stretch::geometry::Size {
+                width: stretch::style::Dimension::Points(100.0000),
+                height: stretch::style::Dimension::Points(50.0000),
+            },
+            padding: stretch::geometry::Rect {
+                start: stretch::style::Dimension::Points(10.0000),
+                end: stretch::style::Dimension::Points(10.0000),
+                top: stretch::style::Dimension::Points(10.0000),
+                bottom: stretch::style::Dimension::Points(10.0000),
+            },
+            children: Box::new(vec![
+                stretch::style::Node {
+                    flex_shrink: 0.0,
+                    size: stretch::geometry::Size {
+                        height: stretch::style::Dimension::Points(30.0000),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+                stretch::style::Node {
+                    flex_shrink: 0.0,
+                    size: stretch::geometry::Size {
+                        height: stretch::style::Dimension::Points(30.0000),
+                        ..Default::default()
+                    },
+                    margin: stretch::geometry::Rect {
+                        bottom: stretch::style::Dimension::Points(5.0000),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+            ]),
+            ..Default::default()
+        });
+
+        assert_eq!(layout.size.width, 100.0000);
+        assert_eq!(layout.size.height, 50.0000);
+        assert_eq!(layout.content_size.width, 100.0000);
+        assert_eq!(layout.content_size.height, 85.0000);
+
+        assert_eq!(layout.children[1].location.y, 40.0000);
+        assert_eq!(layout.children[1].content_size.height, 30.0000);
+    }
+
+    #[test]
+    fn content_size_includes_absolute_descendants() {
+        let layout = stretch::compute(&stretch::style::Node {
+            size: stretch::geometry::Size {
+                width: stretch::style::Dimension::Points(100.0000),
+                height: stretch::style::Dimension::Points(100.0000),
+            },
+            children: Box::new(vec![stretch::style::Node {
+                size: stretch::geometry::Size {
+                    width: stretch::style::Dimension::Points(50.0000),
+                    height: stretch::style::Dimension::Points(50.0000),
+                },
+                children: Box::new(vec![stretch::style::Node {
+                    position_type: stretch::style::PositionType::Absolute,
+                    size: stretch::geometry::Size {
+                        width: stretch::style::Dimension::Points(40.0000),
+                        height: stretch::style::Dimension::Points(40.0000),
+                    },
+                    position: stretch::geometry::Rect {
+                        start: stretch::style::Dimension::Points(80.0000),
+                        top: stretch::style::Dimension::Points(90.0000),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                }]),
+                ..Default::default()
+            }]),
+            ..Default::default()
+        });
+
+        assert_eq!(layout.content_size.width, 120.0000);
+        assert_eq!(layout.content_size.height, 130.0000);
+
+        assert_eq!(layout.children[0].content_size.width, 120.0000);
+        assert_eq!(layout.children[0].content_size.height, 130.0000);
+    }
+
+    #[test]
+    fn content_size_of_scroll_container_is_clipped() {
+        let layout = stretch::compute(&stretch::style::Node {
+            size: stretch::geometry::Size {
+                width: stretch::style::Dimension::Points(100.0000),
+                height: stretch::style::Dimension::Points(100.0000),
+            },
+            children: Box::new(vec![stretch::style::Node {
+                overflow_x: stretch::style::Overflow::Hidden,
+                overflow_y: stretch::style::Overflow::Hidden,
+                size: stretch::geometry::Size {
+                    width: stretch::style::Dimension::Points(50.0000),
+                    height: stretch::style::Dimension::Points(50.0000),
+                },
+                children: Box::new(vec![stretch::style::Node {
+                    flex_shrink: 0.0,
+                    size: stretch::geometry::Size {
+                        width: stretch::style::Dimension::Points(200.0000),
+                        height: stretch::style::Dimension::Points(200.0000),
+                    },
+                    ..Default::default()
+                }]),
+                ..Default::default()
+            }]),
+            ..Default::default()
+        });
+
+        assert_eq!(layout.content_size.width, 100.0000);
+        assert_eq!(layout.content_size.height, 100.0000);
+
+        assert_eq!(layout.children[0].content_size.width, 200.0000);
+        assert_eq!(layout.children[0].content_size.height, 200.0000);
+    }
+
+    #[test]
+    fn content_size_of_rtl_container_overflows_to_start() {
+        let layout = stretch::compute(&stretch::style::Node {
+            direction: stretch::style::Direction::RTL,
+            size: stretch::geometry::Size {
+                width: stretch::style::Dimension::Points(100.0000),
+                height: stretch::style::Dimension::Points(100.0000),
+            },
+            children: Box::new(vec![stretch::style::Node {
+                flex_shrink: 0.0,
+                size: stretch::geometry::Size {
+                    width: stretch::style::Dimension::Points(150.0000),
+                    height: stretch::style::Dimension::Points(50.0000),
+                },
+                ..Default::default()
+            }]),
+            ..Default::default()
+        });
+
+        assert_eq!(layout.content_size.width, 150.0000);
+        assert_eq!(layout.content_size.height, 100.0000);
+
+        assert_eq!(layout.children[0].location.x, -50.0000);
+    }
+
+    #[test]
+    fn content_size_excludes_overflow_of_rtl_child_in_ltr_container() {
+        let layout = stretch::compute(&stretch::style::Node {
+            size: stretch::geometry::Size {
+                width: stretch::style::Dimension::Points(100.0000),
+                height: stretch::style::Dimension::Points(100.0000),
+            },
+            children: Box::new(vec![stretch::style::Node {
+                direction: stretch::style::Direction::RTL,
+                size: stretch::geometry::Size {
+                    width: stretch::style::Dimension::Points(100.0000),
+                    height: stretch::style::Dimension::Points(50.0000),
+                },
+                children: Box::new(vec![stretch::style::Node {
+                    flex_shrink: 0.0,
+                    size: stretch::geometry::Size {
+                        width: stretch::style::Dimension::Points(150.0000),
+                        height: stretch::style::Dimension::Points(80.0000),
+                    },
+                    ..Default::default()
+                }]),
+                ..Default::default()
+            }]),
+            ..Default::default()
+        });
+
+        assert_eq!(layout.content_size.width, 100.0000);
+        assert_eq!(layout.content_size.height, 100.0000);
+
+        assert_eq!(layout.children[0].content_size.width, 150.0000);
+        assert_eq!(layout.children[0].children[0].location.x, -50.0000);
+    }
+
+    #[test]
+    fn content_size_includes_overflow_of_rtl_child_in_rtl_container() {
+        let layout = stretch::compute(&stretch::style::Node {
+            direction: stretch::style::Direction::RTL,
+            size: stretch::geometry::Size {
+                width: stretch::style::Dimension::Points(100.0000),
+                height: stretch::style::Dimension::Points(100.0000),
+            },
+            children: Box::new(vec![stretch::style::Node {
+                size: stretch::geometry::Size {
+                    width: stretch::style::Dimension::Points(100.0000),
+                    height: stretch::style::Dimension::Points(50.0000),
+                },
+                children: Box::new(vec![stretch::style::Node {
+                    flex_shrink: 0.0,
+                    size: stretch::geometry::Size {
+                        width: stretch::style::Dimension::Points(150.0000),
+                        height: stretch::style::Dimension::Points(50.0000),
+                    },
+                    ..Default::default()
+                }]),
+                ..Default::default()
+            }]),
+            ..Default::default()
+        });
+
+        assert_eq!(layout.content_size.width, 150.0000);
+
+        assert_eq!(layout.children[0].content_size.width, 150.0000);
+        assert_eq!(layout.children[0].children[0].location.x, -50.0000);
+    }
+}
@@ -0,0 +1,426 @@
+#[cfg(test)]
+mod contents {
+    use stretch::geometry::Size;
+    use stretch::style::{
+        Constraint, ConstraintAttribute, ConstraintItem, ConstraintRelation, ConstraintVariable, Dimension, Display,
+        GridTrack, JustifyContent, Node, TrackSizingFunction,
+    };
+
+    #[test]
+    fn contents_children_are_flex_items_of_parent() {
+        let layout = stretch::compute(&Node {
+            size: Size { width: Dimension::Points(100.0000), ..Default::default() },
+            children: Box::new(vec![
+                Node {
+                    size: Size { width: Dimension::Points(20.0000), height: Dimension::Points(10.0000) },
+                    ..Default::default()
+                },
+                Node {
+                    display: Display::Contents,
+                    children: Box::new(vec![
+                        Node {
+                            flex_grow: 1.0,
+                            size: Size { width: Dimension::Points(0.0000), height: Dimension::Points(10.0000) },
+                            ..Default::default()
+                        },
+                        Node {
+                            size: Size { width: Dimension::Points(30.0000), height: Dimension::Points(10.0000) },
+                            ..Default::default()
+                        },
+                    ]),
+                    ..Default::default()
+                },
+            ]),
+            ..Default::default()
+        });
+
+        assert_eq!(layout.children.len(), 2);
+
+        assert_eq!(layout.children[1].size.width, 0.0000);
+        assert_eq!(layout.children[1].size.height, 0.0000);
+        assert_eq!(layout.children[1].location.x, 0.0000);
+        assert_eq!(layout.children[1].location.y, 0.0000);
+
+        assert_eq!(layout.children[1].children[0].size.width, 50.0000);
+        assert_eq!(layout.children[1].children[0].location.x, 20.0000);
+        assert_eq!(layout.children[1].children[1].location.x, 70.0000);
+    }
+
+    #[test]
+    fn contents_children_share_free_space() {
+        let layout = stretch::compute(&Node {
+            justify_content: JustifyContent::SpaceBetween,
+            size: Size { width: Dimension::Points(100.0000), ..Default::default() },
+            children: Box::new(vec![
+                Node {
+                    display: Display::Contents,
+                    children: Box::new(vec![
+                        Node {
+                            size: Size { width: Dimension::Points(10.0000), height: Dimension::Points(10.0000) },
+                            ..Default::default()
+                        },
+                        Node {
+                            size: Size { width: Dimension::Points(10.0000), height: Dimension::Points(10.0000) },
+                            ..Default::default()
+                        },
+                    ]),
+                    ..Default::default()
+                },
+                Node {
+                    size: Size { width: Dimension::Points(10.0000), height: Dimension::Points(10.0000) },
+                    ..Default::default()
+                },
+            ]),
+            ..Default::default()
+        });
+
+        assert_eq!(layout.children[0].children[0].location.x, 0.0000);
+        assert_eq!(layout.children[0].children[1].location.x, 45.0000);
+        assert_eq!(layout.children[1].location.x, 90.0000);
+    }
+
+    #[test]
+    fn nested_contents_keep_indices() {
+        let layout = stretch::compute(&Node {
+            size: Size { width: Dimension::Points(100.0000), ..Default::default() },
+            children: Box::new(vec![Node {
+                display: Display::Contents,
+                children: Box::new(vec![
+                    Node {
+                        display: Display::None,
+                        size: Size { width: Dimension::Points(10.0000), height: Dimension::Points(10.0000) },
+                        ..Default::default()
+                    },
+                    Node {
+                        display: Display::Contents,
+                        children: Box::new(vec![Node {
+                            size: Size { width: Dimension::Points(20.0000), height: Dimension::Points(10.0000) },
+                            ..Default::default()
+                        }]),
+                        ..Default::default()
+                    },
+                    Node {
+                        size: Size { width: Dimension::Points(30.0000), height: Dimension::Points(10.0000) },
+                        ..Default::default()
+                    },
+                ]),
+                ..Default::default()
+            }]),
+            ..Default::default()
+        });
+
+        assert_eq!(layout.children[0].children.len(), 3);
+
+        assert_eq!(layout.children[0].children[0].size.width, 0.0000);
+        assert_eq!(layout.children[0].children[1].children[0].size.width, 20.0000);
+        assert_eq!(layout.children[0].children[1].children[0].location.x, 0.0000);
+        assert_eq!(layout.children[0].children[2].location.x, 20.0000);
+    }
+
+    #[test]
+    fn contents_children_are_grid_items_of_parent() {
+        let layout = stretch::compute(&Node {
+            display: Display::Grid,
+            grid_template_columns: Box::new(vec![GridTrack::Single(TrackSizingFunction::points(50.0)); 3]),
+            children: Box::new(vec![
+                Node { size: Size { height: Dimension::Points(10.0000), ..Default::default() }, ..Default::default() },
+                Node {
+                    display: Display::Contents,
+                    children: Box::new(vec![
+                        Node {
+                            size: Size { height: Dimension::Points(10.0000), ..Default::default() },
+                            ..Default::default()
+                        },
+                        Node {
+                            size: Size { height: Dimension::Points(10.0000), ..Default::default() },
+                            ..Default::default()
+                        },
+                    ]),
+                    ..Default::default()
+                },
+            ]),
+            ..Default::default()
+        });
+
+        assert_eq!(layout.size.width, 150.0000);
+        assert_eq!(layout.size.height, 10.0000);
+
+        assert_eq!(layout.children[1].size.width, 0.0000);
+        assert_eq!(layout.children[1].children[0].size.width, 50.0000);
+        assert_eq!(layout.children[1].children[0].location.x, 50.0000);
+        assert_eq!(layout.children[1].children[1].location.x, 100.0000);
+    }
+
+    #[test]
+    fn contents_children_are_masonry_items_of_parent() {
+        let layout = stretch::compute(&Node {
+            display: Display::Masonry,
+            size: Size { width: Dimension::Points(100.0000), ..Default::default() },
+            grid_template_columns: Box::new(vec![GridTrack::Single(TrackSizingFunction::fr(1.0)); 2]),
+            children: Box::new(vec![
+                Node { size: Size { height: Dimension::Points(30.0000), ..Default::default() }, ..Default::default() },
+                Node {
+                    display: Display::Contents,
+                    children: Box::new(vec![
+                        Node {
+                            size: Size { height: Dimension::Points(10.0000), ..Default::default() },
+                            ..Default::default()
+                        },
+                        Node {
+                            size: Size { height: Dimension::Points(10.0000), ..Default::default() },
+                            ..Default::default()
+                        },
+                    ]),
+                    ..Default::default()
+                },
+            ]),
+            ..Default::default()
+        });
+
+        assert_eq!(layout.size.height, 30.0000);
+
+        assert_eq!(layout.children[1].children[0].location.x, 50.0000);
+        assert_eq!(layout.children[1].children[0].location.y, 0.0000);
+        assert_eq!(layout.children[1].children[1].location.x, 50.0000);
+        assert_eq!(layout.children[1].children[1].location.y, 10.0000);
+    }
+
+    #[test]
+    fn contents_children_are_blocks_of_parent() {
+        let layout = stretch::compute(&Node {
+            display: Display::Block,
+            size: Size { width: Dimension::Points(100.0000), ..Default::default() },
+            children: Box::new(vec![
+                Node {
+                    display: Display::Block,
+                    size: Size { height: Dimension::Points(10.0000), ..Default::default() },
+                    ..Default::default()
+                },
+                Node {
+                    display: Display::Contents,
+                    children: Box::new(vec![
+                        Node {
+                            display: Display::Block,
+                            size: Size { height: Dimension::Points(20.0000), ..Default::default() },
+                            ..Default::default()
+                        },
+                        Node {
+                            display: Display::Block,
+                            size: Size { height: Dimension::Points(30.0000), ..Default::default() },
+                            ..Default::default()
+                        },
+                    ]),
+                    ..Default::default()
+                },
+            ]),
+            ..Default::default()
+        });
+
+        assert_eq!(layout.size.height, 60.0000);
+
+        assert_eq!(layout.children[1].size.height, 0.0000);
+        assert_eq!(layout.children[1].location.y, 0.0000);
+        assert_eq!(layout.children[1].children[0].size.width, 100.0000);
+        assert_eq!(layout.children[1].children[0].location.y, 10.0000);
+        assert_eq!(layout.children[1].children[1].location.y, 30.0000);
+    }
+
+    #[test]
+    fn contents_children_are_rows_and_cells_of_table() {
+        let layout = stretch::compute(&Node {
+            display: Display::Table,
+            children: Box::new(vec![
+                Node {
+                    display: Display::TableRow,
+                    children: Box::new(vec![
+                        Node {
+                            display: Display::TableCell,
+                            size: Size { width: Dimension::Points(30.0000), height: Dimension::Points(10.0000) },
+                            ..Default::default()
+                        },
+                        Node {
+                            display: Display::Contents,
+                            children: Box::new(vec![Node {
+                                display: Display::TableCell,
+                                size: Size { width: Dimension::Points(20.0000), height: Dimension::Points(10.0000) },
+                                ..Default::default()
+                            }]),
+                            ..Default::default()
+                        },
+                    ]),
+                    ..Default::default()
+                },
+                Node {
+                    display: Display::Contents,
+                    children: Box::new(vec![Node {
+                        display: Display::TableRow,
+                        children: Box::new(vec![
+                            Node {
+                                display: Display::TableCell,
+                                size: Size { width: Dimension::Points(10.0000), height: Dimension::Points(10.0000) },
+                                ..Default::default()
+                            },
+                            Node {
+                                display: Display::TableCell,
+                                size: Size { width: Dimension::Points(10.0000), height: Dimension::Points(10.0000) },
+                                ..Default::default()
+                            },
+                        ]),
+                        ..Default::default()
+                    }]),
+                    ..Default::default()
+                },
+            ]),
+            ..Default::default()
+        });
+
+        assert_eq!(layout.size.width, 50.0000);
+        assert_eq!(layout.size.height, 20.0000);
+
+        assert_eq!(layout.children[0].children[1].children[0].size.width, 20.0000);
+        assert_eq!(layout.children[0].children[1].children[0].location.x, 30.0000);
+        assert_eq!(layout.children[1].children[0].size.width, 50.0000);
+        assert_eq!(layout.children[1].children[0].location.y, 10.0000);
+        assert_eq!(layout.children[1].children[0].children[1].location.x, 30.0000);
+    }
+
+    #[test]
+    fn contents_children_are_broken_into_columns() {
+        let layout = stretch::compute(&Node {
+            display: Display::Block,
+            column_count: 2,
+            size: Size { width: Dimension::Points(100.0000), ..Default::default() },
+            children: Box::new(vec![
+                Node {
+                    display: Display::Block,
+                    size: Size { height: Dimension::Points(10.0000), ..Default::default() },
+                    ..Default::default()
+                },
+                Node {
+                    display: Display::Contents,
+                    children: Box::new(vec![
+                        Node {
+                            display: Display::Block,
+                            size: Size { height: Dimension::Points(10.0000), ..Default::default() },
+                            ..Default::default()
+                        },
+                        Node {
+                            display: Display::Block,
+                            size: Size { height: Dimension::Points(10.0000), ..Default::default() },
+                            ..Default::default()
+                        },
+                    ]),
+                    ..Default::default()
+                },
+                Node {
+                    display: Display::Block,
+                    size: Size { height: Dimension::Points(10.0000), ..Default::default() },
+                    ..Default::default()
+                },
+            ]),
+            ..Default::default()
+        });
+
+        assert_eq!(layout.size.height, 20.0000);
+
+        assert_eq!(layout.children[1].children[0].location.x, 0.0000);
+        assert_eq!(layout.children[1].children[0].location.y, 10.0000);
+        assert_eq!(layout.children[1].children[1].location.x, 50.0000);
+        assert_eq!(layout.children[1].children[1].location.y, 0.0000);
+        assert_eq!(layout.children[2].location.x, 50.0000);
+        assert_eq!(layout.children[2].location.y, 10.0000);
+    }
+
+    #[test]
+    fn contents_children_are_indexed_by_constraints() {
+        let layout = stretch::compute(&Node {
+            display: Display::Constraints,
+            size: Size { width: Dimension::Points(200.0000), height: Dimension::Points(100.0000) },
+            children: Box::new(vec![
+                Node {
+                    size: Size { width: Dimension::Points(50.0000), height: Dimension::Points(20.0000) },
+                    ..Default::default()
+                },
+                Node {
+                    display: Display::Contents,
+                    children: Box::new(vec![Node {
+                        size: Size { width: Dimension::Points(30.0000), height: Dimension::Points(20.0000) },
+                        ..Default::default()
+                    }]),
+                    ..Default::default()
+                },
+            ]),
+            constraints: Box::new(vec![Constraint::new(
+                ConstraintVariable::new(ConstraintItem::Child(1), ConstraintAttribute::Left),
+                ConstraintRelation::Equal,
+                ConstraintVariable::new(ConstraintItem::Child(0), ConstraintAttribute::Right) + 8.0,
+            )]),
+            ..Default::default()
+        });
+
+        assert_eq!(layout.children[1].children[0].size.width, 30.0000);
+        assert_eq!(layout.children[1].children[0].location.x, 58.0000);
+    }
+
+    #[test]
+    fn contents_children_are_stacked_in_parent() {
+        let layout = stretch::compute(&Node {
+            display: Display::Stack,
+            children: Box::new(vec![
+                Node {
+                    size: Size { width: Dimension::Points(100.0000), height: Dimension::Points(50.0000) },
+                    ..Default::default()
+                },
+                Node {
+                    display: Display::Contents,
+                    children: Box::new(vec![Node {
+                        size: Size { width: Dimension::Points(30.0000), height: Dimension::Points(80.0000) },
+                        ..Default::default()
+                    }]),
+                    ..Default::default()
+                },
+            ]),
+            ..Default::default()
+        });
+
+        assert_eq!(layout.size.width, 100.0000);
+        assert_eq!(layout.size.height, 80.0000);
+
+        assert_eq!(layout.children[1].children[0].size.width, 30.0000);
+        assert_eq!(layout.children[1].children[0].location.x, 0.0000);
+        assert_eq!(layout.children[1].children[0].location.y, 0.0000);
+    }
+
+    #[test]
+    fn contents_root_lays_out_children_as_roots() {
+        let layout = stretch::compute(&Node {
+            display: Display::Contents,
+            children: Box::new(vec![
+                Node {
+                    size: Size { width: Dimension::Points(100.0000), height: Dimension::Points(20.0000) },
+                    ..Default::default()
+                },
+                Node {
+                    display: Display::Block,
+                    size: Size { width: Dimension::Points(50.0000), ..Default::default() },
+                    children: Box::new(vec![Node {
+                        display: Display::Block,
+                        size: Size { height: Dimension::Points(10.0000), ..Default::default() },
+                        ..Default::default()
+                    }]),
+                    ..Default::default()
+                },
+            ]),
+            ..Default::default()
+        });
+
+        assert_eq!(layout.size.width, 0.0000);
+        assert_eq!(layout.size.height, 0.0000);
+
+        assert_eq!(layout.children[0].size.width, 100.0000);
+        assert_eq!(layout.children[0].location.x, 0.0000);
+        assert_eq!(layout.children[1].size.width, 50.0000);
+        assert_eq!(layout.children[1].size.height, 10.0000);
+        assert_eq!(layout.children[1].location.y, 0.0000);
+    }
+}
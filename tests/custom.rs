@@ -0,0 +1,314 @@
+#[cfg(test)]
+mod custom {
+    use stretch::geometry::{Point, Rect, Size};
+    use stretch::number::{AvailableSpace, Number, OrElse};
+    use stretch::style::{CustomLayout, Dimension, Display, LayoutAlgorithm, LayoutChildren, Node, PositionType};
+
+    // Places the children next to each other at their max-content size, 10 points apart.
+    struct Carousel;
+
+    impl LayoutAlgorithm for Carousel {
+        fn compute(
+            &self,
+            _node_size: Size<Number>,
+            _available_space: Size<AvailableSpace>,
+            children: &mut dyn LayoutChildren,
+        ) -> CustomLayout {
+            let mut size = Size { width: 0.0, height: 0.0 };
+            let mut locations = vec![];
+
+            for index in 0..children.count() {
+                let child_size = children.compute(
+                    index,
+                    Size { width: Number::Undefined, height: Number::Undefined },
+                    Size { width: AvailableSpace::MaxContent, height: AvailableSpace::MaxContent },
+                );
+
+                if index > 0 {
+                    size.width += 10.0;
+                }
+
+                locations.push(Point { x: size.width, y: 0.0 });
+                size.width += child_size.width;
+                size.height = f32::max(size.height, child_size.height);
+            }
+
+            CustomLayout { size, locations }
+        }
+    }
+
+    // Places the children evenly on a circle around the center of the node.
+    struct Radial {
+        radius: f32,
+    }
+
+    impl LayoutAlgorithm for Radial {
+        fn compute(
+            &self,
+            node_size: Size<Number>,
+            _available_space: Size<AvailableSpace>,
+            children: &mut dyn LayoutChildren,
+        ) -> CustomLayout {
+            let size = Size { width: node_size.width.or_else(0.0), height: node_size.height.or_else(0.0) };
+            let count = children.count();
+
+            let locations = (0..count)
+                .map(|index| {
+                    let child_size = children.compute(
+                        index,
+                        Size { width: Number::Undefined, height: Number::Undefined },
+                        Size { width: AvailableSpace::MaxContent, height: AvailableSpace::MaxContent },
+                    );
+
+                    let angle = index as f32 / count as f32 * 2.0 * std::f32::consts::PI;
+                    Point {
+                        x: size.width / 2.0 + self.radius * angle.cos() - child_size.width / 2.0,
+                        y: size.height / 2.0 + self.radius * angle.sin() - child_size.height / 2.0,
+                    }
+                })
+                .collect();
+
+            CustomLayout { size, locations }
+        }
+    }
+
+    // Lays out nothing, leaving the size of the node to its style.
+    struct Empty;
+
+    impl LayoutAlgorithm for Empty {
+        fn compute(
+            &self,
+            _node_size: Size<Number>,
+            _available_space: Size<AvailableSpace>,
+            _children: &mut dyn LayoutChildren,
+        ) -> CustomLayout {
+            CustomLayout { size: Size { width: 0.0, height: 0.0 }, locations: vec![] }
+        }
+    }
+
+    // Lays out one child past the last.
+    struct PastLast;
+
+    impl LayoutAlgorithm for PastLast {
+        fn compute(
+            &self,
+            _node_size: Size<Number>,
+            _available_space: Size<AvailableSpace>,
+            children: &mut dyn LayoutChildren,
+        ) -> CustomLayout {
+            let size = children.compute(
+                children.count(),
+                Size { width: Number::Undefined, height: Number::Undefined },
+                Size { width: AvailableSpace::MaxContent, height: AvailableSpace::MaxContent },
+            );
+
+            CustomLayout { size, locations: vec![] }
+        }
+    }
+
+    #[test]
+    fn custom_sizes_node_and_places_children() {
+        let layout = stretch::compute(&Node {
+            algorithm: Some(Box::new(Carousel)),
+            children: Box::new(vec![
+                Node {
+                    size: Size { width: Dimension::Points(50.0000), height: Dimension::Points(20.0000) },
+                    ..Default::default()
+                },
+                Node {
+                    size: Size { width: Dimension::Points(30.0000), height: Dimension::Points(40.0000) },
+                    ..Default::default()
+                },
+            ]),
+            ..Default::default()
+        });
+
+        assert_eq!(layout.size.width, 90.0000);
+        assert_eq!(layout.size.height, 40.0000);
+
+        assert_eq!(layout.children[0].location.x, 0.0000);
+        assert_eq!(layout.children[0].location.y, 0.0000);
+        assert_eq!(layout.children[1].location.x, 60.0000);
+        assert_eq!(layout.children[1].location.y, 0.0000);
+    }
+
+    #[test]
+    fn custom_lays_out_contents_children() {
+        let layout = stretch::compute(&Node {
+            algorithm: Some(Box::new(Carousel)),
+            children: Box::new(vec![
+                Node {
+                    size: Size { width: Dimension::Points(20.0000), height: Dimension::Points(10.0000) },
+                    ..Default::default()
+                },
+                Node {
+                    display: Display::Contents,
+                    children: Box::new(vec![
+                        Node {
+                            size: Size { width: Dimension::Points(30.0000), height: Dimension::Points(10.0000) },
+                            ..Default::default()
+                        },
+                        Node {
+                            size: Size { width: Dimension::Points(10.0000), height: Dimension::Points(10.0000) },
+                            ..Default::default()
+                        },
+                    ]),
+                    ..Default::default()
+                },
+            ]),
+            ..Default::default()
+        });
+
+        assert_eq!(layout.size.width, 80.0000);
+
+        assert_eq!(layout.children[1].size.width, 0.0000);
+        assert_eq!(layout.children[1].children[0].location.x, 30.0000);
+        assert_eq!(layout.children[1].children[1].location.x, 70.0000);
+    }
+
+    #[test]
+    fn custom_nests_flex_children() {
+        let layout = stretch::compute(&Node {
+            algorithm: Some(Box::new(Carousel)),
+            children: Box::new(vec![
+                Node {
+                    size: Size { width: Dimension::Points(50.0000), height: Dimension::Points(20.0000) },
+                    ..Default::default()
+                },
+                Node {
+                    children: Box::new(vec![
+                        Node {
+                            size: Size { width: Dimension::Points(20.0000), height: Dimension::Points(20.0000) },
+                            ..Default::default()
+                        },
+                        Node {
+                            size: Size { width: Dimension::Points(20.0000), height: Dimension::Points(30.0000) },
+                            ..Default::default()
+                        },
+                    ]),
+                    ..Default::default()
+                },
+            ]),
+            ..Default::default()
+        });
+
+        assert_eq!(layout.size.width, 100.0000);
+        assert_eq!(layout.size.height, 30.0000);
+
+        assert_eq!(layout.children[1].location.x, 60.0000);
+        assert_eq!(layout.children[1].size.width, 40.0000);
+        assert_eq!(layout.children[1].size.height, 30.0000);
+        assert_eq!(layout.children[1].children[1].location.x, 20.0000);
+    }
+
+    #[test]
+    fn custom_radial_menu() {
+        let layout = stretch::compute(&Node {
+            algorithm: Some(Box::new(Radial { radius: 50.0 })),
+            size: Size { width: Dimension::Points(120.0000), height: Dimension::Points(120.0000) },
+            children: Box::new(vec![
+                Node {
+                    size: Size { width: Dimension::Points(20.0000), height: Dimension::Points(20.0000) },
+                    ..Default::default()
+                },
+                Node {
+                    size: Size { width: Dimension::Points(20.0000), height: Dimension::Points(20.0000) },
+                    ..Default::default()
+                },
+                Node {
+                    size: Size { width: Dimension::Points(20.0000), height: Dimension::Points(20.0000) },
+                    ..Default::default()
+                },
+                Node {
+                    size: Size { width: Dimension::Points(20.0000), height: Dimension::Points(20.0000) },
+                    ..Default::default()
+                },
+            ]),
+            ..Default::default()
+        });
+
+        assert_eq!(layout.children[0].location.x, 100.0000);
+        assert_eq!(layout.children[0].location.y, 50.0000);
+        assert_eq!(layout.children[1].location.x, 50.0000);
+        assert_eq!(layout.children[1].location.y, 100.0000);
+        assert_eq!(layout.children[2].location.x, 0.0000);
+        assert_eq!(layout.children[2].location.y, 50.0000);
+        assert_eq!(layout.children[3].location.x, 50.0000);
+        assert_eq!(layout.children[3].location.y, 0.0000);
+    }
+
+    #[test]
+    fn custom_only_lays_out_in_flow_children() {
+        let layout = stretch::compute(&Node {
+            algorithm: Some(Box::new(Carousel)),
+            children: Box::new(vec![
+                Node {
+                    size: Size { width: Dimension::Points(50.0000), height: Dimension::Points(20.0000) },
+                    ..Default::default()
+                },
+                Node {
+                    display: Display::None,
+                    size: Size { width: Dimension::Points(100.0000), height: Dimension::Points(100.0000) },
+                    ..Default::default()
+                },
+                Node {
+                    position_type: PositionType::Absolute,
+                    position: Rect { end: Dimension::Points(0.0000), ..Default::default() },
+                    size: Size { width: Dimension::Points(10.0000), height: Dimension::Points(10.0000) },
+                    ..Default::default()
+                },
+                Node {
+                    size: Size { width: Dimension::Points(30.0000), height: Dimension::Points(20.0000) },
+                    ..Default::default()
+                },
+            ]),
+            ..Default::default()
+        });
+
+        assert_eq!(layout.size.width, 90.0000);
+
+        assert_eq!(layout.children[1].size.width, 0.0000);
+        assert_eq!(layout.children[2].location.x, 80.0000);
+        assert_eq!(layout.children[3].location.x, 60.0000);
+    }
+
+    #[test]
+    fn custom_places_children_not_laid_out_at_content_start() {
+        let layout = stretch::compute(&Node {
+            algorithm: Some(Box::new(Empty)),
+            size: Size { width: Dimension::Points(100.0000), height: Dimension::Points(100.0000) },
+            padding: Rect {
+                start: Dimension::Points(10.0000),
+                end: Dimension::Points(10.0000),
+                top: Dimension::Points(5.0000),
+                bottom: Dimension::Points(5.0000),
+            },
+            children: Box::new(vec![Node {
+                size: Size { width: Dimension::Points(30.0000), height: Dimension::Points(20.0000) },
+                ..Default::default()
+            }]),
+            ..Default::default()
+        });
+
+        assert_eq!(layout.size.width, 100.0000);
+        assert_eq!(layout.size.height, 100.0000);
+
+        assert_eq!(layout.children[0].size.width, 30.0000);
+        assert_eq!(layout.children[0].size.height, 20.0000);
+        assert_eq!(layout.children[0].location.x, 10.0000);
+        assert_eq!(layout.children[0].location.y, 5.0000);
+    }
+
+    #[test]
+    #[should_panic]
+    fn custom_laying_out_child_out_of_range_panics() {
+        stretch::compute(&Node {
+            algorithm: Some(Box::new(PastLast)),
+            children: Box::new(vec![Node {
+                size: Size { width: Dimension::Points(10.0000), height: Dimension::Points(10.0000) },
+                ..Default::default()
+            }]),
+            ..Default::default()
+        });
+    }
+}
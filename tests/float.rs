@@ -0,0 +1,225 @@
+mod common;
+
+#[cfg(test)]
+mod float {
+    use super::common::text;
+    use stretch::geometry::Size;
+    use stretch::number::OrElse;
+    use stretch::style::{Clear, Dimension, Display, Float, Node};
+
+    #[test]
+    fn float_left_and_right() {
+        let layout = stretch::compute(&Node {
+            display: Display::Block,
+            size: Size { width: Dimension::Points(200.0000), ..Default::default() },
+            children: Box::new(vec![
+                Node {
+                    display: Display::Block,
+                    float: Float::Left,
+                    size: Size { width: Dimension::Points(50.0000), height: Dimension::Points(50.0000) },
+                    ..Default::default()
+                },
+                Node {
+                    display: Display::Block,
+                    float: Float::Right,
+                    size: Size { width: Dimension::Points(30.0000), height: Dimension::Points(20.0000) },
+                    ..Default::default()
+                },
+            ]),
+            ..Default::default()
+        });
+
+        assert_eq!(layout.size.height, 50.0000);
+
+        assert_eq!(layout.children[0].location.x, 0.0000);
+        assert_eq!(layout.children[0].location.y, 0.0000);
+        assert_eq!(layout.children[1].location.x, 170.0000);
+        assert_eq!(layout.children[1].location.y, 0.0000);
+    }
+
+    #[test]
+    fn float_shortens_measured_content() {
+        let layout = stretch::compute(&Node {
+            display: Display::Block,
+            size: Size { width: Dimension::Points(200.0000), ..Default::default() },
+            children: Box::new(vec![
+                Node {
+                    display: Display::Block,
+                    float: Float::Left,
+                    size: Size { width: Dimension::Points(50.0000), height: Dimension::Points(50.0000) },
+                    ..Default::default()
+                },
+                Node { display: Display::Block, measure: Some(Box::new(text)), ..Default::default() },
+            ]),
+            ..Default::default()
+        });
+
+        assert_eq!(layout.size.height, 50.0000);
+
+        assert_eq!(layout.children[1].size.width, 150.0000);
+        assert_eq!(layout.children[1].size.height, 20.0000);
+        assert_eq!(layout.children[1].location.x, 50.0000);
+        assert_eq!(layout.children[1].location.y, 0.0000);
+    }
+
+    #[test]
+    fn floats_wrap_below_when_out_of_space() {
+        let layout = stretch::compute(&Node {
+            display: Display::Block,
+            size: Size { width: Dimension::Points(100.0000), ..Default::default() },
+            children: Box::new(vec![
+                Node {
+                    display: Display::Block,
+                    float: Float::Left,
+                    size: Size { width: Dimension::Points(40.0000), height: Dimension::Points(10.0000) },
+                    ..Default::default()
+                },
+                Node {
+                    display: Display::Block,
+                    float: Float::Left,
+                    size: Size { width: Dimension::Points(40.0000), height: Dimension::Points(10.0000) },
+                    ..Default::default()
+                },
+                Node {
+                    display: Display::Block,
+                    float: Float::Left,
+                    size: Size { width: Dimension::Points(40.0000), height: Dimension::Points(10.0000) },
+                    ..Default::default()
+                },
+            ]),
+            ..Default::default()
+        });
+
+        assert_eq!(layout.size.height, 20.0000);
+
+        assert_eq!(layout.children[1].location.x, 40.0000);
+        assert_eq!(layout.children[1].location.y, 0.0000);
+        assert_eq!(layout.children[2].location.x, 0.0000);
+        assert_eq!(layout.children[2].location.y, 10.0000);
+    }
+
+    #[test]
+    fn clear_moves_below_floats() {
+        let layout = stretch::compute(&Node {
+            display: Display::Block,
+            size: Size { width: Dimension::Points(100.0000), ..Default::default() },
+            children: Box::new(vec![
+                Node {
+                    display: Display::Block,
+                    float: Float::Left,
+                    size: Size { width: Dimension::Points(50.0000), height: Dimension::Points(30.0000) },
+                    ..Default::default()
+                },
+                Node {
+                    display: Display::Block,
+                    clear: Clear::Right,
+                    size: Size { height: Dimension::Points(10.0000), ..Default::default() },
+                    ..Default::default()
+                },
+                Node {
+                    display: Display::Block,
+                    clear: Clear::Left,
+                    size: Size { height: Dimension::Points(10.0000), ..Default::default() },
+                    ..Default::default()
+                },
+            ]),
+            ..Default::default()
+        });
+
+        assert_eq!(layout.size.height, 40.0000);
+
+        assert_eq!(layout.children[1].size.width, 100.0000);
+        assert_eq!(layout.children[1].location.y, 0.0000);
+        assert_eq!(layout.children[2].location.y, 30.0000);
+    }
+
+    #[test]
+    fn float_in_nested_block_affects_following_content() {
+        let layout = stretch::compute(&Node {
+            display: Display::Block,
+            size: Size { width: Dimension::Points(100.0000), ..Default::default() },
+            children: Box::new(vec![
+                Node {
+                    display: Display::Block,
+                    children: Box::new(vec![Node {
+                        display: Display::Block,
+                        float: Float::Left,
+                        size: Size { width: Dimension::Points(40.0000), height: Dimension::Points(40.0000) },
+                        ..Default::default()
+                    }]),
+                    ..Default::default()
+                },
+                Node {
+                    display: Display::Block,
+                    measure: Some(Box::new(|known, _| Size { width: known.width.or_else(0.0), height: 10.0 })),
+                    ..Default::default()
+                },
+            ]),
+            ..Default::default()
+        });
+
+        assert_eq!(layout.size.height, 40.0000);
+
+        assert_eq!(layout.children[0].size.height, 0.0000);
+        assert_eq!(layout.children[1].size.width, 60.0000);
+        assert_eq!(layout.children[1].location.x, 40.0000);
+        assert_eq!(layout.children[1].location.y, 0.0000);
+    }
+
+    #[test]
+    fn float_content_in_nested_block_flows_around_float() {
+        let layout = stretch::compute(&Node {
+            display: Display::Block,
+            size: Size { width: Dimension::Points(100.0000), ..Default::default() },
+            children: Box::new(vec![
+                Node {
+                    display: Display::Block,
+                    float: Float::Right,
+                    size: Size { width: Dimension::Points(30.0000), height: Dimension::Points(30.0000) },
+                    ..Default::default()
+                },
+                Node {
+                    display: Display::Block,
+                    children: Box::new(vec![Node {
+                        display: Display::Block,
+                        measure: Some(Box::new(|known, _| Size { width: known.width.or_else(0.0), height: 10.0 })),
+                        ..Default::default()
+                    }]),
+                    ..Default::default()
+                },
+            ]),
+            ..Default::default()
+        });
+
+        assert_eq!(layout.children[1].size.width, 100.0000);
+        assert_eq!(layout.children[1].children[0].size.width, 70.0000);
+        assert_eq!(layout.children[1].children[0].location.x, 0.0000);
+    }
+
+    #[test]
+    fn float_left_rtl() {
+        let layout = stretch::compute(&Node {
+            display: Display::Block,
+            direction: stretch::style::Direction::RTL,
+            size: Size { width: Dimension::Points(100.0000), ..Default::default() },
+            children: Box::new(vec![
+                Node {
+                    display: Display::Block,
+                    float: Float::Left,
+                    size: Size { width: Dimension::Points(30.0000), height: Dimension::Points(10.0000) },
+                    ..Default::default()
+                },
+                Node {
+                    display: Display::Block,
+                    float: Float::Right,
+                    size: Size { width: Dimension::Points(30.0000), height: Dimension::Points(10.0000) },
+                    ..Default::default()
+                },
+            ]),
+            ..Default::default()
+        });
+
+        assert_eq!(layout.children[0].location.x, 0.0000);
+        assert_eq!(layout.children[1].location.x, 70.0000);
+    }
+}
@@ -10254,12 +10254,12 @@ mod generated {
         assert_eq!(layout.location.y, 0.0000);
 
         assert_eq!(layout.children[0].size.width, 100.0000);
-        assert_eq!(layout.children[0].size.height, 50.0000);
+        assert_eq!(layout.children[0].size.height, 100.0000);
         assert_eq!(layout.children[0].location.x, 0.0000);
         assert_eq!(layout.children[0].location.y, 0.0000);
 
         assert_eq!(layout.children[0].children[0].size.width, 100.0000);
-        assert_eq!(layout.children[0].children[0].size.height, 50.0000);
+        assert_eq!(layout.children[0].children[0].size.height, 100.0000);
         assert_eq!(layout.children[0].children[0].location.x, 0.0000);
         assert_eq!(layout.children[0].children[0].location.y, 0.0000);
     }
@@ -11017,4 +11017,1649 @@ mod generated {
         assert_eq!(layout.children[0].location.y, 10.0000);
     }
 
+    #[test]
+    fn rtl_row() {
+        let layout = stretch::compute(&stretch::style::Node {
+            direction: stretch::style::Direction::RTL,
+            size: stretch::geometry::Size {
+                width: stretch::style::Dimension::Points(100.0000),
+                height: stretch::style::Dimension::Points(100.0000),
+                ..Default::default()
+            },
+            children: vec![
+                stretch::style::Node {
+                    size: stretch::geometry::Size {
+                        width: stretch::style::Dimension::Points(10.0000),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+                stretch::style::Node {
+                    size: stretch::geometry::Size {
+                        width: stretch::style::Dimension::Points(10.0000),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        });
+
+        assert_eq!(layout.size.width, 100.0000);
+        assert_eq!(layout.size.height, 100.0000);
+        assert_eq!(layout.location.x, 0.0000);
+        assert_eq!(layout.location.y, 0.0000);
+
+        assert_eq!(layout.children[0].size.width, 10.0000);
+        assert_eq!(layout.children[0].size.height, 100.0000);
+        assert_eq!(layout.children[0].location.x, 90.0000);
+        assert_eq!(layout.children[0].location.y, 0.0000);
+
+        assert_eq!(layout.children[1].size.width, 10.0000);
+        assert_eq!(layout.children[1].size.height, 100.0000);
+        assert_eq!(layout.children[1].location.x, 80.0000);
+        assert_eq!(layout.children[1].location.y, 0.0000);
+    }
+
+    #[test]
+    fn rtl_row_reverse() {
+        let layout = stretch::compute(&stretch::style::Node {
+            direction: stretch::style::Direction::RTL,
+            flex_direction: stretch::style::FlexDirection::RowReverse,
+            size: stretch::geometry::Size {
+                width: stretch::style::Dimension::Points(100.0000),
+                height: stretch::style::Dimension::Points(100.0000),
+                ..Default::default()
+            },
+            children: vec![
+                stretch::style::Node {
+                    size: stretch::geometry::Size {
+                        width: stretch::style::Dimension::Points(10.0000),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+                stretch::style::Node {
+                    size: stretch::geometry::Size {
+                        width: stretch::style::Dimension::Points(10.0000),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        });
+
+        assert_eq!(layout.size.width, 100.0000);
+        assert_eq!(layout.size.height, 100.0000);
+        assert_eq!(layout.location.x, 0.0000);
+        assert_eq!(layout.location.y, 0.0000);
+
+        assert_eq!(layout.children[0].size.width, 10.0000);
+        assert_eq!(layout.children[0].size.height, 100.0000);
+        assert_eq!(layout.children[0].location.x, 0.0000);
+        assert_eq!(layout.children[0].location.y, 0.0000);
+
+        assert_eq!(layout.children[1].size.width, 10.0000);
+        assert_eq!(layout.children[1].size.height, 100.0000);
+        assert_eq!(layout.children[1].location.x, 10.0000);
+        assert_eq!(layout.children[1].location.y, 0.0000);
+    }
+
+    #[test]
+    fn rtl_justify_content_flex_end() {
+        let layout = stretch::compute(&stretch::style::Node {
+            direction: stretch::style::Direction::RTL,
+            justify_content: stretch::style::JustifyContent::FlexEnd,
+            size: stretch::geometry::Size {
+                width: stretch::style::Dimension::Points(100.0000),
+                height: stretch::style::Dimension::Points(100.0000),
+                ..Default::default()
+            },
+            children: vec![
+                stretch::style::Node {
+                    size: stretch::geometry::Size {
+                        width: stretch::style::Dimension::Points(10.0000),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+                stretch::style::Node {
+                    size: stretch::geometry::Size {
+                        width: stretch::style::Dimension::Points(10.0000),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        });
+
+        assert_eq!(layout.size.width, 100.0000);
+        assert_eq!(layout.size.height, 100.0000);
+        assert_eq!(layout.location.x, 0.0000);
+        assert_eq!(layout.location.y, 0.0000);
+
+        assert_eq!(layout.children[0].size.width, 10.0000);
+        assert_eq!(layout.children[0].size.height, 100.0000);
+        assert_eq!(layout.children[0].location.x, 10.0000);
+        assert_eq!(layout.children[0].location.y, 0.0000);
+
+        assert_eq!(layout.children[1].size.width, 10.0000);
+        assert_eq!(layout.children[1].size.height, 100.0000);
+        assert_eq!(layout.children[1].location.x, 0.0000);
+        assert_eq!(layout.children[1].location.y, 0.0000);
+    }
+
+    #[test]
+    fn rtl_column_align_items_flex_start() {
+        let layout = stretch::compute(&stretch::style::Node {
+            direction: stretch::style::Direction::RTL,
+            flex_direction: stretch::style::FlexDirection::Column,
+            align_items: stretch::style::AlignItems::FlexStart,
+            size: stretch::geometry::Size {
+                width: stretch::style::Dimension::Points(100.0000),
+                height: stretch::style::Dimension::Points(100.0000),
+                ..Default::default()
+            },
+            children: vec![
+                stretch::style::Node {
+                    size: stretch::geometry::Size {
+                        width: stretch::style::Dimension::Points(10.0000),
+                        height: stretch::style::Dimension::Points(10.0000),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+                stretch::style::Node {
+                    size: stretch::geometry::Size {
+                        width: stretch::style::Dimension::Points(20.0000),
+                        height: stretch::style::Dimension::Points(10.0000),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        });
+
+        assert_eq!(layout.size.width, 100.0000);
+        assert_eq!(layout.size.height, 100.0000);
+        assert_eq!(layout.location.x, 0.0000);
+        assert_eq!(layout.location.y, 0.0000);
+
+        assert_eq!(layout.children[0].size.width, 10.0000);
+        assert_eq!(layout.children[0].size.height, 10.0000);
+        assert_eq!(layout.children[0].location.x, 90.0000);
+        assert_eq!(layout.children[0].location.y, 0.0000);
+
+        assert_eq!(layout.children[1].size.width, 20.0000);
+        assert_eq!(layout.children[1].size.height, 10.0000);
+        assert_eq!(layout.children[1].location.x, 80.0000);
+        assert_eq!(layout.children[1].location.y, 10.0000);
+    }
+
+    #[test]
+    fn rtl_margin_and_padding_start() {
+        let layout = stretch::compute(&stretch::style::Node {
+            direction: stretch::style::Direction::RTL,
+            size: stretch::geometry::Size {
+                width: stretch::style::Dimension::Points(100.0000),
+                height: stretch::style::Dimension::Points(100.0000),
+                ..Default::default()
+            },
+            padding: stretch::geometry::Rect { start: stretch::style::Dimension::Points(5.0000), ..Default::default() },
+            children: vec![
+                stretch::style::Node {
+                    size: stretch::geometry::Size {
+                        width: stretch::style::Dimension::Points(10.0000),
+                        ..Default::default()
+                    },
+                    margin: stretch::geometry::Rect {
+                        start: stretch::style::Dimension::Points(10.0000),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+                stretch::style::Node {
+                    size: stretch::geometry::Size {
+                        width: stretch::style::Dimension::Points(10.0000),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        });
+
+        assert_eq!(layout.size.width, 100.0000);
+        assert_eq!(layout.size.height, 100.0000);
+        assert_eq!(layout.location.x, 0.0000);
+        assert_eq!(layout.location.y, 0.0000);
+
+        assert_eq!(layout.children[0].size.width, 10.0000);
+        assert_eq!(layout.children[0].size.height, 100.0000);
+        assert_eq!(layout.children[0].location.x, 75.0000);
+        assert_eq!(layout.children[0].location.y, 0.0000);
+
+        assert_eq!(layout.children[1].size.width, 10.0000);
+        assert_eq!(layout.children[1].size.height, 100.0000);
+        assert_eq!(layout.children[1].location.x, 65.0000);
+        assert_eq!(layout.children[1].location.y, 0.0000);
+    }
+
+    #[test]
+    fn rtl_absolute_position_start() {
+        let layout = stretch::compute(&stretch::style::Node {
+            direction: stretch::style::Direction::RTL,
+            size: stretch::geometry::Size {
+                width: stretch::style::Dimension::Points(100.0000),
+                height: stretch::style::Dimension::Points(100.0000),
+                ..Default::default()
+            },
+            children: vec![stretch::style::Node {
+                position_type: stretch::style::PositionType::Absolute,
+                size: stretch::geometry::Size {
+                    width: stretch::style::Dimension::Points(10.0000),
+                    height: stretch::style::Dimension::Points(10.0000),
+                    ..Default::default()
+                },
+                position: stretch::geometry::Rect {
+                    start: stretch::style::Dimension::Points(10.0000),
+                    top: stretch::style::Dimension::Points(10.0000),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }],
+            ..Default::default()
+        });
+
+        assert_eq!(layout.size.width, 100.0000);
+        assert_eq!(layout.size.height, 100.0000);
+        assert_eq!(layout.location.x, 0.0000);
+        assert_eq!(layout.location.y, 0.0000);
+
+        assert_eq!(layout.children[0].size.width, 10.0000);
+        assert_eq!(layout.children[0].size.height, 10.0000);
+        assert_eq!(layout.children[0].location.x, 80.0000);
+        assert_eq!(layout.children[0].location.y, 10.0000);
+    }
+
+    #[test]
+    fn rtl_inherit() {
+        let layout = stretch::compute(&stretch::style::Node {
+            direction: stretch::style::Direction::RTL,
+            flex_direction: stretch::style::FlexDirection::Column,
+            size: stretch::geometry::Size {
+                width: stretch::style::Dimension::Points(100.0000),
+                height: stretch::style::Dimension::Points(100.0000),
+                ..Default::default()
+            },
+            children: vec![stretch::style::Node {
+                size: stretch::geometry::Size {
+                    height: stretch::style::Dimension::Points(50.0000),
+                    ..Default::default()
+                },
+                children: vec![
+                    stretch::style::Node {
+                        size: stretch::geometry::Size {
+                            width: stretch::style::Dimension::Points(10.0000),
+                            ..Default::default()
+                        },
+                        ..Default::default()
+                    },
+                    stretch::style::Node {
+                        size: stretch::geometry::Size {
+                            width: stretch::style::Dimension::Points(10.0000),
+                            ..Default::default()
+                        },
+                        ..Default::default()
+                    },
+                ],
+                ..Default::default()
+            }],
+            ..Default::default()
+        });
+
+        assert_eq!(layout.size.width, 100.0000);
+        assert_eq!(layout.size.height, 100.0000);
+        assert_eq!(layout.location.x, 0.0000);
+        assert_eq!(layout.location.y, 0.0000);
+
+        assert_eq!(layout.children[0].size.width, 100.0000);
+        assert_eq!(layout.children[0].size.height, 50.0000);
+        assert_eq!(layout.children[0].location.x, 0.0000);
+        assert_eq!(layout.children[0].location.y, 0.0000);
+
+        assert_eq!(layout.children[0].children[0].size.width, 10.0000);
+        assert_eq!(layout.children[0].children[0].size.height, 50.0000);
+        assert_eq!(layout.children[0].children[0].location.x, 90.0000);
+        assert_eq!(layout.children[0].children[0].location.y, 0.0000);
+
+        assert_eq!(layout.children[0].children[1].size.width, 10.0000);
+        assert_eq!(layout.children[0].children[1].size.height, 50.0000);
+        assert_eq!(layout.children[0].children[1].location.x, 80.0000);
+        assert_eq!(layout.children[0].children[1].location.y, 0.0000);
+    }
+
+    #[test]
+    fn order_row() {
+        let layout = stretch::compute(&stretch::style::Node {
+            size: stretch::geometry::Size {
+                width: stretch::style::Dimension::Points(100.0000),
+                height: stretch::style::Dimension::Points(100.0000),
+                ..Default::default()
+            },
+            children: vec![
+                stretch::style::Node {
+                    order: 2,
+                    size: stretch::geometry::Size {
+                        width: stretch::style::Dimension::Points(10.0000),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+                stretch::style::Node {
+                    size: stretch::geometry::Size {
+                        width: stretch::style::Dimension::Points(20.0000),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+                stretch::style::Node {
+                    order: 1,
+                    size: stretch::geometry::Size {
+                        width: stretch::style::Dimension::Points(30.0000),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        });
+
+        assert_eq!(layout.size.width, 100.0000);
+        assert_eq!(layout.size.height, 100.0000);
+        assert_eq!(layout.location.x, 0.0000);
+        assert_eq!(layout.location.y, 0.0000);
+
+        assert_eq!(layout.children[0].size.width, 10.0000);
+        assert_eq!(layout.children[0].size.height, 100.0000);
+        assert_eq!(layout.children[0].location.x, 50.0000);
+        assert_eq!(layout.children[0].location.y, 0.0000);
+
+        assert_eq!(layout.children[1].size.width, 20.0000);
+        assert_eq!(layout.children[1].size.height, 100.0000);
+        assert_eq!(layout.children[1].location.x, 0.0000);
+        assert_eq!(layout.children[1].location.y, 0.0000);
+
+        assert_eq!(layout.children[2].size.width, 30.0000);
+        assert_eq!(layout.children[2].size.height, 100.0000);
+        assert_eq!(layout.children[2].location.x, 20.0000);
+        assert_eq!(layout.children[2].location.y, 0.0000);
+    }
+
+    #[test]
+    fn order_wrap() {
+        let layout = stretch::compute(&stretch::style::Node {
+            flex_wrap: stretch::style::FlexWrap::Wrap,
+            align_content: stretch::style::AlignContent::FlexStart,
+            size: stretch::geometry::Size {
+                width: stretch::style::Dimension::Points(100.0000),
+                height: stretch::style::Dimension::Points(100.0000),
+                ..Default::default()
+            },
+            children: vec![
+                stretch::style::Node {
+                    order: 1,
+                    size: stretch::geometry::Size {
+                        width: stretch::style::Dimension::Points(40.0000),
+                        height: stretch::style::Dimension::Points(10.0000),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+                stretch::style::Node {
+                    size: stretch::geometry::Size {
+                        width: stretch::style::Dimension::Points(60.0000),
+                        height: stretch::style::Dimension::Points(10.0000),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+                stretch::style::Node {
+                    size: stretch::geometry::Size {
+                        width: stretch::style::Dimension::Points(60.0000),
+                        height: stretch::style::Dimension::Points(10.0000),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        });
+
+        assert_eq!(layout.size.width, 100.0000);
+        assert_eq!(layout.size.height, 100.0000);
+        assert_eq!(layout.location.x, 0.0000);
+        assert_eq!(layout.location.y, 0.0000);
+
+        assert_eq!(layout.children[0].size.width, 40.0000);
+        assert_eq!(layout.children[0].size.height, 10.0000);
+        assert_eq!(layout.children[0].location.x, 60.0000);
+        assert_eq!(layout.children[0].location.y, 10.0000);
+
+        assert_eq!(layout.children[1].size.width, 60.0000);
+        assert_eq!(layout.children[1].size.height, 10.0000);
+        assert_eq!(layout.children[1].location.x, 0.0000);
+        assert_eq!(layout.children[1].location.y, 0.0000);
+
+        assert_eq!(layout.children[2].size.width, 60.0000);
+        assert_eq!(layout.children[2].size.height, 10.0000);
+        assert_eq!(layout.children[2].location.x, 0.0000);
+        assert_eq!(layout.children[2].location.y, 10.0000);
+    }
+
+    #[test]
+    fn order_negative_row_reverse() {
+        let layout = stretch::compute(&stretch::style::Node {
+            flex_direction: stretch::style::FlexDirection::RowReverse,
+            size: stretch::geometry::Size {
+                width: stretch::style::Dimension::Points(100.0000),
+                height: stretch::style::Dimension::Points(100.0000),
+                ..Default::default()
+            },
+            children: vec![
+                stretch::style::Node {
+                    size: stretch::geometry::Size {
+                        width: stretch::style::Dimension::Points(10.0000),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+                stretch::style::Node {
+                    order: -1,
+                    size: stretch::geometry::Size {
+                        width: stretch::style::Dimension::Points(20.0000),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        });
+
+        assert_eq!(layout.size.width, 100.0000);
+        assert_eq!(layout.size.height, 100.0000);
+        assert_eq!(layout.location.x, 0.0000);
+        assert_eq!(layout.location.y, 0.0000);
+
+        assert_eq!(layout.children[0].size.width, 10.0000);
+        assert_eq!(layout.children[0].size.height, 100.0000);
+        assert_eq!(layout.children[0].location.x, 70.0000);
+        assert_eq!(layout.children[0].location.y, 0.0000);
+
+        assert_eq!(layout.children[1].size.width, 20.0000);
+        assert_eq!(layout.children[1].size.height, 100.0000);
+        assert_eq!(layout.children[1].location.x, 80.0000);
+        assert_eq!(layout.children[1].location.y, 0.0000);
+    }
+
+    #[test]
+    fn gap_row() {
+        let layout = stretch::compute(&stretch::style::Node {
+            size: stretch::geometry::Size {
+                width: stretch::style::Dimension::Points(100.0000),
+                height: stretch::style::Dimension::Points(100.0000),
+                ..Default::default()
+            },
+            gap: stretch::geometry::Size { width: stretch::style::Dimension::Points(10.0000), ..Default::default() },
+            children: vec![
+                stretch::style::Node {
+                    size: stretch::geometry::Size {
+                        width: stretch::style::Dimension::Points(20.0000),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+                stretch::style::Node {
+                    size: stretch::geometry::Size {
+                        width: stretch::style::Dimension::Points(20.0000),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+                stretch::style::Node {
+                    size: stretch::geometry::Size {
+                        width: stretch::style::Dimension::Points(20.0000),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        });
+
+        assert_eq!(layout.size.width, 100.0000);
+        assert_eq!(layout.size.height, 100.0000);
+        assert_eq!(layout.location.x, 0.0000);
+        assert_eq!(layout.location.y, 0.0000);
+
+        assert_eq!(layout.children[0].size.width, 20.0000);
+        assert_eq!(layout.children[0].size.height, 100.0000);
+        assert_eq!(layout.children[0].location.x, 0.0000);
+        assert_eq!(layout.children[0].location.y, 0.0000);
+
+        assert_eq!(layout.children[1].size.width, 20.0000);
+        assert_eq!(layout.children[1].size.height, 100.0000);
+        assert_eq!(layout.children[1].location.x, 30.0000);
+        assert_eq!(layout.children[1].location.y, 0.0000);
+
+        assert_eq!(layout.children[2].size.width, 20.0000);
+        assert_eq!(layout.children[2].size.height, 100.0000);
+        assert_eq!(layout.children[2].location.x, 60.0000);
+        assert_eq!(layout.children[2].location.y, 0.0000);
+    }
+
+    #[test]
+    fn gap_row_wrap() {
+        let layout = stretch::compute(&stretch::style::Node {
+            flex_wrap: stretch::style::FlexWrap::Wrap,
+            align_content: stretch::style::AlignContent::FlexStart,
+            size: stretch::geometry::Size {
+                width: stretch::style::Dimension::Points(100.0000),
+                height: stretch::style::Dimension::Points(100.0000),
+                ..Default::default()
+            },
+            gap: stretch::geometry::Size {
+                width: stretch::style::Dimension::Points(20.0000),
+                height: stretch::style::Dimension::Points(10.0000),
+                ..Default::default()
+            },
+            children: vec![
+                stretch::style::Node {
+                    size: stretch::geometry::Size {
+                        width: stretch::style::Dimension::Points(40.0000),
+                        height: stretch::style::Dimension::Points(10.0000),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+                stretch::style::Node {
+                    size: stretch::geometry::Size {
+                        width: stretch::style::Dimension::Points(40.0000),
+                        height: stretch::style::Dimension::Points(10.0000),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+                stretch::style::Node {
+                    size: stretch::geometry::Size {
+                        width: stretch::style::Dimension::Points(40.0000),
+                        height: stretch::style::Dimension::Points(10.0000),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        });
+
+        assert_eq!(layout.size.width, 100.0000);
+        assert_eq!(layout.size.height, 100.0000);
+        assert_eq!(layout.location.x, 0.0000);
+        assert_eq!(layout.location.y, 0.0000);
+
+        assert_eq!(layout.children[0].size.width, 40.0000);
+        assert_eq!(layout.children[0].size.height, 10.0000);
+        assert_eq!(layout.children[0].location.x, 0.0000);
+        assert_eq!(layout.children[0].location.y, 0.0000);
+
+        assert_eq!(layout.children[1].size.width, 40.0000);
+        assert_eq!(layout.children[1].size.height, 10.0000);
+        assert_eq!(layout.children[1].location.x, 60.0000);
+        assert_eq!(layout.children[1].location.y, 0.0000);
+
+        assert_eq!(layout.children[2].size.width, 40.0000);
+        assert_eq!(layout.children[2].size.height, 10.0000);
+        assert_eq!(layout.children[2].location.x, 0.0000);
+        assert_eq!(layout.children[2].location.y, 20.0000);
+    }
+
+    #[test]
+    fn gap_column_percent() {
+        let layout = stretch::compute(&stretch::style::Node {
+            flex_direction: stretch::style::FlexDirection::Column,
+            size: stretch::geometry::Size {
+                width: stretch::style::Dimension::Points(100.0000),
+                height: stretch::style::Dimension::Points(200.0000),
+                ..Default::default()
+            },
+            gap: stretch::geometry::Size { height: stretch::style::Dimension::Percent(0.1000), ..Default::default() },
+            children: vec![
+                stretch::style::Node {
+                    size: stretch::geometry::Size {
+                        height: stretch::style::Dimension::Points(20.0000),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+                stretch::style::Node {
+                    size: stretch::geometry::Size {
+                        height: stretch::style::Dimension::Points(20.0000),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        });
+
+        assert_eq!(layout.size.width, 100.0000);
+        assert_eq!(layout.size.height, 200.0000);
+        assert_eq!(layout.location.x, 0.0000);
+        assert_eq!(layout.location.y, 0.0000);
+
+        assert_eq!(layout.children[0].size.width, 100.0000);
+        assert_eq!(layout.children[0].size.height, 20.0000);
+        assert_eq!(layout.children[0].location.x, 0.0000);
+        assert_eq!(layout.children[0].location.y, 0.0000);
+
+        assert_eq!(layout.children[1].size.width, 100.0000);
+        assert_eq!(layout.children[1].size.height, 20.0000);
+        assert_eq!(layout.children[1].location.x, 0.0000);
+        assert_eq!(layout.children[1].location.y, 40.0000);
+    }
+
+    #[test]
+    fn gap_flex_grow() {
+        let layout = stretch::compute(&stretch::style::Node {
+            size: stretch::geometry::Size {
+                width: stretch::style::Dimension::Points(100.0000),
+                height: stretch::style::Dimension::Points(100.0000),
+                ..Default::default()
+            },
+            gap: stretch::geometry::Size { width: stretch::style::Dimension::Points(10.0000), ..Default::default() },
+            children: vec![
+                stretch::style::Node { flex_grow: 1.0000, ..Default::default() },
+                stretch::style::Node { flex_grow: 1.0000, ..Default::default() },
+            ],
+            ..Default::default()
+        });
+
+        assert_eq!(layout.size.width, 100.0000);
+        assert_eq!(layout.size.height, 100.0000);
+        assert_eq!(layout.location.x, 0.0000);
+        assert_eq!(layout.location.y, 0.0000);
+
+        assert_eq!(layout.children[0].size.width, 45.0000);
+        assert_eq!(layout.children[0].size.height, 100.0000);
+        assert_eq!(layout.children[0].location.x, 0.0000);
+        assert_eq!(layout.children[0].location.y, 0.0000);
+
+        assert_eq!(layout.children[1].size.width, 45.0000);
+        assert_eq!(layout.children[1].size.height, 100.0000);
+        assert_eq!(layout.children[1].location.x, 55.0000);
+        assert_eq!(layout.children[1].location.y, 0.0000);
+    }
+
+    #[test]
+    fn gap_display_none() {
+        let layout = stretch::compute(&stretch::style::Node {
+            size: stretch::geometry::Size {
+                width: stretch::style::Dimension::Points(100.0000),
+                height: stretch::style::Dimension::Points(100.0000),
+                ..Default::default()
+            },
+            gap: stretch::geometry::Size { width: stretch::style::Dimension::Points(10.0000), ..Default::default() },
+            children: vec![
+                stretch::style::Node {
+                    size: stretch::geometry::Size {
+                        width: stretch::style::Dimension::Points(20.0000),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+                stretch::style::Node {
+                    display: stretch::style::Display::None,
+                    size: stretch::geometry::Size {
+                        width: stretch::style::Dimension::Points(20.0000),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+                stretch::style::Node {
+                    size: stretch::geometry::Size {
+                        width: stretch::style::Dimension::Points(20.0000),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        });
+
+        assert_eq!(layout.size.width, 100.0000);
+        assert_eq!(layout.size.height, 100.0000);
+        assert_eq!(layout.location.x, 0.0000);
+        assert_eq!(layout.location.y, 0.0000);
+
+        assert_eq!(layout.children[0].size.width, 20.0000);
+        assert_eq!(layout.children[0].size.height, 100.0000);
+        assert_eq!(layout.children[0].location.x, 0.0000);
+        assert_eq!(layout.children[0].location.y, 0.0000);
+
+        assert_eq!(layout.children[1].size.width, 0.0000);
+        assert_eq!(layout.children[1].size.height, 0.0000);
+        assert_eq!(layout.children[1].location.x, 0.0000);
+        assert_eq!(layout.children[1].location.y, 0.0000);
+
+        assert_eq!(layout.children[2].size.width, 20.0000);
+        assert_eq!(layout.children[2].size.height, 100.0000);
+        assert_eq!(layout.children[2].location.x, 30.0000);
+        assert_eq!(layout.children[2].location.y, 0.0000);
+    }
+
+    #[test]
+    fn min_width_auto_row() {
+        let layout = stretch::compute(&stretch::style::Node {
+            size: stretch::geometry::Size { width: stretch::style::Dimension::Points(100.0000), ..Default::default() },
+            children: vec![stretch::style::Node {
+                size: stretch::geometry::Size {
+                    width: stretch::style::Dimension::Points(120.0000),
+                    ..Default::default()
+                },
+                children: vec![stretch::style::Node {
+                    size: stretch::geometry::Size {
+                        width: stretch::style::Dimension::Points(150.0000),
+                        height: stretch::style::Dimension::Points(10.0000),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }],
+            ..Default::default()
+        });
+
+        assert_eq!(layout.size.width, 100.0000);
+        assert_eq!(layout.size.height, 10.0000);
+        assert_eq!(layout.location.x, 0.0000);
+        assert_eq!(layout.location.y, 0.0000);
+
+        assert_eq!(layout.children[0].size.width, 120.0000);
+        assert_eq!(layout.children[0].size.height, 10.0000);
+        assert_eq!(layout.children[0].location.x, 0.0000);
+        assert_eq!(layout.children[0].location.y, 0.0000);
+
+        assert_eq!(layout.children[0].children[0].size.width, 120.0000);
+        assert_eq!(layout.children[0].children[0].size.height, 10.0000);
+        assert_eq!(layout.children[0].children[0].location.x, 0.0000);
+        assert_eq!(layout.children[0].children[0].location.y, 0.0000);
+    }
+
+    #[test]
+    fn min_height_auto_column() {
+        let layout = stretch::compute(&stretch::style::Node {
+            flex_direction: stretch::style::FlexDirection::Column,
+            size: stretch::geometry::Size { height: stretch::style::Dimension::Points(100.0000), ..Default::default() },
+            children: vec![stretch::style::Node {
+                flex_basis: stretch::style::Dimension::Points(50.0000),
+                children: vec![stretch::style::Node {
+                    size: stretch::geometry::Size {
+                        width: stretch::style::Dimension::Points(100.0000),
+                        height: stretch::style::Dimension::Points(100.0000),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }],
+            ..Default::default()
+        });
+
+        assert_eq!(layout.size.width, 100.0000);
+        assert_eq!(layout.size.height, 100.0000);
+        assert_eq!(layout.location.x, 0.0000);
+        assert_eq!(layout.location.y, 0.0000);
+
+        assert_eq!(layout.children[0].size.width, 100.0000);
+        assert_eq!(layout.children[0].size.height, 100.0000);
+        assert_eq!(layout.children[0].location.x, 0.0000);
+        assert_eq!(layout.children[0].location.y, 0.0000);
+
+        assert_eq!(layout.children[0].children[0].size.width, 100.0000);
+        assert_eq!(layout.children[0].children[0].size.height, 100.0000);
+        assert_eq!(layout.children[0].children[0].location.x, 0.0000);
+        assert_eq!(layout.children[0].children[0].location.y, 0.0000);
+    }
+
+    #[test]
+    fn min_height_auto_column_overflow_hidden() {
+        let layout = stretch::compute(&stretch::style::Node {
+            flex_direction: stretch::style::FlexDirection::Column,
+            size: stretch::geometry::Size { height: stretch::style::Dimension::Points(100.0000), ..Default::default() },
+            children: vec![stretch::style::Node {
+                overflow_x: stretch::style::Overflow::Hidden,
+                overflow_y: stretch::style::Overflow::Hidden,
+                flex_basis: stretch::style::Dimension::Points(50.0000),
+                children: vec![stretch::style::Node {
+                    size: stretch::geometry::Size {
+                        width: stretch::style::Dimension::Points(100.0000),
+                        height: stretch::style::Dimension::Points(100.0000),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }],
+            ..Default::default()
+        });
+
+        assert_eq!(layout.size.width, 100.0000);
+        assert_eq!(layout.size.height, 100.0000);
+        assert_eq!(layout.location.x, 0.0000);
+        assert_eq!(layout.location.y, 0.0000);
+
+        assert_eq!(layout.children[0].size.width, 100.0000);
+        assert_eq!(layout.children[0].size.height, 50.0000);
+        assert_eq!(layout.children[0].location.x, 0.0000);
+        assert_eq!(layout.children[0].location.y, 0.0000);
+
+        assert_eq!(layout.children[0].children[0].size.width, 100.0000);
+        assert_eq!(layout.children[0].children[0].size.height, 100.0000);
+        assert_eq!(layout.children[0].children[0].location.x, 0.0000);
+        assert_eq!(layout.children[0].children[0].location.y, 0.0000);
+    }
+
+    #[test]
+    fn align_baseline_column_child() {
+        let layout = stretch::compute(&stretch::style::Node {
+            align_items: stretch::style::AlignItems::Baseline,
+            size: stretch::geometry::Size {
+                width: stretch::style::Dimension::Points(100.0000),
+                height: stretch::style::Dimension::Points(100.0000),
+                ..Default::default()
+            },
+            children: vec![
+                stretch::style::Node {
+                    flex_direction: stretch::style::FlexDirection::Column,
+                    size: stretch::geometry::Size {
+                        width: stretch::style::Dimension::Points(50.0000),
+                        height: stretch::style::Dimension::Points(50.0000),
+                        ..Default::default()
+                    },
+                    padding: stretch::geometry::Rect {
+                        top: stretch::style::Dimension::Points(10.0000),
+                        ..Default::default()
+                    },
+                    children: vec![stretch::style::Node {
+                        size: stretch::geometry::Size {
+                            height: stretch::style::Dimension::Points(20.0000),
+                            ..Default::default()
+                        },
+                        ..Default::default()
+                    }],
+                    ..Default::default()
+                },
+                stretch::style::Node {
+                    size: stretch::geometry::Size {
+                        width: stretch::style::Dimension::Points(50.0000),
+                        height: stretch::style::Dimension::Points(20.0000),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        });
+
+        assert_eq!(layout.size.width, 100.0000);
+        assert_eq!(layout.size.height, 100.0000);
+        assert_eq!(layout.location.x, 0.0000);
+        assert_eq!(layout.location.y, 0.0000);
+
+        assert_eq!(layout.children[0].size.width, 50.0000);
+        assert_eq!(layout.children[0].size.height, 50.0000);
+        assert_eq!(layout.children[0].location.x, 0.0000);
+        assert_eq!(layout.children[0].location.y, 0.0000);
+
+        assert_eq!(layout.children[0].children[0].size.width, 50.0000);
+        assert_eq!(layout.children[0].children[0].size.height, 20.0000);
+        assert_eq!(layout.children[0].children[0].location.x, 0.0000);
+        assert_eq!(layout.children[0].children[0].location.y, 10.0000);
+
+        assert_eq!(layout.children[1].size.width, 50.0000);
+        assert_eq!(layout.children[1].size.height, 20.0000);
+        assert_eq!(layout.children[1].location.x, 50.0000);
+        assert_eq!(layout.children[1].location.y, 10.0000);
+    }
+
+    #[test]
+    fn align_baseline_child_margin() {
+        let layout = stretch::compute(&stretch::style::Node {
+            align_items: stretch::style::AlignItems::Baseline,
+            size: stretch::geometry::Size { width: stretch::style::Dimension::Points(100.0000), ..Default::default() },
+            children: vec![
+                stretch::style::Node {
+                    size: stretch::geometry::Size {
+                        width: stretch::style::Dimension::Points(50.0000),
+                        height: stretch::style::Dimension::Points(50.0000),
+                        ..Default::default()
+                    },
+                    margin: stretch::geometry::Rect {
+                        top: stretch::style::Dimension::Points(10.0000),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+                stretch::style::Node {
+                    size: stretch::geometry::Size {
+                        width: stretch::style::Dimension::Points(50.0000),
+                        height: stretch::style::Dimension::Points(20.0000),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        });
+
+        assert_eq!(layout.size.width, 100.0000);
+        assert_eq!(layout.size.height, 60.0000);
+        assert_eq!(layout.location.x, 0.0000);
+        assert_eq!(layout.location.y, 0.0000);
+
+        assert_eq!(layout.children[0].size.width, 50.0000);
+        assert_eq!(layout.children[0].size.height, 50.0000);
+        assert_eq!(layout.children[0].location.x, 0.0000);
+        assert_eq!(layout.children[0].location.y, 10.0000);
+
+        assert_eq!(layout.children[1].size.width, 50.0000);
+        assert_eq!(layout.children[1].size.height, 20.0000);
+        assert_eq!(layout.children[1].location.x, 50.0000);
+        assert_eq!(layout.children[1].location.y, 40.0000);
+    }
+
+    #[test]
+    fn align_baseline_absolute_child() {
+        let layout = stretch::compute(&stretch::style::Node {
+            align_items: stretch::style::AlignItems::Baseline,
+            size: stretch::geometry::Size {
+                width: stretch::style::Dimension::Points(100.0000),
+                height: stretch::style::Dimension::Points(100.0000),
+                ..Default::default()
+            },
+            children: vec![stretch::style::Node {
+                position_type: stretch::style::PositionType::Absolute,
+                size: stretch::geometry::Size {
+                    width: stretch::style::Dimension::Points(20.0000),
+                    height: stretch::style::Dimension::Points(20.0000),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }],
+            ..Default::default()
+        });
+
+        assert_eq!(layout.size.width, 100.0000);
+        assert_eq!(layout.size.height, 100.0000);
+        assert_eq!(layout.location.x, 0.0000);
+        assert_eq!(layout.location.y, 0.0000);
+
+        assert_eq!(layout.children[0].size.width, 20.0000);
+        assert_eq!(layout.children[0].size.height, 20.0000);
+        assert_eq!(layout.children[0].location.x, 0.0000);
+        assert_eq!(layout.children[0].location.y, 0.0000);
+    }
+
+    #[test]
+    fn justify_content_row_reverse_start() {
+        let layout = stretch::compute(&stretch::style::Node {
+            flex_direction: stretch::style::FlexDirection::RowReverse,
+            justify_content: stretch::style::JustifyContent::Start,
+            size: stretch::geometry::Size {
+                width: stretch::style::Dimension::Points(100.0000),
+                height: stretch::style::Dimension::Points(20.0000),
+                ..Default::default()
+            },
+            children: vec![
+                stretch::style::Node {
+                    size: stretch::geometry::Size {
+                        width: stretch::style::Dimension::Points(20.0000),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+                stretch::style::Node {
+                    size: stretch::geometry::Size {
+                        width: stretch::style::Dimension::Points(20.0000),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+                stretch::style::Node {
+                    size: stretch::geometry::Size {
+                        width: stretch::style::Dimension::Points(20.0000),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        });
+
+        assert_eq!(layout.size.width, 100.0000);
+        assert_eq!(layout.size.height, 20.0000);
+        assert_eq!(layout.location.x, 0.0000);
+        assert_eq!(layout.location.y, 0.0000);
+
+        assert_eq!(layout.children[0].size.width, 20.0000);
+        assert_eq!(layout.children[0].size.height, 20.0000);
+        assert_eq!(layout.children[0].location.x, 40.0000);
+        assert_eq!(layout.children[0].location.y, 0.0000);
+
+        assert_eq!(layout.children[1].size.width, 20.0000);
+        assert_eq!(layout.children[1].size.height, 20.0000);
+        assert_eq!(layout.children[1].location.x, 20.0000);
+        assert_eq!(layout.children[1].location.y, 0.0000);
+
+        assert_eq!(layout.children[2].size.width, 20.0000);
+        assert_eq!(layout.children[2].size.height, 20.0000);
+        assert_eq!(layout.children[2].location.x, 0.0000);
+        assert_eq!(layout.children[2].location.y, 0.0000);
+    }
+
+    #[test]
+    fn justify_content_left_rtl() {
+        let layout = stretch::compute(&stretch::style::Node {
+            direction: stretch::style::Direction::RTL,
+            justify_content: stretch::style::JustifyContent::Left,
+            size: stretch::geometry::Size {
+                width: stretch::style::Dimension::Points(100.0000),
+                height: stretch::style::Dimension::Points(20.0000),
+                ..Default::default()
+            },
+            children: vec![
+                stretch::style::Node {
+                    size: stretch::geometry::Size {
+                        width: stretch::style::Dimension::Points(20.0000),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+                stretch::style::Node {
+                    size: stretch::geometry::Size {
+                        width: stretch::style::Dimension::Points(20.0000),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+                stretch::style::Node {
+                    size: stretch::geometry::Size {
+                        width: stretch::style::Dimension::Points(20.0000),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        });
+
+        assert_eq!(layout.size.width, 100.0000);
+        assert_eq!(layout.size.height, 20.0000);
+        assert_eq!(layout.location.x, 0.0000);
+        assert_eq!(layout.location.y, 0.0000);
+
+        assert_eq!(layout.children[0].size.width, 20.0000);
+        assert_eq!(layout.children[0].size.height, 20.0000);
+        assert_eq!(layout.children[0].location.x, 40.0000);
+        assert_eq!(layout.children[0].location.y, 0.0000);
+
+        assert_eq!(layout.children[1].size.width, 20.0000);
+        assert_eq!(layout.children[1].size.height, 20.0000);
+        assert_eq!(layout.children[1].location.x, 20.0000);
+        assert_eq!(layout.children[1].location.y, 0.0000);
+
+        assert_eq!(layout.children[2].size.width, 20.0000);
+        assert_eq!(layout.children[2].size.height, 20.0000);
+        assert_eq!(layout.children[2].location.x, 0.0000);
+        assert_eq!(layout.children[2].location.y, 0.0000);
+    }
+
+    #[test]
+    fn justify_content_safe_center_overflow() {
+        let layout = stretch::compute(&stretch::style::Node {
+            justify_content: stretch::style::JustifyContent::Center,
+            justify_content_safety: stretch::style::AlignmentSafety::Safe,
+            size: stretch::geometry::Size { width: stretch::style::Dimension::Points(100.0000), ..Default::default() },
+            children: vec![stretch::style::Node {
+                flex_shrink: 0.0000,
+                size: stretch::geometry::Size {
+                    width: stretch::style::Dimension::Points(150.0000),
+                    height: stretch::style::Dimension::Points(10.0000),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }],
+            ..Default::default()
+        });
+
+        assert_eq!(layout.size.width, 100.0000);
+        assert_eq!(layout.size.height, 10.0000);
+        assert_eq!(layout.location.x, 0.0000);
+        assert_eq!(layout.location.y, 0.0000);
+
+        assert_eq!(layout.children[0].size.width, 150.0000);
+        assert_eq!(layout.children[0].size.height, 10.0000);
+        assert_eq!(layout.children[0].location.x, 0.0000);
+        assert_eq!(layout.children[0].location.y, 0.0000);
+    }
+
+    #[test]
+    fn align_content_space_evenly() {
+        let layout = stretch::compute(&stretch::style::Node {
+            flex_wrap: stretch::style::FlexWrap::Wrap,
+            align_content: stretch::style::AlignContent::SpaceEvenly,
+            size: stretch::geometry::Size {
+                width: stretch::style::Dimension::Points(100.0000),
+                height: stretch::style::Dimension::Points(100.0000),
+                ..Default::default()
+            },
+            children: vec![
+                stretch::style::Node {
+                    size: stretch::geometry::Size {
+                        width: stretch::style::Dimension::Points(50.0000),
+                        height: stretch::style::Dimension::Points(20.0000),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+                stretch::style::Node {
+                    size: stretch::geometry::Size {
+                        width: stretch::style::Dimension::Points(50.0000),
+                        height: stretch::style::Dimension::Points(20.0000),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+                stretch::style::Node {
+                    size: stretch::geometry::Size {
+                        width: stretch::style::Dimension::Points(50.0000),
+                        height: stretch::style::Dimension::Points(20.0000),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+                stretch::style::Node {
+                    size: stretch::geometry::Size {
+                        width: stretch::style::Dimension::Points(50.0000),
+                        height: stretch::style::Dimension::Points(20.0000),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        });
+
+        assert_eq!(layout.size.width, 100.0000);
+        assert_eq!(layout.size.height, 100.0000);
+        assert_eq!(layout.location.x, 0.0000);
+        assert_eq!(layout.location.y, 0.0000);
+
+        assert_eq!(layout.children[0].size.width, 50.0000);
+        assert_eq!(layout.children[0].size.height, 20.0000);
+        assert_eq!(layout.children[0].location.x, 0.0000);
+        assert_eq!(layout.children[0].location.y, 20.0000);
+
+        assert_eq!(layout.children[1].size.width, 50.0000);
+        assert_eq!(layout.children[1].size.height, 20.0000);
+        assert_eq!(layout.children[1].location.x, 50.0000);
+        assert_eq!(layout.children[1].location.y, 20.0000);
+
+        assert_eq!(layout.children[2].size.width, 50.0000);
+        assert_eq!(layout.children[2].size.height, 20.0000);
+        assert_eq!(layout.children[2].location.x, 0.0000);
+        assert_eq!(layout.children[2].location.y, 60.0000);
+
+        assert_eq!(layout.children[3].size.width, 50.0000);
+        assert_eq!(layout.children[3].size.height, 20.0000);
+        assert_eq!(layout.children[3].location.x, 50.0000);
+        assert_eq!(layout.children[3].location.y, 60.0000);
+    }
+
+    #[test]
+    fn align_content_end_wrap_reverse() {
+        let layout = stretch::compute(&stretch::style::Node {
+            flex_wrap: stretch::style::FlexWrap::WrapReverse,
+            align_content: stretch::style::AlignContent::End,
+            size: stretch::geometry::Size {
+                width: stretch::style::Dimension::Points(100.0000),
+                height: stretch::style::Dimension::Points(100.0000),
+                ..Default::default()
+            },
+            children: vec![
+                stretch::style::Node {
+                    size: stretch::geometry::Size {
+                        width: stretch::style::Dimension::Points(50.0000),
+                        height: stretch::style::Dimension::Points(20.0000),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+                stretch::style::Node {
+                    size: stretch::geometry::Size {
+                        width: stretch::style::Dimension::Points(50.0000),
+                        height: stretch::style::Dimension::Points(20.0000),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+                stretch::style::Node {
+                    size: stretch::geometry::Size {
+                        width: stretch::style::Dimension::Points(50.0000),
+                        height: stretch::style::Dimension::Points(20.0000),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+                stretch::style::Node {
+                    size: stretch::geometry::Size {
+                        width: stretch::style::Dimension::Points(50.0000),
+                        height: stretch::style::Dimension::Points(20.0000),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        });
+
+        assert_eq!(layout.size.width, 100.0000);
+        assert_eq!(layout.size.height, 100.0000);
+        assert_eq!(layout.location.x, 0.0000);
+        assert_eq!(layout.location.y, 0.0000);
+
+        assert_eq!(layout.children[0].size.width, 50.0000);
+        assert_eq!(layout.children[0].size.height, 20.0000);
+        assert_eq!(layout.children[0].location.x, 0.0000);
+        assert_eq!(layout.children[0].location.y, 80.0000);
+
+        assert_eq!(layout.children[1].size.width, 50.0000);
+        assert_eq!(layout.children[1].size.height, 20.0000);
+        assert_eq!(layout.children[1].location.x, 50.0000);
+        assert_eq!(layout.children[1].location.y, 80.0000);
+
+        assert_eq!(layout.children[2].size.width, 50.0000);
+        assert_eq!(layout.children[2].size.height, 20.0000);
+        assert_eq!(layout.children[2].location.x, 0.0000);
+        assert_eq!(layout.children[2].location.y, 60.0000);
+
+        assert_eq!(layout.children[3].size.width, 50.0000);
+        assert_eq!(layout.children[3].size.height, 20.0000);
+        assert_eq!(layout.children[3].location.x, 50.0000);
+        assert_eq!(layout.children[3].location.y, 60.0000);
+    }
+
+    #[test]
+    fn align_items_start_wrap_reverse() {
+        let layout = stretch::compute(&stretch::style::Node {
+            flex_wrap: stretch::style::FlexWrap::WrapReverse,
+            align_items: stretch::style::AlignItems::Start,
+            size: stretch::geometry::Size {
+                width: stretch::style::Dimension::Points(100.0000),
+                height: stretch::style::Dimension::Points(100.0000),
+                ..Default::default()
+            },
+            children: vec![stretch::style::Node {
+                size: stretch::geometry::Size {
+                    width: stretch::style::Dimension::Points(20.0000),
+                    height: stretch::style::Dimension::Points(20.0000),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }],
+            ..Default::default()
+        });
+
+        assert_eq!(layout.size.width, 100.0000);
+        assert_eq!(layout.size.height, 100.0000);
+        assert_eq!(layout.location.x, 0.0000);
+        assert_eq!(layout.location.y, 0.0000);
+
+        assert_eq!(layout.children[0].size.width, 20.0000);
+        assert_eq!(layout.children[0].size.height, 20.0000);
+        assert_eq!(layout.children[0].location.x, 0.0000);
+        assert_eq!(layout.children[0].location.y, 0.0000);
+    }
+
+    #[test]
+    fn align_items_safe_end_overflow() {
+        let layout = stretch::compute(&stretch::style::Node {
+            align_items: stretch::style::AlignItems::End,
+            align_items_safety: stretch::style::AlignmentSafety::Safe,
+            size: stretch::geometry::Size {
+                width: stretch::style::Dimension::Points(100.0000),
+                height: stretch::style::Dimension::Points(50.0000),
+                ..Default::default()
+            },
+            children: vec![stretch::style::Node {
+                size: stretch::geometry::Size {
+                    width: stretch::style::Dimension::Points(20.0000),
+                    height: stretch::style::Dimension::Points(80.0000),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }],
+            ..Default::default()
+        });
+
+        assert_eq!(layout.size.width, 100.0000);
+        assert_eq!(layout.size.height, 50.0000);
+        assert_eq!(layout.location.x, 0.0000);
+        assert_eq!(layout.location.y, 0.0000);
+
+        assert_eq!(layout.children[0].size.width, 20.0000);
+        assert_eq!(layout.children[0].size.height, 80.0000);
+        assert_eq!(layout.children[0].location.x, 0.0000);
+        assert_eq!(layout.children[0].location.y, 0.0000);
+    }
+
+    #[test]
+    fn align_items_normal() {
+        let layout = stretch::compute(&stretch::style::Node {
+            align_items: stretch::style::AlignItems::Normal,
+            size: stretch::geometry::Size {
+                width: stretch::style::Dimension::Points(100.0000),
+                height: stretch::style::Dimension::Points(100.0000),
+                ..Default::default()
+            },
+            children: vec![stretch::style::Node {
+                size: stretch::geometry::Size {
+                    width: stretch::style::Dimension::Points(20.0000),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }],
+            ..Default::default()
+        });
+
+        assert_eq!(layout.size.width, 100.0000);
+        assert_eq!(layout.size.height, 100.0000);
+        assert_eq!(layout.location.x, 0.0000);
+        assert_eq!(layout.location.y, 0.0000);
+
+        assert_eq!(layout.children[0].size.width, 20.0000);
+        assert_eq!(layout.children[0].size.height, 100.0000);
+        assert_eq!(layout.children[0].location.x, 0.0000);
+        assert_eq!(layout.children[0].location.y, 0.0000);
+    }
+
+    #[test]
+    fn absolute_justify_content_end_align_items_end() {
+        let layout = stretch::compute(&stretch::style::Node {
+            align_items: stretch::style::AlignItems::End,
+            justify_content: stretch::style::JustifyContent::End,
+            size: stretch::geometry::Size {
+                width: stretch::style::Dimension::Points(100.0000),
+                height: stretch::style::Dimension::Points(100.0000),
+                ..Default::default()
+            },
+            children: vec![stretch::style::Node {
+                position_type: stretch::style::PositionType::Absolute,
+                size: stretch::geometry::Size {
+                    width: stretch::style::Dimension::Points(20.0000),
+                    height: stretch::style::Dimension::Points(20.0000),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }],
+            ..Default::default()
+        });
+
+        assert_eq!(layout.size.width, 100.0000);
+        assert_eq!(layout.size.height, 100.0000);
+        assert_eq!(layout.location.x, 0.0000);
+        assert_eq!(layout.location.y, 0.0000);
+
+        assert_eq!(layout.children[0].size.width, 20.0000);
+        assert_eq!(layout.children[0].size.height, 20.0000);
+        assert_eq!(layout.children[0].location.x, 80.0000);
+        assert_eq!(layout.children[0].location.y, 80.0000);
+    }
+
+    #[test]
+    fn align_items_last_baseline() {
+        let layout = stretch::compute(&stretch::style::Node {
+            align_items: stretch::style::AlignItems::LastBaseline,
+            size: stretch::geometry::Size {
+                width: stretch::style::Dimension::Points(100.0000),
+                height: stretch::style::Dimension::Points(100.0000),
+                ..Default::default()
+            },
+            children: vec![
+                stretch::style::Node {
+                    size: stretch::geometry::Size {
+                        width: stretch::style::Dimension::Points(50.0000),
+                        height: stretch::style::Dimension::Points(50.0000),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+                stretch::style::Node {
+                    size: stretch::geometry::Size {
+                        width: stretch::style::Dimension::Points(50.0000),
+                        height: stretch::style::Dimension::Points(20.0000),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        });
+
+        assert_eq!(layout.size.width, 100.0000);
+        assert_eq!(layout.size.height, 100.0000);
+        assert_eq!(layout.location.x, 0.0000);
+        assert_eq!(layout.location.y, 0.0000);
+
+        assert_eq!(layout.children[0].size.width, 50.0000);
+        assert_eq!(layout.children[0].size.height, 50.0000);
+        assert_eq!(layout.children[0].location.x, 0.0000);
+        assert_eq!(layout.children[0].location.y, 50.0000);
+
+        assert_eq!(layout.children[1].size.width, 50.0000);
+        assert_eq!(layout.children[1].size.height, 20.0000);
+        assert_eq!(layout.children[1].location.x, 50.0000);
+        assert_eq!(layout.children[1].location.y, 80.0000);
+    }
+
+    #[test]
+    fn align_items_last_baseline_column_child() {
+        let layout = stretch::compute(&stretch::style::Node {
+            align_items: stretch::style::AlignItems::LastBaseline,
+            size: stretch::geometry::Size { width: stretch::style::Dimension::Points(100.0000), ..Default::default() },
+            children: vec![
+                stretch::style::Node {
+                    flex_direction: stretch::style::FlexDirection::Column,
+                    size: stretch::geometry::Size {
+                        width: stretch::style::Dimension::Points(50.0000),
+                        ..Default::default()
+                    },
+                    padding: stretch::geometry::Rect {
+                        bottom: stretch::style::Dimension::Points(10.0000),
+                        ..Default::default()
+                    },
+                    children: vec![
+                        stretch::style::Node {
+                            size: stretch::geometry::Size {
+                                height: stretch::style::Dimension::Points(20.0000),
+                                ..Default::default()
+                            },
+                            ..Default::default()
+                        },
+                        stretch::style::Node {
+                            size: stretch::geometry::Size {
+                                height: stretch::style::Dimension::Points(10.0000),
+                                ..Default::default()
+                            },
+                            ..Default::default()
+                        },
+                    ],
+                    ..Default::default()
+                },
+                stretch::style::Node {
+                    size: stretch::geometry::Size {
+                        width: stretch::style::Dimension::Points(50.0000),
+                        height: stretch::style::Dimension::Points(20.0000),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        });
+
+        assert_eq!(layout.size.width, 100.0000);
+        assert_eq!(layout.size.height, 40.0000);
+        assert_eq!(layout.location.x, 0.0000);
+        assert_eq!(layout.location.y, 0.0000);
+
+        assert_eq!(layout.children[0].size.width, 50.0000);
+        assert_eq!(layout.children[0].size.height, 40.0000);
+        assert_eq!(layout.children[0].location.x, 0.0000);
+        assert_eq!(layout.children[0].location.y, 0.0000);
+
+        assert_eq!(layout.children[0].children[0].size.width, 50.0000);
+        assert_eq!(layout.children[0].children[0].size.height, 20.0000);
+        assert_eq!(layout.children[0].children[0].location.x, 0.0000);
+        assert_eq!(layout.children[0].children[0].location.y, 0.0000);
+
+        assert_eq!(layout.children[0].children[1].size.width, 50.0000);
+        assert_eq!(layout.children[0].children[1].size.height, 10.0000);
+        assert_eq!(layout.children[0].children[1].location.x, 0.0000);
+        assert_eq!(layout.children[0].children[1].location.y, 20.0000);
+
+        assert_eq!(layout.children[1].size.width, 50.0000);
+        assert_eq!(layout.children[1].size.height, 20.0000);
+        assert_eq!(layout.children[1].location.x, 50.0000);
+        assert_eq!(layout.children[1].location.y, 10.0000);
+    }
+
+    #[test]
+    fn overflow_x_hidden_item_shrinks() {
+        let layout = stretch::compute(&stretch::style::Node {
+            size: stretch::geometry::Size { width: stretch::style::Dimension::Points(100.0000), ..Default::default() },
+            children: vec![stretch::style::Node {
+                overflow_x: stretch::style::Overflow::Hidden,
+                children: vec![stretch::style::Node {
+                    flex_shrink: 0.0000,
+                    size: stretch::geometry::Size {
+                        width: stretch::style::Dimension::Points(150.0000),
+                        height: stretch::style::Dimension::Points(10.0000),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }],
+            ..Default::default()
+        });
+
+        assert_eq!(layout.size.width, 100.0000);
+        assert_eq!(layout.size.height, 10.0000);
+        assert_eq!(layout.location.x, 0.0000);
+        assert_eq!(layout.location.y, 0.0000);
+
+        assert_eq!(layout.children[0].size.width, 100.0000);
+        assert_eq!(layout.children[0].size.height, 10.0000);
+        assert_eq!(layout.children[0].location.x, 0.0000);
+        assert_eq!(layout.children[0].location.y, 0.0000);
+
+        assert_eq!(layout.children[0].children[0].size.width, 150.0000);
+        assert_eq!(layout.children[0].children[0].size.height, 10.0000);
+        assert_eq!(layout.children[0].children[0].location.x, 0.0000);
+        assert_eq!(layout.children[0].children[0].location.y, 0.0000);
+    }
+
+    #[test]
+    fn overflow_y_hidden_item_shrinks() {
+        let layout = stretch::compute(&stretch::style::Node {
+            size: stretch::geometry::Size { width: stretch::style::Dimension::Points(100.0000), ..Default::default() },
+            children: vec![stretch::style::Node {
+                overflow_y: stretch::style::Overflow::Hidden,
+                children: vec![stretch::style::Node {
+                    flex_shrink: 0.0000,
+                    size: stretch::geometry::Size {
+                        width: stretch::style::Dimension::Points(150.0000),
+                        height: stretch::style::Dimension::Points(10.0000),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }],
+            ..Default::default()
+        });
+
+        assert_eq!(layout.size.width, 100.0000);
+        assert_eq!(layout.size.height, 10.0000);
+        assert_eq!(layout.location.x, 0.0000);
+        assert_eq!(layout.location.y, 0.0000);
+
+        assert_eq!(layout.children[0].size.width, 100.0000);
+        assert_eq!(layout.children[0].size.height, 10.0000);
+        assert_eq!(layout.children[0].location.x, 0.0000);
+        assert_eq!(layout.children[0].location.y, 0.0000);
+
+        assert_eq!(layout.children[0].children[0].size.width, 150.0000);
+        assert_eq!(layout.children[0].children[0].size.height, 10.0000);
+        assert_eq!(layout.children[0].children[0].location.x, 0.0000);
+        assert_eq!(layout.children[0].children[0].location.y, 0.0000);
+    }
+
+    #[test]
+    fn justify_content_space_around_overflow() {
+        let layout = stretch::compute(&stretch::style::Node {
+            justify_content: stretch::style::JustifyContent::SpaceAround,
+            size: stretch::geometry::Size { width: stretch::style::Dimension::Points(100.0000), ..Default::default() },
+            children: vec![
+                stretch::style::Node {
+                    flex_shrink: 0.0000,
+                    size: stretch::geometry::Size {
+                        width: stretch::style::Dimension::Points(80.0000),
+                        height: stretch::style::Dimension::Points(10.0000),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+                stretch::style::Node {
+                    flex_shrink: 0.0000,
+                    size: stretch::geometry::Size {
+                        width: stretch::style::Dimension::Points(80.0000),
+                        height: stretch::style::Dimension::Points(10.0000),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        });
+
+        assert_eq!(layout.size.width, 100.0000);
+        assert_eq!(layout.size.height, 10.0000);
+        assert_eq!(layout.location.x, 0.0000);
+        assert_eq!(layout.location.y, 0.0000);
+
+        assert_eq!(layout.children[0].size.width, 80.0000);
+        assert_eq!(layout.children[0].size.height, 10.0000);
+        assert_eq!(layout.children[0].location.x, -30.0000);
+        assert_eq!(layout.children[0].location.y, 0.0000);
+
+        assert_eq!(layout.children[1].size.width, 80.0000);
+        assert_eq!(layout.children[1].size.height, 10.0000);
+        assert_eq!(layout.children[1].location.x, 50.0000);
+        assert_eq!(layout.children[1].location.y, 0.0000);
+    }
 }
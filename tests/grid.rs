@@ -0,0 +1,375 @@
+mod common;
+
+#[cfg(test)]
+mod grid {
+    use super::common::text;
+    use stretch::geometry::{Line, Size};
+    use stretch::style::{Dimension, Display, GridPlacement, GridTrack, GridTrackRepetition, TrackSizingFunction};
+
+    #[test]
+    fn grid_fixed_and_flexible_columns() {
+        let layout = stretch::compute(&stretch::style::Node {
+            display: Display::Grid,
+            size: Size { width: Dimension::Points(350.0000), ..Default::default() },
+            grid_template_columns: Box::new(vec![
+                GridTrack::Single(TrackSizingFunction::points(50.0)),
+                GridTrack::Single(TrackSizingFunction::fr(1.0)),
+                GridTrack::Single(TrackSizingFunction::fr(2.0)),
+            ]),
+            children: Box::new(vec![
+                stretch::style::Node {
+                    size: Size { height: Dimension::Points(20.0000), ..Default::default() },
+                    ..Default::default()
+                },
+                stretch::style::Node {
+                    size: Size { height: Dimension::Points(20.0000), ..Default::default() },
+                    ..Default::default()
+                },
+                stretch::style::Node {
+                    size: Size { height: Dimension::Points(20.0000), ..Default::default() },
+                    ..Default::default()
+                },
+            ]),
+            ..Default::default()
+        });
+
+        assert_eq!(layout.size.width, 350.0000);
+        assert_eq!(layout.size.height, 20.0000);
+
+        assert_eq!(layout.children[0].size.width, 50.0000);
+        assert_eq!(layout.children[0].location.x, 0.0000);
+        assert_eq!(layout.children[1].size.width, 100.0000);
+        assert_eq!(layout.children[1].location.x, 50.0000);
+        assert_eq!(layout.children[2].size.width, 200.0000);
+        assert_eq!(layout.children[2].location.x, 150.0000);
+    }
+
+    #[test]
+    fn grid_auto_placement_creates_rows() {
+        let layout = stretch::compute(&stretch::style::Node {
+            display: Display::Grid,
+            grid_template_columns: Box::new(vec![
+                GridTrack::Single(TrackSizingFunction::points(50.0)),
+                GridTrack::Single(TrackSizingFunction::points(50.0)),
+            ]),
+            children: Box::new(vec![
+                stretch::style::Node {
+                    size: Size { height: Dimension::Points(10.0000), ..Default::default() },
+                    ..Default::default()
+                },
+                stretch::style::Node {
+                    size: Size { height: Dimension::Points(20.0000), ..Default::default() },
+                    ..Default::default()
+                },
+                stretch::style::Node {
+                    size: Size { height: Dimension::Points(30.0000), ..Default::default() },
+                    ..Default::default()
+                },
+            ]),
+            ..Default::default()
+        });
+
+        assert_eq!(layout.size.width, 100.0000);
+        assert_eq!(layout.size.height, 50.0000);
+
+        assert_eq!(layout.children[0].size.height, 10.0000);
+        assert_eq!(layout.children[0].location.x, 0.0000);
+        assert_eq!(layout.children[0].location.y, 0.0000);
+        assert_eq!(layout.children[1].location.x, 50.0000);
+        assert_eq!(layout.children[1].location.y, 0.0000);
+        assert_eq!(layout.children[2].location.x, 0.0000);
+        assert_eq!(layout.children[2].location.y, 20.0000);
+    }
+
+    #[test]
+    fn grid_line_placement_and_span() {
+        let layout = stretch::compute(&stretch::style::Node {
+            display: Display::Grid,
+            grid_template_columns: Box::new(vec![GridTrack::Repeat(
+                GridTrackRepetition::Count(3),
+                vec![TrackSizingFunction::points(100.0)],
+            )]),
+            grid_template_rows: Box::new(vec![GridTrack::Repeat(
+                GridTrackRepetition::Count(3),
+                vec![TrackSizingFunction::points(100.0)],
+            )]),
+            children: Box::new(vec![
+                stretch::style::Node {
+                    grid_column: Line { start: GridPlacement::Line(2), end: GridPlacement::Span(2) },
+                    grid_row: Line { start: GridPlacement::Line(-2), end: GridPlacement::Auto },
+                    ..Default::default()
+                },
+                stretch::style::Node::default(),
+                stretch::style::Node {
+                    grid_row: Line { start: GridPlacement::Line(1), end: GridPlacement::Auto },
+                    ..Default::default()
+                },
+            ]),
+            ..Default::default()
+        });
+
+        assert_eq!(layout.size.width, 300.0000);
+        assert_eq!(layout.size.height, 300.0000);
+
+        assert_eq!(layout.children[0].size.width, 200.0000);
+        assert_eq!(layout.children[0].size.height, 100.0000);
+        assert_eq!(layout.children[0].location.x, 100.0000);
+        assert_eq!(layout.children[0].location.y, 200.0000);
+
+        assert_eq!(layout.children[1].location.x, 100.0000);
+        assert_eq!(layout.children[1].location.y, 0.0000);
+
+        assert_eq!(layout.children[2].location.x, 0.0000);
+        assert_eq!(layout.children[2].location.y, 0.0000);
+    }
+
+    #[test]
+    fn grid_implicit_rows_cycle_auto_rows() {
+        let layout = stretch::compute(&stretch::style::Node {
+            display: Display::Grid,
+            grid_template_columns: Box::new(vec![GridTrack::Single(TrackSizingFunction::points(100.0))]),
+            grid_auto_rows: Box::new(vec![TrackSizingFunction::points(10.0), TrackSizingFunction::points(20.0)]),
+            children: Box::new(vec![
+                stretch::style::Node::default(),
+                stretch::style::Node::default(),
+                stretch::style::Node::default(),
+            ]),
+            ..Default::default()
+        });
+
+        assert_eq!(layout.size.height, 40.0000);
+
+        assert_eq!(layout.children[0].size.height, 10.0000);
+        assert_eq!(layout.children[1].size.height, 20.0000);
+        assert_eq!(layout.children[1].location.y, 10.0000);
+        assert_eq!(layout.children[2].size.height, 10.0000);
+        assert_eq!(layout.children[2].location.y, 30.0000);
+    }
+
+    #[test]
+    fn grid_repeat_auto_fill() {
+        let layout = stretch::compute(&stretch::style::Node {
+            display: Display::Grid,
+            size: Size { width: Dimension::Points(250.0000), ..Default::default() },
+            gap: Size { width: Dimension::Points(10.0000), ..Default::default() },
+            grid_template_columns: Box::new(vec![GridTrack::Repeat(
+                GridTrackRepetition::AutoFill,
+                vec![TrackSizingFunction::points(100.0)],
+            )]),
+            children: Box::new(vec![
+                stretch::style::Node {
+                    size: Size { height: Dimension::Points(10.0000), ..Default::default() },
+                    ..Default::default()
+                },
+                stretch::style::Node {
+                    size: Size { height: Dimension::Points(10.0000), ..Default::default() },
+                    ..Default::default()
+                },
+                stretch::style::Node {
+                    size: Size { height: Dimension::Points(10.0000), ..Default::default() },
+                    ..Default::default()
+                },
+            ]),
+            ..Default::default()
+        });
+
+        assert_eq!(layout.size.height, 20.0000);
+
+        assert_eq!(layout.children[0].location.x, 0.0000);
+        assert_eq!(layout.children[1].location.x, 110.0000);
+        assert_eq!(layout.children[1].location.y, 0.0000);
+        assert_eq!(layout.children[2].location.x, 0.0000);
+        assert_eq!(layout.children[2].location.y, 10.0000);
+    }
+
+    #[test]
+    fn grid_repeat_auto_fit_collapses_empty_tracks() {
+        let layout = stretch::compute(&stretch::style::Node {
+            display: Display::Grid,
+            justify_content: stretch::style::JustifyContent::Center,
+            size: Size { width: Dimension::Points(300.0000), ..Default::default() },
+            grid_template_columns: Box::new(vec![GridTrack::Repeat(
+                GridTrackRepetition::AutoFit,
+                vec![TrackSizingFunction::points(50.0)],
+            )]),
+            children: Box::new(vec![
+                stretch::style::Node {
+                    size: Size { height: Dimension::Points(10.0000), ..Default::default() },
+                    ..Default::default()
+                },
+                stretch::style::Node {
+                    size: Size { height: Dimension::Points(10.0000), ..Default::default() },
+                    ..Default::default()
+                },
+            ]),
+            ..Default::default()
+        });
+
+        assert_eq!(layout.children[0].location.x, 100.0000);
+        assert_eq!(layout.children[1].location.x, 150.0000);
+    }
+
+    #[test]
+    fn grid_auto_column_fits_content() {
+        let layout = stretch::compute(&stretch::style::Node {
+            display: Display::Grid,
+            size: Size { width: Dimension::Points(200.0000), ..Default::default() },
+            grid_template_columns: Box::new(vec![
+                GridTrack::Single(TrackSizingFunction::auto()),
+                GridTrack::Single(TrackSizingFunction::fr(1.0)),
+            ]),
+            children: Box::new(vec![
+                stretch::style::Node {
+                    measure: Some(Box::new(|_, _| Size { width: 30.0, height: 10.0 })),
+                    ..Default::default()
+                },
+                stretch::style::Node::default(),
+            ]),
+            ..Default::default()
+        });
+
+        assert_eq!(layout.size.height, 10.0000);
+
+        assert_eq!(layout.children[0].size.width, 30.0000);
+        assert_eq!(layout.children[1].size.width, 170.0000);
+        assert_eq!(layout.children[1].size.height, 10.0000);
+        assert_eq!(layout.children[1].location.x, 30.0000);
+    }
+
+    #[test]
+    fn grid_row_height_for_column_width() {
+        let layout = stretch::compute(&stretch::style::Node {
+            display: Display::Grid,
+            size: Size { width: Dimension::Points(100.0000), ..Default::default() },
+            grid_template_columns: Box::new(vec![
+                GridTrack::Single(TrackSizingFunction::fr(1.0)),
+                GridTrack::Single(TrackSizingFunction::fr(1.0)),
+            ]),
+            children: Box::new(vec![stretch::style::Node { measure: Some(Box::new(text)), ..Default::default() }]),
+            ..Default::default()
+        });
+
+        assert_eq!(layout.size.height, 60.0000);
+
+        assert_eq!(layout.children[0].size.width, 50.0000);
+        assert_eq!(layout.children[0].size.height, 60.0000);
+    }
+
+    #[test]
+    fn grid_dense_auto_flow_fills_holes() {
+        let layout = stretch::compute(&stretch::style::Node {
+            display: Display::Grid,
+            grid_auto_flow: stretch::style::GridAutoFlow::RowDense,
+            grid_template_columns: Box::new(vec![GridTrack::Repeat(
+                GridTrackRepetition::Count(3),
+                vec![TrackSizingFunction::points(10.0)],
+            )]),
+            children: Box::new(vec![
+                stretch::style::Node {
+                    grid_column: Line { start: GridPlacement::Span(2), end: GridPlacement::Auto },
+                    size: Size { height: Dimension::Points(10.0000), ..Default::default() },
+                    ..Default::default()
+                },
+                stretch::style::Node {
+                    grid_column: Line { start: GridPlacement::Span(2), end: GridPlacement::Auto },
+                    size: Size { height: Dimension::Points(10.0000), ..Default::default() },
+                    ..Default::default()
+                },
+                stretch::style::Node {
+                    grid_column: Line { start: GridPlacement::Span(1), end: GridPlacement::Auto },
+                    size: Size { height: Dimension::Points(10.0000), ..Default::default() },
+                    ..Default::default()
+                },
+            ]),
+            ..Default::default()
+        });
+
+        assert_eq!(layout.size.height, 20.0000);
+
+        assert_eq!(layout.children[1].location.x, 0.0000);
+        assert_eq!(layout.children[1].location.y, 10.0000);
+        assert_eq!(layout.children[2].location.x, 20.0000);
+        assert_eq!(layout.children[2].location.y, 0.0000);
+    }
+
+    #[test]
+    fn grid_align_self_center() {
+        let layout = stretch::compute(&stretch::style::Node {
+            display: Display::Grid,
+            grid_template_columns: Box::new(vec![GridTrack::Single(TrackSizingFunction::points(100.0))]),
+            grid_template_rows: Box::new(vec![GridTrack::Single(TrackSizingFunction::points(100.0))]),
+            children: Box::new(vec![stretch::style::Node {
+                align_self: stretch::style::AlignSelf::Center,
+                size: Size { height: Dimension::Points(20.0000), ..Default::default() },
+                ..Default::default()
+            }]),
+            ..Default::default()
+        });
+
+        assert_eq!(layout.children[0].size.width, 100.0000);
+        assert_eq!(layout.children[0].size.height, 20.0000);
+        assert_eq!(layout.children[0].location.y, 40.0000);
+    }
+
+    #[test]
+    fn grid_rtl() {
+        let layout = stretch::compute(&stretch::style::Node {
+            display: Display::Grid,
+            direction: stretch::style::Direction::RTL,
+            grid_template_columns: Box::new(vec![
+                GridTrack::Single(TrackSizingFunction::points(30.0)),
+                GridTrack::Single(TrackSizingFunction::points(70.0)),
+            ]),
+            children: Box::new(vec![
+                stretch::style::Node {
+                    size: Size { height: Dimension::Points(10.0000), ..Default::default() },
+                    ..Default::default()
+                },
+                stretch::style::Node {
+                    size: Size { height: Dimension::Points(10.0000), ..Default::default() },
+                    ..Default::default()
+                },
+            ]),
+            ..Default::default()
+        });
+
+        assert_eq!(layout.size.width, 100.0000);
+
+        assert_eq!(layout.children[0].size.width, 30.0000);
+        assert_eq!(layout.children[0].location.x, 70.0000);
+        assert_eq!(layout.children[1].location.x, 0.0000);
+    }
+
+    #[test]
+    fn grid_as_flex_item() {
+        let layout = stretch::compute(&stretch::style::Node {
+            size: Size { width: Dimension::Points(300.0000), ..Default::default() },
+            children: Box::new(vec![stretch::style::Node {
+                display: Display::Grid,
+                flex_grow: 1.0,
+                grid_template_columns: Box::new(vec![
+                    GridTrack::Single(TrackSizingFunction::fr(1.0)),
+                    GridTrack::Single(TrackSizingFunction::fr(1.0)),
+                ]),
+                children: Box::new(vec![
+                    stretch::style::Node {
+                        size: Size { height: Dimension::Points(10.0000), ..Default::default() },
+                        ..Default::default()
+                    },
+                    stretch::style::Node {
+                        size: Size { height: Dimension::Points(10.0000), ..Default::default() },
+                        ..Default::default()
+                    },
+                ]),
+                ..Default::default()
+            }]),
+            ..Default::default()
+        });
+
+        assert_eq!(layout.size.height, 10.0000);
+
+        assert_eq!(layout.children[0].size.width, 300.0000);
+        assert_eq!(layout.children[0].children[0].size.width, 150.0000);
+        assert_eq!(layout.children[0].children[1].location.x, 150.0000);
+    }
+}
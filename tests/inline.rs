@@ -0,0 +1,233 @@
+#[cfg(test)]
+mod inline {
+    use stretch::geometry::{Rect, Size};
+    use stretch::style::{Dimension, Direction, Display, Float, Node, TextAlign, TextFunc, TextRun, TextSegment};
+
+    // A run of words of the same advance, each followed by the given width of whitespace, in lines
+    // 10 points high with the baseline at 8 points.
+    fn words(count: usize, advance: f32, whitespace: f32) -> Option<TextFunc> {
+        Some(Box::new(move || TextRun {
+            segments: vec![TextSegment { advance, trailing_whitespace: whitespace }; count],
+            line_height: 10.0,
+            baseline: 8.0,
+        }))
+    }
+
+    #[test]
+    fn inline_text_wraps_into_lines() {
+        let layout = stretch::compute(&Node {
+            display: Display::Block,
+            size: Size { width: Dimension::Points(100.0000), ..Default::default() },
+            children: Box::new(vec![Node { text: words(10, 20.0, 5.0), ..Default::default() }]),
+            ..Default::default()
+        });
+
+        assert_eq!(layout.size.height, 20.0000);
+
+        assert_eq!(layout.children[0].size.width, 100.0000);
+        assert_eq!(layout.children[0].size.height, 20.0000);
+        assert_eq!(layout.children[0].children.len(), 10);
+        assert_eq!(layout.children[0].children[4].location.x, 80.0000);
+        assert_eq!(layout.children[0].children[4].location.y, 0.0000);
+        assert_eq!(layout.children[0].children[5].location.x, 0.0000);
+        assert_eq!(layout.children[0].children[5].location.y, 10.0000);
+    }
+
+    #[test]
+    fn inline_runs_without_whitespace_between_stay_together() {
+        let layout = stretch::compute(&Node {
+            display: Display::Block,
+            size: Size { width: Dimension::Points(30.0000), ..Default::default() },
+            children: Box::new(vec![
+                Node { text: words(1, 20.0, 0.0), ..Default::default() },
+                Node { text: words(1, 20.0, 0.0), ..Default::default() },
+            ]),
+            ..Default::default()
+        });
+
+        assert_eq!(layout.size.height, 10.0000);
+
+        assert_eq!(layout.children[1].location.x, 20.0000);
+        assert_eq!(layout.children[1].location.y, 0.0000);
+    }
+
+    #[test]
+    fn inline_contents_children_join_lines() {
+        let layout = stretch::compute(&Node {
+            display: Display::Block,
+            size: Size { width: Dimension::Points(100.0000), ..Default::default() },
+            children: Box::new(vec![
+                Node { text: words(2, 20.0, 5.0), ..Default::default() },
+                Node {
+                    display: Display::Contents,
+                    children: Box::new(vec![Node {
+                        display: Display::Inline,
+                        children: Box::new(vec![Node {
+                            display: Display::Contents,
+                            children: Box::new(vec![Node { text: words(3, 20.0, 5.0), ..Default::default() }]),
+                            ..Default::default()
+                        }]),
+                        ..Default::default()
+                    }]),
+                    ..Default::default()
+                },
+            ]),
+            ..Default::default()
+        });
+
+        assert_eq!(layout.size.height, 10.0000);
+
+        assert_eq!(layout.children[1].size.height, 0.0000);
+        assert_eq!(layout.children[1].children[0].size.width, 60.0000);
+        assert_eq!(layout.children[1].children[0].location.x, 40.0000);
+        assert_eq!(layout.children[1].children[0].location.y, 0.0000);
+        assert_eq!(layout.children[1].children[0].children[0].children[0].size.width, 60.0000);
+        assert_eq!(layout.children[1].children[0].children[0].children[0].location.x, 0.0000);
+    }
+
+    #[test]
+    fn inline_block_aligned_by_baseline() {
+        let layout = stretch::compute(&Node {
+            display: Display::Block,
+            size: Size { width: Dimension::Points(200.0000), ..Default::default() },
+            children: Box::new(vec![
+                Node { text: words(2, 10.0, 0.0), ..Default::default() },
+                Node {
+                    display: Display::InlineBlock,
+                    size: Size { width: Dimension::Points(20.0000), height: Dimension::Points(30.0000) },
+                    ..Default::default()
+                },
+            ]),
+            ..Default::default()
+        });
+
+        assert_eq!(layout.size.height, 32.0000);
+
+        assert_eq!(layout.children[0].location.y, 22.0000);
+        assert_eq!(layout.children[0].baseline, 8.0000);
+        assert_eq!(layout.children[1].location.x, 20.0000);
+        assert_eq!(layout.children[1].location.y, 0.0000);
+    }
+
+    #[test]
+    fn inline_box_spaced_by_padding() {
+        let layout = stretch::compute(&Node {
+            display: Display::Block,
+            size: Size { width: Dimension::Points(200.0000), ..Default::default() },
+            children: Box::new(vec![Node {
+                display: Display::Inline,
+                padding: Rect {
+                    start: Dimension::Points(10.0000),
+                    end: Dimension::Points(10.0000),
+                    ..Default::default()
+                },
+                children: Box::new(vec![Node { text: words(2, 20.0, 0.0), ..Default::default() }]),
+                ..Default::default()
+            }]),
+            ..Default::default()
+        });
+
+        assert_eq!(layout.children[0].size.width, 60.0000);
+        assert_eq!(layout.children[0].size.height, 10.0000);
+        assert_eq!(layout.children[0].children[0].location.x, 10.0000);
+        assert_eq!(layout.children[0].children[0].size.width, 40.0000);
+    }
+
+    #[test]
+    fn text_align_center_and_end() {
+        let aligned = |text_align| {
+            stretch::compute(&Node {
+                display: Display::Block,
+                text_align,
+                size: Size { width: Dimension::Points(100.0000), ..Default::default() },
+                children: Box::new(vec![Node { text: words(2, 20.0, 0.0), ..Default::default() }]),
+                ..Default::default()
+            })
+        };
+
+        assert_eq!(aligned(TextAlign::Center).children[0].location.x, 30.0000);
+        assert_eq!(aligned(TextAlign::End).children[0].location.x, 60.0000);
+        assert_eq!(aligned(TextAlign::Left).children[0].location.x, 0.0000);
+    }
+
+    #[test]
+    fn text_align_justify_spreads_all_but_last_line() {
+        let layout = stretch::compute(&Node {
+            display: Display::Block,
+            text_align: TextAlign::Justify,
+            size: Size { width: Dimension::Points(105.0000), ..Default::default() },
+            children: Box::new(vec![Node { text: words(6, 30.0, 5.0), ..Default::default() }]),
+            ..Default::default()
+        });
+
+        assert_eq!(layout.size.height, 20.0000);
+
+        assert_eq!(layout.children[0].children[1].location.x, 40.0000);
+        assert_eq!(layout.children[0].children[2].location.x, 80.0000);
+        assert_eq!(layout.children[0].children[4].location.x, 30.0000);
+    }
+
+    #[test]
+    fn inline_text_rtl() {
+        let layout = stretch::compute(&Node {
+            display: Display::Block,
+            direction: Direction::RTL,
+            size: Size { width: Dimension::Points(100.0000), ..Default::default() },
+            children: Box::new(vec![Node { text: words(2, 20.0, 0.0), ..Default::default() }]),
+            ..Default::default()
+        });
+
+        assert_eq!(layout.children[0].location.x, 60.0000);
+        assert_eq!(layout.children[0].children[0].location.x, 20.0000);
+        assert_eq!(layout.children[0].children[1].location.x, 0.0000);
+    }
+
+    #[test]
+    fn float_shortens_lines() {
+        let layout = stretch::compute(&Node {
+            display: Display::Block,
+            size: Size { width: Dimension::Points(100.0000), ..Default::default() },
+            children: Box::new(vec![
+                Node {
+                    display: Display::Block,
+                    float: Float::Left,
+                    size: Size { width: Dimension::Points(30.0000), height: Dimension::Points(15.0000) },
+                    ..Default::default()
+                },
+                Node { text: words(6, 20.0, 0.0), ..Default::default() },
+            ]),
+            ..Default::default()
+        });
+
+        assert_eq!(layout.size.height, 20.0000);
+
+        assert_eq!(layout.children[1].location.x, 30.0000);
+        assert_eq!(layout.children[1].children[3].location.x, 0.0000);
+        assert_eq!(layout.children[1].children[3].location.y, 10.0000);
+    }
+
+    #[test]
+    fn text_leaf_in_flex() {
+        let layout = stretch::compute(&Node {
+            size: Size { width: Dimension::Points(50.0000), ..Default::default() },
+            children: Box::new(vec![Node { text: words(4, 20.0, 0.0), ..Default::default() }]),
+            ..Default::default()
+        });
+
+        assert_eq!(layout.children[0].size.width, 50.0000);
+        assert_eq!(layout.children[0].size.height, 20.0000);
+        assert_eq!(layout.children[0].children[2].location.y, 10.0000);
+    }
+
+    #[test]
+    fn block_shrinks_to_fit_inline_content() {
+        let layout = stretch::compute(&Node {
+            display: Display::Block,
+            children: Box::new(vec![Node { text: words(3, 20.0, 5.0), ..Default::default() }]),
+            ..Default::default()
+        });
+
+        assert_eq!(layout.size.width, 55.0000);
+        assert_eq!(layout.size.height, 10.0000);
+    }
+}
@@ -0,0 +1,74 @@
+#[cfg(test)]
+mod inline_flex {
+    use stretch::geometry::Size;
+    use stretch::style::{Dimension, Display, FlexWrap, Node};
+
+    #[test]
+    fn inline_flex_shrinks_to_content() {
+        let layout = stretch::compute(&Node {
+            display: Display::Block,
+            size: Size { width: Dimension::Points(200.0000), ..Default::default() },
+            children: Box::new(vec![
+                Node {
+                    display: Display::InlineFlex,
+                    children: Box::new(vec![
+                        Node {
+                            size: Size { width: Dimension::Points(20.0000), height: Dimension::Points(10.0000) },
+                            ..Default::default()
+                        },
+                        Node {
+                            size: Size { width: Dimension::Points(30.0000), height: Dimension::Points(10.0000) },
+                            ..Default::default()
+                        },
+                    ]),
+                    ..Default::default()
+                },
+                Node {
+                    display: Display::InlineBlock,
+                    size: Size { width: Dimension::Points(40.0000), height: Dimension::Points(10.0000) },
+                    ..Default::default()
+                },
+            ]),
+            ..Default::default()
+        });
+
+        assert_eq!(layout.size.height, 10.0000);
+
+        assert_eq!(layout.children[0].size.width, 50.0000);
+        assert_eq!(layout.children[0].location.x, 0.0000);
+        assert_eq!(layout.children[1].location.x, 50.0000);
+        assert_eq!(layout.children[1].location.y, 0.0000);
+    }
+
+    #[test]
+    fn inline_flex_wrapping_fits_available_width() {
+        let layout = stretch::compute(&Node {
+            display: Display::Block,
+            size: Size { width: Dimension::Points(100.0000), ..Default::default() },
+            children: Box::new(vec![Node {
+                display: Display::InlineFlex,
+                flex_wrap: FlexWrap::Wrap,
+                children: Box::new(vec![
+                    Node {
+                        size: Size { width: Dimension::Points(40.0000), height: Dimension::Points(10.0000) },
+                        ..Default::default()
+                    },
+                    Node {
+                        size: Size { width: Dimension::Points(40.0000), height: Dimension::Points(10.0000) },
+                        ..Default::default()
+                    },
+                    Node {
+                        size: Size { width: Dimension::Points(40.0000), height: Dimension::Points(10.0000) },
+                        ..Default::default()
+                    },
+                ]),
+                ..Default::default()
+            }]),
+            ..Default::default()
+        });
+
+        assert_eq!(layout.children[0].size.width, 100.0000);
+        assert_eq!(layout.children[0].size.height, 20.0000);
+        assert_eq!(layout.children[0].children[2].location.y, 10.0000);
+    }
+}
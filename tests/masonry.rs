@@ -0,0 +1,139 @@
+#[cfg(test)]
+mod masonry {
+    use stretch::geometry::{Line, Size};
+    use stretch::style::{
+        Dimension, Direction, Display, GridPlacement, GridTrack, GridTrackRepetition, MasonryAutoFlow, Node,
+        TrackSizingFunction,
+    };
+
+    #[test]
+    fn masonry_packs_shortest_column() {
+        let layout = stretch::compute(&Node {
+            display: Display::Masonry,
+            size: Size { width: Dimension::Points(100.0000), ..Default::default() },
+            grid_template_columns: Box::new(vec![GridTrack::Single(TrackSizingFunction::fr(1.0)); 2]),
+            children: Box::new(vec![
+                Node { size: Size { height: Dimension::Points(30.0000), ..Default::default() }, ..Default::default() },
+                Node { size: Size { height: Dimension::Points(10.0000), ..Default::default() }, ..Default::default() },
+                Node { size: Size { height: Dimension::Points(10.0000), ..Default::default() }, ..Default::default() },
+                Node { size: Size { height: Dimension::Points(10.0000), ..Default::default() }, ..Default::default() },
+            ]),
+            ..Default::default()
+        });
+
+        assert_eq!(layout.size.width, 100.0000);
+        assert_eq!(layout.size.height, 30.0000);
+
+        assert_eq!(layout.children[0].size.width, 50.0000);
+        assert_eq!(layout.children[1].location.x, 50.0000);
+        assert_eq!(layout.children[1].location.y, 0.0000);
+        assert_eq!(layout.children[2].location.x, 50.0000);
+        assert_eq!(layout.children[2].location.y, 10.0000);
+        assert_eq!(layout.children[3].location.x, 50.0000);
+        assert_eq!(layout.children[3].location.y, 20.0000);
+    }
+
+    #[test]
+    fn masonry_next_places_sequentially() {
+        let layout = stretch::compute(&Node {
+            display: Display::Masonry,
+            masonry_auto_flow: MasonryAutoFlow::Next,
+            size: Size { width: Dimension::Points(100.0000), ..Default::default() },
+            grid_template_columns: Box::new(vec![GridTrack::Single(TrackSizingFunction::fr(1.0)); 2]),
+            children: Box::new(vec![
+                Node { size: Size { height: Dimension::Points(30.0000), ..Default::default() }, ..Default::default() },
+                Node { size: Size { height: Dimension::Points(10.0000), ..Default::default() }, ..Default::default() },
+                Node { size: Size { height: Dimension::Points(10.0000), ..Default::default() }, ..Default::default() },
+                Node { size: Size { height: Dimension::Points(10.0000), ..Default::default() }, ..Default::default() },
+            ]),
+            ..Default::default()
+        });
+
+        assert_eq!(layout.size.height, 40.0000);
+
+        assert_eq!(layout.children[2].location.x, 0.0000);
+        assert_eq!(layout.children[2].location.y, 30.0000);
+        assert_eq!(layout.children[3].location.x, 50.0000);
+        assert_eq!(layout.children[3].location.y, 10.0000);
+    }
+
+    #[test]
+    fn masonry_auto_fill_columns_and_gap() {
+        let layout = stretch::compute(&Node {
+            display: Display::Masonry,
+            size: Size { width: Dimension::Points(100.0000), ..Default::default() },
+            gap: Size { width: Dimension::Points(5.0000), height: Dimension::Points(4.0000) },
+            grid_template_columns: Box::new(vec![GridTrack::Repeat(
+                GridTrackRepetition::AutoFill,
+                vec![TrackSizingFunction::points(30.0)],
+            )]),
+            children: Box::new(vec![
+                Node { size: Size { height: Dimension::Points(10.0000), ..Default::default() }, ..Default::default() },
+                Node { size: Size { height: Dimension::Points(20.0000), ..Default::default() }, ..Default::default() },
+                Node { size: Size { height: Dimension::Points(10.0000), ..Default::default() }, ..Default::default() },
+                Node { size: Size { height: Dimension::Points(10.0000), ..Default::default() }, ..Default::default() },
+            ]),
+            ..Default::default()
+        });
+
+        assert_eq!(layout.size.height, 24.0000);
+
+        assert_eq!(layout.children[0].size.width, 30.0000);
+        assert_eq!(layout.children[1].location.x, 35.0000);
+        assert_eq!(layout.children[2].location.x, 70.0000);
+        assert_eq!(layout.children[3].location.x, 0.0000);
+        assert_eq!(layout.children[3].location.y, 14.0000);
+    }
+
+    #[test]
+    fn masonry_explicit_column_and_span() {
+        let layout = stretch::compute(&Node {
+            display: Display::Masonry,
+            size: Size { width: Dimension::Points(100.0000), ..Default::default() },
+            grid_template_columns: Box::new(vec![GridTrack::Single(TrackSizingFunction::fr(1.0)); 2]),
+            children: Box::new(vec![
+                Node {
+                    grid_column: Line { start: GridPlacement::Line(2), end: GridPlacement::Auto },
+                    size: Size { height: Dimension::Points(20.0000), ..Default::default() },
+                    ..Default::default()
+                },
+                Node { size: Size { height: Dimension::Points(10.0000), ..Default::default() }, ..Default::default() },
+                Node {
+                    grid_column: Line { start: GridPlacement::Span(2), end: GridPlacement::Auto },
+                    size: Size { height: Dimension::Points(10.0000), ..Default::default() },
+                    ..Default::default()
+                },
+            ]),
+            ..Default::default()
+        });
+
+        assert_eq!(layout.size.height, 30.0000);
+
+        assert_eq!(layout.children[0].location.x, 50.0000);
+        assert_eq!(layout.children[1].location.x, 0.0000);
+        assert_eq!(layout.children[2].location.x, 0.0000);
+        assert_eq!(layout.children[2].location.y, 20.0000);
+        assert_eq!(layout.children[2].size.width, 100.0000);
+    }
+
+    #[test]
+    fn masonry_rtl() {
+        let layout = stretch::compute(&Node {
+            display: Display::Masonry,
+            direction: Direction::RTL,
+            size: Size { width: Dimension::Points(100.0000), ..Default::default() },
+            grid_template_columns: Box::new(vec![GridTrack::Single(TrackSizingFunction::fr(1.0)); 2]),
+            children: Box::new(vec![
+                Node { size: Size { height: Dimension::Points(30.0000), ..Default::default() }, ..Default::default() },
+                Node { size: Size { height: Dimension::Points(10.0000), ..Default::default() }, ..Default::default() },
+                Node { size: Size { height: Dimension::Points(10.0000), ..Default::default() }, ..Default::default() },
+            ]),
+            ..Default::default()
+        });
+
+        assert_eq!(layout.children[0].location.x, 50.0000);
+        assert_eq!(layout.children[1].location.x, 0.0000);
+        assert_eq!(layout.children[2].location.x, 0.0000);
+        assert_eq!(layout.children[2].location.y, 10.0000);
+    }
+}
@@ -0,0 +1,200 @@
+mod common;
+
+#[cfg(test)]
+mod measure {
+    use super::common::text;
+
+    #[test]
+    fn measure_root() {
+        let layout = stretch::compute(&stretch::style::Node {
+            measure: Some(Box::new(|_, _| stretch::geometry::Size { width: 100.0, height: 100.0 })),
+            ..Default::default()
+        });
+
+        assert_eq!(layout.size.width, 100.0000);
+        assert_eq!(layout.size.height, 100.0000);
+    }
+
+    #[test]
+    fn measure_child() {
+        let layout = stretch::compute(&stretch::style::Node {
+            children: Box::new(vec![stretch::style::Node {
+                measure: Some(Box::new(|_, _| stretch::geometry::Size { width: 100.0, height: 50.0 })),
+                ..Default::default()
+            }]),
+            ..Default::default()
+        });
+
+        assert_eq!(layout.size.width, 100.0000);
+        assert_eq!(layout.size.height, 50.0000);
+
+        assert_eq!(layout.children[0].size.width, 100.0000);
+        assert_eq!(layout.children[0].size.height, 50.0000);
+    }
+
+    #[test]
+    fn measure_child_with_padding() {
+        let layout = stretch::compute(&stretch::style::Node {
+            children: Box::new(vec![stretch::style::Node {
+                padding: stretch::geometry::Rect {
+                    start: stretch::style::Dimension::Points(10.0000),
+                    end: stretch::style::Dimension::Points(10.0000),
+                    top: stretch::style::Dimension::Points(10.0000),
+                    bottom: stretch::style::Dimension::Points(10.0000),
+                },
+                measure: Some(Box::new(|_, _| stretch::geometry::Size { width: 100.0, height: 50.0 })),
+                ..Default::default()
+            }]),
+            ..Default::default()
+        });
+
+        assert_eq!(layout.size.width, 120.0000);
+        assert_eq!(layout.size.height, 70.0000);
+
+        assert_eq!(layout.children[0].size.width, 120.0000);
+        assert_eq!(layout.children[0].size.height, 70.0000);
+    }
+
+    #[test]
+    fn measure_child_with_known_width_and_padding() {
+        let layout = stretch::compute(&stretch::style::Node {
+            children: Box::new(vec![stretch::style::Node {
+                size: stretch::geometry::Size {
+                    width: stretch::style::Dimension::Points(70.0000),
+                    ..Default::default()
+                },
+                padding: stretch::geometry::Rect {
+                    start: stretch::style::Dimension::Points(10.0000),
+                    end: stretch::style::Dimension::Points(10.0000),
+                    top: stretch::style::Dimension::Points(5.0000),
+                    bottom: stretch::style::Dimension::Points(5.0000),
+                },
+                measure: Some(Box::new(text)),
+                ..Default::default()
+            }]),
+            ..Default::default()
+        });
+
+        assert_eq!(layout.children[0].size.width, 70.0000);
+        assert_eq!(layout.children[0].size.height, 70.0000);
+    }
+
+    #[test]
+    fn measure_child_with_max_size() {
+        let layout = stretch::compute(&stretch::style::Node {
+            children: Box::new(vec![stretch::style::Node {
+                max_size: stretch::geometry::Size {
+                    width: stretch::style::Dimension::Points(80.0000),
+                    height: stretch::style::Dimension::Points(40.0000),
+                },
+                measure: Some(Box::new(|_, _| stretch::geometry::Size { width: 100.0, height: 50.0 })),
+                ..Default::default()
+            }]),
+            ..Default::default()
+        });
+
+        assert_eq!(layout.children[0].size.width, 80.0000);
+        assert_eq!(layout.children[0].size.height, 40.0000);
+    }
+
+    #[test]
+    fn measure_child_with_percentage_min_height() {
+        let layout = stretch::compute(&stretch::style::Node {
+            display: stretch::style::Display::Block,
+            size: stretch::geometry::Size { width: stretch::style::Dimension::Points(200.0000), ..Default::default() },
+            children: Box::new(vec![stretch::style::Node {
+                min_size: stretch::geometry::Size {
+                    height: stretch::style::Dimension::Percent(0.5000),
+                    ..Default::default()
+                },
+                measure: Some(Box::new(|_, _| stretch::geometry::Size { width: 100.0, height: 50.0 })),
+                ..Default::default()
+            }]),
+            ..Default::default()
+        });
+
+        assert_eq!(layout.size.height, 100.0000);
+
+        assert_eq!(layout.children[0].size.width, 200.0000);
+        assert_eq!(layout.children[0].size.height, 100.0000);
+    }
+
+    #[test]
+    fn measure_child_with_known_width() {
+        let layout = stretch::compute(&stretch::style::Node {
+            children: Box::new(vec![stretch::style::Node {
+                size: stretch::geometry::Size {
+                    width: stretch::style::Dimension::Points(50.0000),
+                    ..Default::default()
+                },
+                measure: Some(Box::new(text)),
+                ..Default::default()
+            }]),
+            ..Default::default()
+        });
+
+        assert_eq!(layout.size.width, 50.0000);
+        assert_eq!(layout.size.height, 60.0000);
+
+        assert_eq!(layout.children[0].size.width, 50.0000);
+        assert_eq!(layout.children[0].size.height, 60.0000);
+    }
+
+    #[test]
+    fn measure_height_for_width_in_column() {
+        let layout = stretch::compute(&stretch::style::Node {
+            flex_direction: stretch::style::FlexDirection::Column,
+            size: stretch::geometry::Size { width: stretch::style::Dimension::Points(100.0000), ..Default::default() },
+            children: Box::new(vec![stretch::style::Node { measure: Some(Box::new(text)), ..Default::default() }]),
+            ..Default::default()
+        });
+
+        assert_eq!(layout.size.width, 100.0000);
+        assert_eq!(layout.size.height, 30.0000);
+
+        assert_eq!(layout.children[0].size.width, 100.0000);
+        assert_eq!(layout.children[0].size.height, 30.0000);
+    }
+
+    #[test]
+    fn measure_height_for_width_in_row() {
+        let layout = stretch::compute(&stretch::style::Node {
+            size: stretch::geometry::Size { width: stretch::style::Dimension::Points(400.0000), ..Default::default() },
+            children: Box::new(vec![stretch::style::Node {
+                flex_grow: 1.0,
+                measure: Some(Box::new(text)),
+                ..Default::default()
+            }]),
+            ..Default::default()
+        });
+
+        assert_eq!(layout.size.width, 400.0000);
+        assert_eq!(layout.size.height, 10.0000);
+
+        assert_eq!(layout.children[0].size.width, 400.0000);
+        assert_eq!(layout.children[0].size.height, 10.0000);
+    }
+
+    #[test]
+    fn measure_shrink_to_min_content_in_row() {
+        let layout = stretch::compute(&stretch::style::Node {
+            size: stretch::geometry::Size { width: stretch::style::Dimension::Points(15.0000), ..Default::default() },
+            children: Box::new(vec![
+                stretch::style::Node { measure: Some(Box::new(text)), ..Default::default() },
+                stretch::style::Node { measure: Some(Box::new(text)), ..Default::default() },
+            ]),
+            ..Default::default()
+        });
+
+        assert_eq!(layout.size.width, 15.0000);
+        assert_eq!(layout.size.height, 300.0000);
+
+        assert_eq!(layout.children[0].size.width, 10.0000);
+        assert_eq!(layout.children[0].size.height, 300.0000);
+        assert_eq!(layout.children[0].location.x, 0.0000);
+
+        assert_eq!(layout.children[1].size.width, 10.0000);
+        assert_eq!(layout.children[1].size.height, 300.0000);
+        assert_eq!(layout.children[1].location.x, 10.0000);
+    }
+}
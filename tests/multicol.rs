@@ -0,0 +1,230 @@
+#[cfg(test)]
+mod multicol {
+    use stretch::geometry::Size;
+    use stretch::style::{ColumnFill, Dimension, Direction, Display, Node, PositionType};
+
+    #[test]
+    fn multicol_balances_columns() {
+        let layout = stretch::compute(&Node {
+            display: Display::Block,
+            column_count: 2,
+            size: Size { width: Dimension::Points(100.0000), ..Default::default() },
+            children: Box::new(vec![
+                Node {
+                    display: Display::Block,
+                    size: Size { height: Dimension::Points(10.0000), ..Default::default() },
+                    ..Default::default()
+                },
+                Node {
+                    display: Display::Block,
+                    size: Size { height: Dimension::Points(10.0000), ..Default::default() },
+                    ..Default::default()
+                },
+                Node {
+                    display: Display::Block,
+                    size: Size { height: Dimension::Points(10.0000), ..Default::default() },
+                    ..Default::default()
+                },
+                Node {
+                    display: Display::Block,
+                    size: Size { height: Dimension::Points(10.0000), ..Default::default() },
+                    ..Default::default()
+                },
+            ]),
+            ..Default::default()
+        });
+
+        assert_eq!(layout.size.width, 100.0000);
+        assert_eq!(layout.size.height, 20.0000);
+
+        assert_eq!(layout.children[0].size.width, 50.0000);
+        assert_eq!(layout.children[1].location.x, 0.0000);
+        assert_eq!(layout.children[1].location.y, 10.0000);
+        assert_eq!(layout.children[2].location.x, 50.0000);
+        assert_eq!(layout.children[2].location.y, 0.0000);
+        assert_eq!(layout.children[3].location.y, 10.0000);
+    }
+
+    #[test]
+    fn multicol_balances_uneven_children() {
+        let layout = stretch::compute(&Node {
+            display: Display::Block,
+            column_count: 2,
+            size: Size { width: Dimension::Points(100.0000), ..Default::default() },
+            children: Box::new(vec![
+                Node {
+                    display: Display::Block,
+                    size: Size { height: Dimension::Points(30.0000), ..Default::default() },
+                    ..Default::default()
+                },
+                Node {
+                    display: Display::Block,
+                    size: Size { height: Dimension::Points(10.0000), ..Default::default() },
+                    ..Default::default()
+                },
+                Node {
+                    display: Display::Block,
+                    size: Size { height: Dimension::Points(10.0000), ..Default::default() },
+                    ..Default::default()
+                },
+                Node {
+                    display: Display::Block,
+                    size: Size { height: Dimension::Points(10.0000), ..Default::default() },
+                    ..Default::default()
+                },
+            ]),
+            ..Default::default()
+        });
+
+        assert_eq!(layout.size.height, 30.0000);
+
+        assert_eq!(layout.children[1].location.x, 50.0000);
+        assert_eq!(layout.children[1].location.y, 0.0000);
+        assert_eq!(layout.children[3].location.x, 50.0000);
+        assert_eq!(layout.children[3].location.y, 20.0000);
+    }
+
+    #[test]
+    fn multicol_column_width_and_gap() {
+        let layout = stretch::compute(&Node {
+            display: Display::Block,
+            column_width: Dimension::Points(30.0000),
+            size: Size { width: Dimension::Points(100.0000), ..Default::default() },
+            gap: Size { width: Dimension::Points(5.0000), ..Default::default() },
+            children: Box::new(vec![
+                Node {
+                    display: Display::Block,
+                    size: Size { height: Dimension::Points(10.0000), ..Default::default() },
+                    ..Default::default()
+                },
+                Node {
+                    display: Display::Block,
+                    size: Size { height: Dimension::Points(10.0000), ..Default::default() },
+                    ..Default::default()
+                },
+                Node {
+                    display: Display::Block,
+                    size: Size { height: Dimension::Points(10.0000), ..Default::default() },
+                    ..Default::default()
+                },
+            ]),
+            ..Default::default()
+        });
+
+        assert_eq!(layout.size.height, 10.0000);
+
+        assert_eq!(layout.children[0].size.width, 30.0000);
+        assert_eq!(layout.children[1].location.x, 35.0000);
+        assert_eq!(layout.children[2].location.x, 70.0000);
+        assert_eq!(layout.children[2].location.y, 0.0000);
+    }
+
+    #[test]
+    fn multicol_fill_auto_overflows() {
+        let layout = stretch::compute(&Node {
+            display: Display::Block,
+            column_count: 2,
+            column_fill: ColumnFill::Auto,
+            size: Size { width: Dimension::Points(100.0000), height: Dimension::Points(20.0000) },
+            children: Box::new(vec![
+                Node {
+                    display: Display::Block,
+                    size: Size { height: Dimension::Points(10.0000), ..Default::default() },
+                    ..Default::default()
+                },
+                Node {
+                    display: Display::Block,
+                    size: Size { height: Dimension::Points(10.0000), ..Default::default() },
+                    ..Default::default()
+                },
+                Node {
+                    display: Display::Block,
+                    size: Size { height: Dimension::Points(10.0000), ..Default::default() },
+                    ..Default::default()
+                },
+                Node {
+                    display: Display::Block,
+                    size: Size { height: Dimension::Points(10.0000), ..Default::default() },
+                    ..Default::default()
+                },
+                Node {
+                    display: Display::Block,
+                    size: Size { height: Dimension::Points(10.0000), ..Default::default() },
+                    ..Default::default()
+                },
+            ]),
+            ..Default::default()
+        });
+
+        assert_eq!(layout.size.height, 20.0000);
+        assert_eq!(layout.content_size.width, 150.0000);
+
+        assert_eq!(layout.children[2].location.x, 50.0000);
+        assert_eq!(layout.children[2].location.y, 0.0000);
+        assert_eq!(layout.children[4].location.x, 100.0000);
+        assert_eq!(layout.children[4].location.y, 0.0000);
+    }
+
+    #[test]
+    fn multicol_rtl() {
+        let layout = stretch::compute(&Node {
+            display: Display::Block,
+            direction: Direction::RTL,
+            column_count: 2,
+            size: Size { width: Dimension::Points(100.0000), ..Default::default() },
+            children: Box::new(vec![
+                Node {
+                    display: Display::Block,
+                    size: Size { width: Dimension::Points(20.0000), height: Dimension::Points(10.0000) },
+                    ..Default::default()
+                },
+                Node {
+                    display: Display::Block,
+                    size: Size { width: Dimension::Points(20.0000), height: Dimension::Points(10.0000) },
+                    ..Default::default()
+                },
+            ]),
+            ..Default::default()
+        });
+
+        assert_eq!(layout.size.height, 10.0000);
+
+        assert_eq!(layout.children[0].location.x, 80.0000);
+        assert_eq!(layout.children[1].location.x, 30.0000);
+        assert_eq!(layout.children[1].location.y, 0.0000);
+    }
+
+    #[test]
+    fn multicol_shrinks_to_fit_columns() {
+        let layout = stretch::compute(&Node {
+            display: Display::Block,
+            column_count: 2,
+            children: Box::new(vec![
+                Node {
+                    display: Display::Block,
+                    size: Size { width: Dimension::Points(20.0000), height: Dimension::Points(10.0000) },
+                    ..Default::default()
+                },
+                Node {
+                    display: Display::Block,
+                    size: Size { width: Dimension::Points(20.0000), height: Dimension::Points(10.0000) },
+                    ..Default::default()
+                },
+                Node {
+                    display: Display::Block,
+                    position_type: PositionType::Absolute,
+                    size: Size { height: Dimension::Points(10.0000), ..Default::default() },
+                    ..Default::default()
+                },
+            ]),
+            ..Default::default()
+        });
+
+        assert_eq!(layout.size.width, 40.0000);
+        assert_eq!(layout.size.height, 10.0000);
+
+        assert_eq!(layout.children[1].location.x, 20.0000);
+        assert_eq!(layout.children[2].location.x, 20.0000);
+        assert_eq!(layout.children[2].location.y, 10.0000);
+    }
+}
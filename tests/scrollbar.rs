@@ -0,0 +1,134 @@
+#[cfg(test)]
+mod scrollbar {
+    #[test]
+    fn scrollbar_reserved_at_inline_end() {
+        let layout = stretch::compute(&stretch::style::Node {
+            overflow_y: stretch::style::Overflow::Scroll,
+            scrollbar_width: 15.0,
+            size: stretch::geometry::Size { width: stretch::style::Dimension::Points(100.0000), ..Default::default() },
+            children: Box::new(vec![stretch::style::Node {
+                flex_grow: 1.0,
+                size: stretch::geometry::Size {
+                    height: stretch::style::Dimension::Points(10.0000),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }]),
+            ..Default::default()
+        });
+
+        assert_eq!(layout.size.width, 100.0000);
+        assert_eq!(layout.children[0].size.width, 85.0000);
+        assert_eq!(layout.children[0].location.x, 0.0000);
+    }
+
+    #[test]
+    fn scrollbar_reserved_at_inline_end_rtl() {
+        let layout = stretch::compute(&stretch::style::Node {
+            direction: stretch::style::Direction::RTL,
+            overflow_y: stretch::style::Overflow::Scroll,
+            scrollbar_width: 15.0,
+            size: stretch::geometry::Size { width: stretch::style::Dimension::Points(100.0000), ..Default::default() },
+            children: Box::new(vec![stretch::style::Node {
+                flex_grow: 1.0,
+                size: stretch::geometry::Size {
+                    height: stretch::style::Dimension::Points(10.0000),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }]),
+            ..Default::default()
+        });
+
+        assert_eq!(layout.children[0].size.width, 85.0000);
+        assert_eq!(layout.children[0].location.x, 15.0000);
+    }
+
+    #[test]
+    fn scrollbar_not_reserved_when_hidden() {
+        let layout = stretch::compute(&stretch::style::Node {
+            overflow_x: stretch::style::Overflow::Hidden,
+            overflow_y: stretch::style::Overflow::Hidden,
+            scrollbar_width: 15.0,
+            size: stretch::geometry::Size { width: stretch::style::Dimension::Points(100.0000), ..Default::default() },
+            children: Box::new(vec![stretch::style::Node {
+                flex_grow: 1.0,
+                size: stretch::geometry::Size {
+                    height: stretch::style::Dimension::Points(10.0000),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }]),
+            ..Default::default()
+        });
+
+        assert_eq!(layout.children[0].size.width, 100.0000);
+    }
+
+    #[test]
+    fn scrollbar_gutter_stable_when_hidden() {
+        let layout = stretch::compute(&stretch::style::Node {
+            overflow_x: stretch::style::Overflow::Hidden,
+            overflow_y: stretch::style::Overflow::Hidden,
+            scrollbar_gutter: stretch::style::ScrollbarGutter::Stable,
+            scrollbar_width: 15.0,
+            size: stretch::geometry::Size { width: stretch::style::Dimension::Points(100.0000), ..Default::default() },
+            children: Box::new(vec![stretch::style::Node {
+                flex_grow: 1.0,
+                size: stretch::geometry::Size {
+                    height: stretch::style::Dimension::Points(10.0000),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }]),
+            ..Default::default()
+        });
+
+        assert_eq!(layout.children[0].size.width, 85.0000);
+        assert_eq!(layout.children[0].location.x, 0.0000);
+    }
+
+    #[test]
+    fn scrollbar_gutter_stable_both_edges() {
+        let layout = stretch::compute(&stretch::style::Node {
+            overflow_y: stretch::style::Overflow::Scroll,
+            scrollbar_gutter: stretch::style::ScrollbarGutter::StableBothEdges,
+            scrollbar_width: 15.0,
+            size: stretch::geometry::Size { width: stretch::style::Dimension::Points(100.0000), ..Default::default() },
+            children: Box::new(vec![stretch::style::Node {
+                flex_grow: 1.0,
+                size: stretch::geometry::Size {
+                    height: stretch::style::Dimension::Points(10.0000),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }]),
+            ..Default::default()
+        });
+
+        assert_eq!(layout.children[0].size.width, 70.0000);
+        assert_eq!(layout.children[0].location.x, 15.0000);
+    }
+
+    #[test]
+    fn scrollbar_reserved_at_bottom() {
+        let layout = stretch::compute(&stretch::style::Node {
+            overflow_x: stretch::style::Overflow::Scroll,
+            scrollbar_width: 15.0,
+            size: stretch::geometry::Size { width: stretch::style::Dimension::Points(100.0000), ..Default::default() },
+            children: Box::new(vec![stretch::style::Node {
+                flex_grow: 1.0,
+                size: stretch::geometry::Size {
+                    height: stretch::style::Dimension::Points(10.0000),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }]),
+            ..Default::default()
+        });
+
+        assert_eq!(layout.size.height, 25.0000);
+        assert_eq!(layout.children[0].size.width, 100.0000);
+        assert_eq!(layout.children[0].size.height, 10.0000);
+    }
+}
@@ -0,0 +1,152 @@
+#[cfg(test)]
+mod stack {
+    use stretch::geometry::{Rect, Size};
+    use stretch::style::{AlignSelf, Dimension, Direction, Display, JustifySelf, Node};
+
+    #[test]
+    fn stack_sizes_to_largest_child() {
+        let layout = stretch::compute(&Node {
+            display: Display::Stack,
+            children: Box::new(vec![
+                Node {
+                    size: Size { width: Dimension::Points(100.0000), height: Dimension::Points(50.0000) },
+                    ..Default::default()
+                },
+                Node {
+                    margin: Rect { top: Dimension::Points(10.0000), ..Default::default() },
+                    size: Size { width: Dimension::Points(30.0000), height: Dimension::Points(80.0000) },
+                    ..Default::default()
+                },
+            ]),
+            ..Default::default()
+        });
+
+        assert_eq!(layout.size.width, 100.0000);
+        assert_eq!(layout.size.height, 90.0000);
+
+        assert_eq!(layout.children[0].location.x, 0.0000);
+        assert_eq!(layout.children[0].location.y, 0.0000);
+        assert_eq!(layout.children[1].location.x, 0.0000);
+        assert_eq!(layout.children[1].location.y, 10.0000);
+    }
+
+    #[test]
+    fn stack_anchors_children() {
+        let layout = stretch::compute(&Node {
+            display: Display::Stack,
+            size: Size { width: Dimension::Points(100.0000), height: Dimension::Points(100.0000) },
+            children: Box::new(vec![
+                Node {
+                    justify_self: JustifySelf::Start,
+                    align_self: AlignSelf::Start,
+                    size: Size { width: Dimension::Points(20.0000), height: Dimension::Points(20.0000) },
+                    ..Default::default()
+                },
+                Node {
+                    justify_self: JustifySelf::Center,
+                    align_self: AlignSelf::Center,
+                    size: Size { width: Dimension::Points(20.0000), height: Dimension::Points(20.0000) },
+                    ..Default::default()
+                },
+                Node {
+                    justify_self: JustifySelf::End,
+                    align_self: AlignSelf::End,
+                    size: Size { width: Dimension::Points(20.0000), height: Dimension::Points(20.0000) },
+                    ..Default::default()
+                },
+                Node {
+                    justify_self: JustifySelf::Right,
+                    align_self: AlignSelf::FlexStart,
+                    size: Size { width: Dimension::Points(20.0000), height: Dimension::Points(20.0000) },
+                    ..Default::default()
+                },
+            ]),
+            ..Default::default()
+        });
+
+        assert_eq!(layout.children[0].location.x, 0.0000);
+        assert_eq!(layout.children[0].location.y, 0.0000);
+        assert_eq!(layout.children[1].location.x, 40.0000);
+        assert_eq!(layout.children[1].location.y, 40.0000);
+        assert_eq!(layout.children[2].location.x, 80.0000);
+        assert_eq!(layout.children[2].location.y, 80.0000);
+        assert_eq!(layout.children[3].location.x, 80.0000);
+        assert_eq!(layout.children[3].location.y, 0.0000);
+    }
+
+    #[test]
+    fn stack_stretches_children_without_size() {
+        let layout = stretch::compute(&Node {
+            display: Display::Stack,
+            size: Size { width: Dimension::Points(100.0000), height: Dimension::Points(60.0000) },
+            children: Box::new(vec![Node {
+                margin: Rect {
+                    start: Dimension::Points(10.0000),
+                    end: Dimension::Points(10.0000),
+                    top: Dimension::Points(10.0000),
+                    bottom: Dimension::Points(10.0000),
+                },
+                ..Default::default()
+            }]),
+            ..Default::default()
+        });
+
+        assert_eq!(layout.children[0].size.width, 80.0000);
+        assert_eq!(layout.children[0].size.height, 40.0000);
+        assert_eq!(layout.children[0].location.x, 10.0000);
+        assert_eq!(layout.children[0].location.y, 10.0000);
+    }
+
+    #[test]
+    fn stack_badge_over_avatar() {
+        let layout = stretch::compute(&Node {
+            display: Display::Stack,
+            children: Box::new(vec![
+                Node {
+                    size: Size { width: Dimension::Points(40.0000), height: Dimension::Points(40.0000) },
+                    ..Default::default()
+                },
+                Node {
+                    justify_self: JustifySelf::End,
+                    align_self: AlignSelf::Start,
+                    size: Size { width: Dimension::Points(20.0000), height: Dimension::Points(20.0000) },
+                    ..Default::default()
+                },
+            ]),
+            ..Default::default()
+        });
+
+        assert_eq!(layout.size.width, 40.0000);
+        assert_eq!(layout.size.height, 40.0000);
+
+        assert_eq!(layout.children[1].location.x, 20.0000);
+        assert_eq!(layout.children[1].location.y, 0.0000);
+    }
+
+    #[test]
+    fn stack_rtl() {
+        let layout = stretch::compute(&Node {
+            display: Display::Stack,
+            direction: Direction::RTL,
+            size: Size { width: Dimension::Points(100.0000), height: Dimension::Points(100.0000) },
+            children: Box::new(vec![
+                Node {
+                    justify_self: JustifySelf::Start,
+                    align_self: AlignSelf::Start,
+                    size: Size { width: Dimension::Points(20.0000), height: Dimension::Points(20.0000) },
+                    ..Default::default()
+                },
+                Node {
+                    justify_self: JustifySelf::Left,
+                    align_self: AlignSelf::Start,
+                    size: Size { width: Dimension::Points(20.0000), height: Dimension::Points(20.0000) },
+                    ..Default::default()
+                },
+            ]),
+            ..Default::default()
+        });
+
+        assert_eq!(layout.children[0].location.x, 80.0000);
+        assert_eq!(layout.children[1].location.x, 0.0000);
+    }
+}
@@ -0,0 +1,307 @@
+#[cfg(test)]
+mod table {
+    use stretch::geometry::Size;
+    use stretch::style::{Dimension, Direction, Display, Node, TableLayout};
+
+    #[test]
+    fn table_columns_share_widest_cell() {
+        let layout = stretch::compute(&Node {
+            display: Display::Table,
+            children: Box::new(vec![
+                Node {
+                    display: Display::TableRow,
+                    children: Box::new(vec![
+                        Node {
+                            display: Display::TableCell,
+                            size: Size { width: Dimension::Points(30.0000), height: Dimension::Points(10.0000) },
+                            ..Default::default()
+                        },
+                        Node {
+                            display: Display::TableCell,
+                            size: Size { width: Dimension::Points(10.0000), height: Dimension::Points(10.0000) },
+                            ..Default::default()
+                        },
+                    ]),
+                    ..Default::default()
+                },
+                Node {
+                    display: Display::TableRow,
+                    children: Box::new(vec![
+                        Node {
+                            display: Display::TableCell,
+                            size: Size { width: Dimension::Points(10.0000), height: Dimension::Points(10.0000) },
+                            ..Default::default()
+                        },
+                        Node {
+                            display: Display::TableCell,
+                            size: Size { width: Dimension::Points(50.0000), height: Dimension::Points(10.0000) },
+                            ..Default::default()
+                        },
+                    ]),
+                    ..Default::default()
+                },
+            ]),
+            ..Default::default()
+        });
+
+        assert_eq!(layout.size.width, 80.0000);
+        assert_eq!(layout.size.height, 20.0000);
+
+        assert_eq!(layout.children[0].size.width, 80.0000);
+        assert_eq!(layout.children[1].location.y, 10.0000);
+
+        assert_eq!(layout.children[0].children[1].size.width, 50.0000);
+        assert_eq!(layout.children[0].children[1].location.x, 30.0000);
+        assert_eq!(layout.children[1].children[0].size.width, 30.0000);
+        assert_eq!(layout.children[1].children[0].location.x, 0.0000);
+    }
+
+    #[test]
+    fn table_border_spacing() {
+        let layout = stretch::compute(&Node {
+            border_spacing: Size { width: Dimension::Points(5.0000), height: Dimension::Points(2.0000) },
+            display: Display::Table,
+            children: Box::new(vec![
+                Node {
+                    display: Display::TableRow,
+                    children: Box::new(vec![
+                        Node {
+                            display: Display::TableCell,
+                            size: Size { width: Dimension::Points(30.0000), height: Dimension::Points(10.0000) },
+                            ..Default::default()
+                        },
+                        Node {
+                            display: Display::TableCell,
+                            size: Size { width: Dimension::Points(10.0000), height: Dimension::Points(10.0000) },
+                            ..Default::default()
+                        },
+                    ]),
+                    ..Default::default()
+                },
+                Node {
+                    display: Display::TableRow,
+                    children: Box::new(vec![
+                        Node {
+                            display: Display::TableCell,
+                            size: Size { width: Dimension::Points(10.0000), height: Dimension::Points(10.0000) },
+                            ..Default::default()
+                        },
+                        Node {
+                            display: Display::TableCell,
+                            size: Size { width: Dimension::Points(50.0000), height: Dimension::Points(10.0000) },
+                            ..Default::default()
+                        },
+                    ]),
+                    ..Default::default()
+                },
+            ]),
+            ..Default::default()
+        });
+
+        assert_eq!(layout.size.width, 95.0000);
+        assert_eq!(layout.size.height, 26.0000);
+
+        assert_eq!(layout.children[0].location.x, 5.0000);
+        assert_eq!(layout.children[0].location.y, 2.0000);
+        assert_eq!(layout.children[1].location.y, 14.0000);
+        assert_eq!(layout.children[1].children[1].location.x, 35.0000);
+    }
+
+    #[test]
+    fn table_column_span_widens_columns() {
+        let layout = stretch::compute(&Node {
+            display: Display::Table,
+            children: Box::new(vec![
+                Node {
+                    display: Display::TableRow,
+                    children: Box::new(vec![Node {
+                        display: Display::TableCell,
+                        column_span: 2,
+                        size: Size { width: Dimension::Points(100.0000), height: Dimension::Points(10.0000) },
+                        ..Default::default()
+                    }]),
+                    ..Default::default()
+                },
+                Node {
+                    display: Display::TableRow,
+                    children: Box::new(vec![
+                        Node {
+                            display: Display::TableCell,
+                            size: Size { width: Dimension::Points(20.0000), height: Dimension::Points(10.0000) },
+                            ..Default::default()
+                        },
+                        Node {
+                            display: Display::TableCell,
+                            size: Size { width: Dimension::Points(20.0000), height: Dimension::Points(10.0000) },
+                            ..Default::default()
+                        },
+                    ]),
+                    ..Default::default()
+                },
+            ]),
+            ..Default::default()
+        });
+
+        assert_eq!(layout.size.width, 100.0000);
+
+        assert_eq!(layout.children[0].children[0].size.width, 100.0000);
+        assert_eq!(layout.children[1].children[0].size.width, 50.0000);
+        assert_eq!(layout.children[1].children[1].location.x, 50.0000);
+    }
+
+    #[test]
+    fn table_row_span_grows_rows() {
+        let layout = stretch::compute(&Node {
+            display: Display::Table,
+            children: Box::new(vec![
+                Node {
+                    display: Display::TableRow,
+                    children: Box::new(vec![
+                        Node {
+                            display: Display::TableCell,
+                            row_span: 2,
+                            size: Size { width: Dimension::Points(10.0000), height: Dimension::Points(50.0000) },
+                            ..Default::default()
+                        },
+                        Node {
+                            display: Display::TableCell,
+                            size: Size { width: Dimension::Points(10.0000), height: Dimension::Points(10.0000) },
+                            ..Default::default()
+                        },
+                    ]),
+                    ..Default::default()
+                },
+                Node {
+                    display: Display::TableRow,
+                    children: Box::new(vec![Node {
+                        display: Display::TableCell,
+                        size: Size { width: Dimension::Points(10.0000), height: Dimension::Points(10.0000) },
+                        ..Default::default()
+                    }]),
+                    ..Default::default()
+                },
+            ]),
+            ..Default::default()
+        });
+
+        assert_eq!(layout.size.height, 50.0000);
+
+        assert_eq!(layout.children[0].size.height, 25.0000);
+        assert_eq!(layout.children[0].children[0].size.height, 50.0000);
+        assert_eq!(layout.children[1].location.y, 25.0000);
+        assert_eq!(layout.children[1].children[0].location.x, 10.0000);
+    }
+
+    #[test]
+    fn table_auto_layout_distributes_extra_width() {
+        let layout = stretch::compute(&Node {
+            size: Size { width: Dimension::Points(200.0000), ..Default::default() },
+            display: Display::Table,
+            children: Box::new(vec![Node {
+                display: Display::TableRow,
+                children: Box::new(vec![
+                    Node {
+                        display: Display::TableCell,
+                        size: Size { width: Dimension::Points(30.0000), height: Dimension::Points(10.0000) },
+                        ..Default::default()
+                    },
+                    Node {
+                        display: Display::TableCell,
+                        size: Size { width: Dimension::Points(50.0000), height: Dimension::Points(10.0000) },
+                        ..Default::default()
+                    },
+                ]),
+                ..Default::default()
+            }]),
+            ..Default::default()
+        });
+
+        assert_eq!(layout.children[0].children[0].size.width, 75.0000);
+        assert_eq!(layout.children[0].children[1].size.width, 125.0000);
+        assert_eq!(layout.children[0].children[1].location.x, 75.0000);
+    }
+
+    #[test]
+    fn table_fixed_layout() {
+        let layout = stretch::compute(&Node {
+            table_layout: TableLayout::Fixed,
+            size: Size { width: Dimension::Points(200.0000), ..Default::default() },
+            display: Display::Table,
+            children: Box::new(vec![
+                Node {
+                    display: Display::TableColumn,
+                    size: Size { width: Dimension::Points(50.0000), ..Default::default() },
+                    ..Default::default()
+                },
+                Node {
+                    display: Display::TableRow,
+                    children: Box::new(vec![
+                        Node {
+                            display: Display::TableCell,
+                            size: Size { width: Dimension::Points(150.0000), height: Dimension::Points(10.0000) },
+                            ..Default::default()
+                        },
+                        Node {
+                            display: Display::TableCell,
+                            size: Size { height: Dimension::Points(10.0000), ..Default::default() },
+                            ..Default::default()
+                        },
+                        Node {
+                            display: Display::TableCell,
+                            size: Size { height: Dimension::Points(10.0000), ..Default::default() },
+                            ..Default::default()
+                        },
+                    ]),
+                    ..Default::default()
+                },
+            ]),
+            ..Default::default()
+        });
+
+        assert_eq!(layout.size.width, 200.0000);
+
+        assert_eq!(layout.children[0].size.width, 50.0000);
+        assert_eq!(layout.children[0].size.height, 10.0000);
+
+        assert_eq!(layout.children[1].children[0].size.width, 50.0000);
+        assert_eq!(layout.children[1].children[1].size.width, 75.0000);
+        assert_eq!(layout.children[1].children[2].location.x, 125.0000);
+    }
+
+    #[test]
+    fn table_row_group_rtl() {
+        let layout = stretch::compute(&Node {
+            display: Display::Table,
+            direction: Direction::RTL,
+            children: Box::new(vec![Node {
+                display: Display::TableRowGroup,
+                children: Box::new(vec![Node {
+                    display: Display::TableRow,
+                    children: Box::new(vec![
+                        Node {
+                            display: Display::TableCell,
+                            size: Size { width: Dimension::Points(30.0000), height: Dimension::Points(10.0000) },
+                            ..Default::default()
+                        },
+                        Node {
+                            display: Display::TableCell,
+                            size: Size { width: Dimension::Points(50.0000), height: Dimension::Points(20.0000) },
+                            ..Default::default()
+                        },
+                    ]),
+                    ..Default::default()
+                }]),
+                ..Default::default()
+            }]),
+            ..Default::default()
+        });
+
+        assert_eq!(layout.children[0].size.width, 80.0000);
+        assert_eq!(layout.children[0].size.height, 20.0000);
+
+        assert_eq!(layout.children[0].children[0].location.y, 0.0000);
+        assert_eq!(layout.children[0].children[0].children[0].location.x, 50.0000);
+        assert_eq!(layout.children[0].children[0].children[1].location.x, 0.0000);
+        assert_eq!(layout.children[0].children[0].children[0].size.height, 20.0000);
+    }
+}
@@ -0,0 +1,129 @@
+#[cfg(test)]
+mod visibility {
+    #[test]
+    fn visibility_hidden() {
+        let layout = stretch::compute(&stretch::style::Node {
+            children: Box::new(vec![
+                stretch::style::Node {
+                    visibility: stretch::style::Visibility::Hidden,
+                    size: stretch::geometry::Size {
+                        width: stretch::style::Dimension::Points(10.0000),
+                        height: stretch::style::Dimension::Points(10.0000),
+                    },
+                    ..Default::default()
+                },
+                stretch::style::Node {
+                    size: stretch::geometry::Size {
+                        width: stretch::style::Dimension::Points(10.0000),
+                        height: stretch::style::Dimension::Points(10.0000),
+                    },
+                    ..Default::default()
+                },
+            ]),
+            ..Default::default()
+        });
+
+        assert_eq!(layout.size.width, 20.0000);
+        assert_eq!(layout.size.height, 10.0000);
+        assert!(layout.visible);
+
+        assert_eq!(layout.children[0].size.width, 10.0000);
+        assert_eq!(layout.children[0].location.x, 0.0000);
+        assert!(!layout.children[0].visible);
+
+        assert_eq!(layout.children[1].size.width, 10.0000);
+        assert_eq!(layout.children[1].location.x, 10.0000);
+        assert!(layout.children[1].visible);
+    }
+
+    #[test]
+    fn visibility_collapse_keeps_strut() {
+        let layout = stretch::compute(&stretch::style::Node {
+            size: stretch::geometry::Size { width: stretch::style::Dimension::Points(100.0000), ..Default::default() },
+            children: Box::new(vec![
+                stretch::style::Node {
+                    size: stretch::geometry::Size {
+                        width: stretch::style::Dimension::Points(20.0000),
+                        height: stretch::style::Dimension::Points(10.0000),
+                    },
+                    ..Default::default()
+                },
+                stretch::style::Node {
+                    visibility: stretch::style::Visibility::Collapse,
+                    size: stretch::geometry::Size {
+                        width: stretch::style::Dimension::Points(30.0000),
+                        height: stretch::style::Dimension::Points(50.0000),
+                    },
+                    ..Default::default()
+                },
+                stretch::style::Node {
+                    size: stretch::geometry::Size {
+                        width: stretch::style::Dimension::Points(20.0000),
+                        height: stretch::style::Dimension::Points(10.0000),
+                    },
+                    ..Default::default()
+                },
+            ]),
+            ..Default::default()
+        });
+
+        assert_eq!(layout.size.width, 100.0000);
+        assert_eq!(layout.size.height, 50.0000);
+
+        assert_eq!(layout.children[0].size.width, 20.0000);
+        assert_eq!(layout.children[0].location.x, 0.0000);
+
+        assert_eq!(layout.children[1].size.width, 0.0000);
+        assert_eq!(layout.children[1].size.height, 0.0000);
+        assert!(!layout.children[1].visible);
+
+        assert_eq!(layout.children[2].size.width, 20.0000);
+        assert_eq!(layout.children[2].location.x, 20.0000);
+    }
+
+    #[test]
+    fn visibility_collapse_wrap() {
+        let layout = stretch::compute(&stretch::style::Node {
+            flex_wrap: stretch::style::FlexWrap::Wrap,
+            align_content: stretch::style::AlignContent::FlexStart,
+            size: stretch::geometry::Size { width: stretch::style::Dimension::Points(50.0000), ..Default::default() },
+            children: Box::new(vec![
+                stretch::style::Node {
+                    size: stretch::geometry::Size {
+                        width: stretch::style::Dimension::Points(30.0000),
+                        height: stretch::style::Dimension::Points(10.0000),
+                    },
+                    ..Default::default()
+                },
+                stretch::style::Node {
+                    visibility: stretch::style::Visibility::Collapse,
+                    size: stretch::geometry::Size {
+                        width: stretch::style::Dimension::Points(30.0000),
+                        height: stretch::style::Dimension::Points(40.0000),
+                    },
+                    ..Default::default()
+                },
+                stretch::style::Node {
+                    size: stretch::geometry::Size {
+                        width: stretch::style::Dimension::Points(30.0000),
+                        height: stretch::style::Dimension::Points(10.0000),
+                    },
+                    ..Default::default()
+                },
+            ]),
+            ..Default::default()
+        });
+
+        assert_eq!(layout.size.width, 50.0000);
+        assert_eq!(layout.size.height, 50.0000);
+
+        assert_eq!(layout.children[0].location.x, 0.0000);
+        assert_eq!(layout.children[0].location.y, 0.0000);
+
+        assert_eq!(layout.children[1].size.width, 0.0000);
+        assert!(!layout.children[1].visible);
+
+        assert_eq!(layout.children[2].location.x, 0.0000);
+        assert_eq!(layout.children[2].location.y, 40.0000);
+    }
+}